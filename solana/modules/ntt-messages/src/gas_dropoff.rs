@@ -0,0 +1,93 @@
+use std::io;
+
+use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
+
+/// A request for native gas to be dropped off to the recipient of a transfer
+/// on the destination chain. It's carried in the transceiver payload, so it
+/// isn't part of the attested NTT manager message.
+///
+/// The dropoff is funded by the relayer that delivers the transfer, who is
+/// compensated on the source chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasDropoff {
+    /// In the native base units of the destination chain (lamports on Solana).
+    pub amount: u64,
+}
+
+impl GasDropoff {
+    const PREFIX: [u8; 4] = [0x99, 0x47, 0x44, 0x4F];
+
+    /// Parses a transceiver payload. Payloads that aren't exactly a dropoff
+    /// request (e.g. empty ones) don't request a dropoff.
+    pub fn from_transceiver_payload(transceiver_payload: &[u8]) -> Option<Self> {
+        let mut reader = transceiver_payload;
+        let gas_dropoff = Self::read(&mut reader).ok()?;
+        reader.is_empty().then_some(gas_dropoff)
+    }
+}
+
+impl TypePrefixedPayload for GasDropoff {
+    const TYPE: Option<u8> = None;
+}
+
+impl Readable for GasDropoff {
+    const SIZE: Option<usize> = Some(4 + 8);
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        let prefix: [u8; 4] = Readable::read(reader)?;
+        if prefix != Self::PREFIX {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid prefix for GasDropoff",
+            ));
+        }
+
+        let amount = Readable::read(reader)?;
+
+        Ok(Self { amount })
+    }
+}
+
+impl Writeable for GasDropoff {
+    fn written_size(&self) -> usize {
+        Self::SIZE.unwrap()
+    }
+
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        Self::PREFIX.write(writer)?;
+        self.amount.write(writer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_transceiver_payload() {
+        let gas_dropoff = GasDropoff { amount: 1_000_000 };
+        let encoded = TypePrefixedPayload::to_vec_payload(&gas_dropoff);
+        assert_eq!(encoded.len(), gas_dropoff.written_size());
+
+        assert_eq!(
+            GasDropoff::from_transceiver_payload(&encoded),
+            Some(gas_dropoff)
+        );
+        assert_eq!(GasDropoff::from_transceiver_payload(&[]), None);
+        assert_eq!(
+            GasDropoff::from_transceiver_payload(&encoded[..encoded.len() - 1]),
+            None
+        );
+        assert_eq!(
+            GasDropoff::from_transceiver_payload(&[&encoded[..], &[0]].concat()),
+            None
+        );
+    }
+}
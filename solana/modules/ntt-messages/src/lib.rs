@@ -1,5 +1,6 @@
 pub mod chain_id;
 pub mod errors;
+pub mod gas_dropoff;
 pub mod mode;
 pub mod ntt;
 pub mod ntt_manager;
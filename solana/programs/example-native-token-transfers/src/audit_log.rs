@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+
+/// Number of entries retained by the [`AuditLog`]. Once the buffer is full, the
+/// oldest entry is overwritten.
+pub const AUDIT_LOG_CAPACITY: u16 = 64;
+
+/// Keccak256 hash of the borsh-serialized state touched by an administrative
+/// action. See [`AuditLog::digest`].
+pub type AuditDigest = [u8; 32];
+
+/// The administrative action recorded by an [`AuditLogEntry`].
+///
+/// NOTE: new variants must only ever be appended, as the discriminant is
+/// stored on-chain.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AuditAction {
+    SetPeer,
+    RegisterTransceiver,
+    DeregisterTransceiver,
+    SetOutboundLimit,
+    SetInboundLimit,
+    SetPaused,
+    SetThreshold,
+    TransferOwnership,
+    TransferOwnershipOneStepUnchecked,
+    ClaimOwnership,
+    AcceptTokenAuthority,
+    AcceptTokenAuthorityFromMultisig,
+    SetTokenAuthority,
+    SetTokenAuthorityOneStepUnchecked,
+    RevertTokenAuthority,
+    ClaimTokenAuthority,
+    ClaimTokenAuthorityToMultisig,
+    SetWormholePeer,
+    InitializeCouncil,
+    SetCouncilMembers,
+    CancelOwnershipTransfer,
+    SetEscrowRecovery,
+    RemovePeer,
+    SetOutboundStrictMode,
+    SetInboundStrictMode,
+    SetLargeTransferPolicy,
+    ApproveLargeInbound,
+    SetBackflow,
+    SetRateLimitExemption,
+    RemoveRateLimitExemption,
+    SetFee,
+    SetPeerFee,
+    SetAmountLimits,
+    SetStrictDustMode,
+    SetPeerAddressFormat,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct AuditLogEntry {
+    pub action: AuditAction,
+    /// The account that authorised the action (the owner, or the claimant in
+    /// the case of the two-step claim instructions).
+    pub signer: Pubkey,
+    /// The account affected by the action (e.g. the peer PDA for
+    /// [`AuditAction::SetPeer`], or the new owner for
+    /// [`AuditAction::TransferOwnership`]).
+    pub subject: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+    /// Digest of the relevant state before the action.
+    pub before: [u8; 32],
+    /// Digest of the relevant state after the action.
+    pub after: [u8; 32],
+}
+
+/// Ring buffer of the most recent administrative actions.
+///
+/// Every instruction in [`crate::instructions::admin`] (and the transceiver
+/// admin instructions) appends an entry here, so that monitoring can
+/// reconstruct recent configuration changes from a single account fetch,
+/// without access to historical transactions.
+///
+/// The log is a required account of those instructions, even in deployments
+/// that haven't created it yet, so that once it's created, no action can skip
+/// it (see [`AuditLog::record_into`]).
+///
+/// Entries are stored in insertion order until the buffer is full, after which
+/// `head` points at the oldest entry (which is the next one to be overwritten).
+#[account]
+#[derive(InitSpace)]
+pub struct AuditLog {
+    pub bump: u8,
+    /// Index into `entries` at which the next entry will be written.
+    pub head: u16,
+    /// Total number of entries ever recorded (including overwritten ones).
+    pub total_entries: u64,
+    #[max_len(AUDIT_LOG_CAPACITY)]
+    pub entries: Vec<AuditLogEntry>,
+}
+
+impl AuditLog {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_log";
+
+    /// Computes the digest of `value` to be recorded as the before/after state
+    /// of an action.
+    pub fn digest<T: AnchorSerialize>(value: &T) -> Result<AuditDigest> {
+        Ok(solana_program::keccak::hash(&value.try_to_vec()?).to_bytes())
+    }
+
+    pub fn record(
+        &mut self,
+        action: AuditAction,
+        signer: Pubkey,
+        subject: Pubkey,
+        before: AuditDigest,
+        after: AuditDigest,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        self.push(AuditLogEntry {
+            action,
+            signer,
+            subject,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+            before,
+            after,
+        });
+        Ok(())
+    }
+
+    /// Records an action in the audit log passed as `info`, if it has been
+    /// created. Before then, nothing is recorded.
+    ///
+    /// NOTE: the caller must check that `info` is the audit log's address
+    /// (with a seeds constraint).
+    pub fn record_into(
+        info: &AccountInfo,
+        action: AuditAction,
+        signer: Pubkey,
+        subject: Pubkey,
+        before: AuditDigest,
+        after: AuditDigest,
+    ) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        let mut audit_log = Account::<AuditLog>::try_from(info)?;
+        audit_log.record(action, signer, subject, before, after)?;
+        audit_log.exit(&crate::ID)
+    }
+
+    fn push(&mut self, entry: AuditLogEntry) {
+        let head = usize::from(self.head);
+        if head < self.entries.len() {
+            self.entries[head] = entry;
+        } else {
+            self.entries.push(entry);
+        }
+        self.head = (self.head + 1) % AUDIT_LOG_CAPACITY;
+        self.total_entries += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(slot: u64) -> AuditLogEntry {
+        AuditLogEntry {
+            action: AuditAction::SetPaused,
+            signer: Pubkey::default(),
+            subject: Pubkey::default(),
+            slot,
+            timestamp: 0,
+            before: [0; 32],
+            after: [0; 32],
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_wraps() {
+        let mut log = AuditLog {
+            bump: 0,
+            head: 0,
+            total_entries: 0,
+            entries: vec![],
+        };
+
+        for slot in 0..u64::from(AUDIT_LOG_CAPACITY) {
+            log.push(entry(slot));
+        }
+        assert_eq!(log.entries.len(), usize::from(AUDIT_LOG_CAPACITY));
+        assert_eq!(log.head, 0);
+
+        // the oldest entry is overwritten first
+        log.push(entry(100));
+        log.push(entry(101));
+        assert_eq!(log.entries.len(), usize::from(AUDIT_LOG_CAPACITY));
+        assert_eq!(log.entries[0].slot, 100);
+        assert_eq!(log.entries[1].slot, 101);
+        assert_eq!(log.entries[2].slot, 2);
+        assert_eq!(log.head, 2);
+        assert_eq!(log.total_entries, u64::from(AUDIT_LOG_CAPACITY) + 2);
+    }
+}
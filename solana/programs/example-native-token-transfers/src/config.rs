@@ -3,7 +3,7 @@ use std::ops::{Deref, DerefMut};
 use anchor_lang::prelude::*;
 use ntt_messages::{chain_id::ChainId, mode::Mode};
 
-use crate::bitmap::Bitmap;
+use crate::{bitmap::Bitmap, layout::versioned_layout};
 
 /// This is a hack to re-export some modules that anchor generates as
 /// pub(crate), as it's not possible to directly re-export a module with a
@@ -55,10 +55,132 @@ pub struct Config {
     pub paused: bool,
     /// The custody account that holds tokens in locking mode.
     pub custody: Pubkey,
+    /// Timestamp after which [`Config::pending_owner`] can no longer claim
+    /// ownership. See [`crate::PENDING_AUTHORITY_VALIDITY`].
+    ///
+    /// NOTE: this field was appended after the initial release. Configs
+    /// created before then have enough slack at the end (as `pending_owner` is
+    /// always `None` when the program is upgradeable) to deserialize it, and
+    /// are grown when the next ownership transfer is initiated. The value is
+    /// only meaningful while `pending_owner` is set.
+    pub pending_owner_expiry: i64,
+    /// NOTE: this field was appended after [`Config::pending_owner_expiry`].
+    /// Configs created before then are grown (see
+    /// [`Config::materialize_records`]) when the fee is first set or an
+    /// ownership transfer is initiated. Until then they may end in stale
+    /// bytes left by a previous `pending_owner`, which are read as
+    /// unavailable.
+    pub fees: FeeSettings,
+    /// NOTE: this field was appended after [`Config::fees`], and must remain
+    /// the last one. Configs created before then are grown along with
+    /// [`Config::fees`].
+    pub dust: DustSettings,
 }
 
 impl Config {
     pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    pub fn pending_owner_expired(&self) -> bool {
+        crate::clock::current_timestamp() >= self.pending_owner_expiry
+    }
+
+    /// Writes the trailing records explicitly, with their current values.
+    ///
+    /// Unavailable records serialize to nothing, so a config that ends in
+    /// them would end in whatever bytes `pending_owner` leaves behind when it
+    /// is cleared. Every instruction that grows the config calls this first,
+    /// and grows it to [`Config::INIT_SPACE`].
+    pub fn materialize_records(&mut self) {
+        self.fees = FeeSettings::V1(self.fees.current());
+        self.dust = DustSettings::V1(self.dust.current());
+    }
+}
+
+/// The protocol fee charged on outbound transfers, set by
+/// [`crate::instructions::set_fee`]. Peers can override the rate (see
+/// [`crate::peer::PeerFeeSettingsV1::fee_bps`]).
+/// [`FeeSettings::Unavailable`] means no fee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FeeSettings {
+    Unavailable,
+    V1(FeeSettingsV1),
+}
+
+// the fee settings follow the slack left by `pending_owner` (see
+// [`Config::pending_owner_expiry`])
+versioned_layout!(FeeSettings after_slack {
+    1 => V1(FeeSettingsV1),
+});
+
+impl FeeSettings {
+    pub fn current(&self) -> FeeSettingsV1 {
+        match self {
+            FeeSettings::Unavailable => FeeSettingsV1::default(),
+            FeeSettings::V1(settings) => settings.clone(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct FeeSettingsV1 {
+    /// The fee, in basis points of the transferred amount.
+    pub fee_bps: u16,
+    /// The token account (of the managed mint) that receives the fees.
+    pub fee_recipient: Pubkey,
+}
+
+impl FeeSettingsV1 {
+    /// The number of basis points in the whole amount.
+    pub const BPS_DENOMINATOR: u16 = 10_000;
+
+    /// The highest fee the owner can set (10%), so that a compromised or
+    /// mistaken owner can't take most of each transfer.
+    pub const MAX_FEE_BPS: u16 = 1_000;
+
+    /// The fee on a transfer of `amount` to a peer, at the peer's rate if it
+    /// overrides the global one. Rounded down.
+    // SECURITY: Integer division is OK here. The fee is rounded down, in the
+    // sender's favour.
+    // SECURITY: Truncation is allowed here. The fee is capped by `amount`,
+    // which is a u64.
+    #[allow(clippy::integer_division)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn fee(&self, amount: u64, peer_fee_bps: Option<u16>) -> u64 {
+        let fee_bps = peer_fee_bps.unwrap_or(self.fee_bps);
+        let fee = u128::from(amount) * u128::from(fee_bps) / u128::from(Self::BPS_DENOMINATOR);
+        fee.min(u128::from(amount)) as u64
+    }
+}
+
+/// How outbound transfers handle dust (the part of the amount that can't be
+/// represented at the trimmed decimals), set by
+/// [`crate::instructions::set_strict_dust_mode`].
+/// [`DustSettings::Unavailable`] means the defaults.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DustSettings {
+    Unavailable,
+    V1(DustSettingsV1),
+}
+
+versioned_layout!(DustSettings {
+    1 => V1(DustSettingsV1),
+});
+
+impl DustSettings {
+    pub fn current(&self) -> DustSettingsV1 {
+        match self {
+            DustSettings::Unavailable => DustSettingsV1::default(),
+            DustSettings::V1(settings) => settings.clone(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct DustSettingsV1 {
+    /// Whether outbound transfers with dust are rejected. Otherwise (the
+    /// default) the dust is removed from the amount, and stays with the
+    /// sender.
+    pub strict: bool,
 }
 
 #[derive(Accounts)]
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::{bitmap::Bitmap, error::NTTError};
+
+/// Maximum number of members in the [`Council`].
+pub const COUNCIL_MAX_MEMBERS: u8 = 32;
+
+/// Placeholder for the account paying for the instruction executed by a
+/// [`CouncilProposal`]. When the proposal is executed, any account meta with
+/// this pubkey is replaced with the council payer PDA (see
+/// [`COUNCIL_PAYER_SEED`]), which the program signs for alongside the council.
+///
+/// The council PDA itself can't pay for account creation, as it holds data.
+/// The executor of the proposal can't either: execution is permissionless, so
+/// handing the executor's signature to the proposed instruction would let it
+/// spend the executor's lamports.
+pub const COUNCIL_PAYER: Pubkey = sentinel_pubkey(b"payer");
+
+/// Seed of the system-owned PDA that pays for the instructions executed by the
+/// council. It has to be funded (with a plain lamport transfer) before
+/// executing proposals that create accounts.
+pub const COUNCIL_PAYER_SEED: &[u8] = b"council_payer";
+
+/// An M-of-N council that can act as the owner of the program.
+///
+/// The council account is a PDA, so it can be set as [`crate::config::Config::owner`]
+/// (through the regular [`crate::instructions::transfer_ownership`] and
+/// [`crate::instructions::claim_ownership`] flow). From then on, admin
+/// instructions are executed by the program signing for the council PDA once
+/// `threshold` of its `members` have approved a [`CouncilProposal`].
+///
+/// Since the council PDA claims ownership as a signer, the upgrade authority of
+/// the program is transferred to it too, and upgrades are executed the same
+/// way as any other proposal.
+#[account]
+#[derive(InitSpace)]
+pub struct Council {
+    pub bump: u8,
+    #[max_len(COUNCIL_MAX_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    /// Number of member approvals required to execute a proposal.
+    pub threshold: u8,
+    /// Incremented every time the members or the threshold change. Proposals
+    /// created under a previous generation can no longer be approved or
+    /// executed, as their approvals refer to member indices of the old set.
+    pub generation: u32,
+    /// The id of the next [`CouncilProposal`].
+    pub next_proposal_id: u64,
+}
+
+impl Council {
+    pub const SEED_PREFIX: &'static [u8] = b"council";
+
+    /// Returns the index of `member` in the council, which is the bit
+    /// corresponding to its vote in [`CouncilProposal::approvals`].
+    pub fn member_index(&self, member: &Pubkey) -> Result<u8> {
+        self.members
+            .iter()
+            .position(|m| m == member)
+            .and_then(|i| u8::try_from(i).ok())
+            .ok_or_else(|| NTTError::NotCouncilMember.into())
+    }
+
+    /// Replaces the members and the threshold, invalidating all outstanding
+    /// proposals.
+    pub fn set_members(&mut self, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        if members.len() > usize::from(COUNCIL_MAX_MEMBERS) {
+            return Err(NTTError::TooManyCouncilMembers.into());
+        }
+        if members
+            .iter()
+            .enumerate()
+            .any(|(i, m)| members[..i].contains(m))
+        {
+            return Err(NTTError::DuplicateCouncilMember.into());
+        }
+        if threshold == 0 {
+            return Err(NTTError::ZeroThreshold.into());
+        }
+        if usize::from(threshold) > members.len() {
+            return Err(NTTError::ThresholdTooHigh.into());
+        }
+        self.members = members;
+        self.threshold = threshold;
+        self.generation += 1;
+        Ok(())
+    }
+}
+
+/// A copy of [`solana_program::instruction::AccountMeta`] with
+/// `AnchorSerialize`/`AnchorDeserialize` impls.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CouncilAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl From<CouncilAccountMeta> for AccountMeta {
+    fn from(val: CouncilAccountMeta) -> Self {
+        AccountMeta {
+            pubkey: val.pubkey,
+            is_signer: val.is_signer,
+            is_writable: val.is_writable,
+        }
+    }
+}
+
+impl From<AccountMeta> for CouncilAccountMeta {
+    fn from(val: AccountMeta) -> Self {
+        CouncilAccountMeta {
+            pubkey: val.pubkey,
+            is_signer: val.is_signer,
+            is_writable: val.is_writable,
+        }
+    }
+}
+
+/// The instruction to be executed by a [`CouncilProposal`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CouncilInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<CouncilAccountMeta>,
+    pub data: Vec<u8>,
+}
+
+impl From<Instruction> for CouncilInstruction {
+    fn from(ix: Instruction) -> Self {
+        CouncilInstruction {
+            program_id: ix.program_id,
+            accounts: ix.accounts.into_iter().map(Into::into).collect(),
+            data: ix.data,
+        }
+    }
+}
+
+impl From<CouncilInstruction> for Instruction {
+    fn from(ix: CouncilInstruction) -> Self {
+        Instruction {
+            program_id: ix.program_id,
+            accounts: ix.accounts.into_iter().map(Into::into).collect(),
+            data: ix.data,
+        }
+    }
+}
+
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct CouncilProposal {
+    pub bump: u8,
+    pub id: u64,
+    /// The [`Council::generation`] the proposal was created under.
+    pub generation: u32,
+    /// The member that created the proposal. It also paid the rent, which is
+    /// refunded when the proposal is closed.
+    pub proposer: Pubkey,
+    /// Bitmap of [`Council::members`] indices that approved the proposal.
+    pub approvals: Bitmap,
+    pub executed: bool,
+    pub instruction: CouncilInstruction,
+}
+
+impl CouncilProposal {
+    pub const SEED_PREFIX: &'static [u8] = b"council_proposal";
+
+    /// The account size (excluding the discriminator) of a proposal
+    /// executing `instruction`.
+    pub fn space(instruction: &CouncilInstruction) -> usize {
+        1 // bump
+        + 8 // id
+        + 4 // generation
+        + 32 // proposer
+        + Bitmap::INIT_SPACE // approvals
+        + 1 // executed
+        + 32 // program_id
+        + 4 + instruction.accounts.len() * (32 + 1 + 1) // accounts
+        + 4 + instruction.data.len() // data
+    }
+}
+
+const fn sentinel_pubkey(input: &[u8]) -> Pubkey {
+    let mut output: [u8; 32] = [0; 32];
+
+    let mut i = 0;
+    while i < input.len() {
+        output[i] = input[i];
+        i += 1;
+    }
+
+    Pubkey::new_from_array(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proposal_space() {
+        let instruction = CouncilInstruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![
+                CouncilAccountMeta {
+                    pubkey: Pubkey::new_unique(),
+                    is_signer: true,
+                    is_writable: false,
+                },
+                CouncilAccountMeta {
+                    pubkey: Pubkey::new_unique(),
+                    is_signer: false,
+                    is_writable: true,
+                },
+            ],
+            data: vec![1, 2, 3],
+        };
+        let proposal = CouncilProposal {
+            bump: 255,
+            id: 0,
+            generation: 1,
+            proposer: Pubkey::new_unique(),
+            approvals: Bitmap::new(),
+            executed: false,
+            instruction: instruction.clone(),
+        };
+        assert_eq!(
+            proposal.try_to_vec().unwrap().len(),
+            CouncilProposal::space(&instruction)
+        );
+    }
+}
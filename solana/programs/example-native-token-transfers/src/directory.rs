@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
+
+use crate::error::NTTError;
+
+/// Maximum number of peers listed in the [`Directory`].
+pub const DIRECTORY_MAX_PEERS: u16 = 128;
+
+/// Maximum number of transceivers listed in the [`Directory`]. This is the
+/// number of transceiver ids that fit in [`crate::bitmap::Bitmap`].
+pub const DIRECTORY_MAX_TRANSCEIVERS: u16 = 128;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct DirectoryPeer {
+    pub chain_id: ChainId,
+    pub address: [u8; 32],
+    pub token_decimals: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct DirectoryTransceiver {
+    pub id: u8,
+    pub transceiver_address: Pubkey,
+    /// Deregistered transceivers keep their id, so they stay listed, but
+    /// disabled.
+    pub enabled: bool,
+}
+
+/// Lists the peers ([`crate::peer::NttManagerPeer`]) and registered
+/// transceivers ([`crate::registered_transceiver::RegisteredTransceiver`]) of
+/// the deployment, so that clients can discover them with a single account
+/// fetch instead of `getProgramAccounts`.
+///
+/// The directory is passed to the admin instructions that modify peers and
+/// transceivers as an optional account. Unlike the
+/// [`crate::audit_log::AuditLog`], skipping it loses nothing: entries that
+/// are missing or stale (e.g. for deployments that predate the directory) can
+/// be fixed permissionlessly with
+/// [`crate::instructions::sync_directory_peer`] and
+/// [`crate::instructions::sync_directory_transceiver`].
+///
+/// Peers are sorted by chain id, and transceivers by id.
+#[account]
+#[derive(InitSpace)]
+pub struct Directory {
+    pub bump: u8,
+    #[max_len(DIRECTORY_MAX_PEERS)]
+    pub peers: Vec<DirectoryPeer>,
+    #[max_len(DIRECTORY_MAX_TRANSCEIVERS)]
+    pub transceivers: Vec<DirectoryTransceiver>,
+}
+
+impl Directory {
+    pub const SEED_PREFIX: &'static [u8] = b"directory";
+
+    pub fn set_peer(&mut self, peer: DirectoryPeer) -> Result<()> {
+        match self
+            .peers
+            .binary_search_by_key(&peer.chain_id.id, |entry| entry.chain_id.id)
+        {
+            Ok(index) => self.peers[index] = peer,
+            Err(index) => {
+                if self.peers.len() >= usize::from(DIRECTORY_MAX_PEERS) {
+                    return Err(NTTError::DirectoryFull.into());
+                }
+                self.peers.insert(index, peer);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn remove_peer(&mut self, chain_id: ChainId) {
+        if let Ok(index) = self
+            .peers
+            .binary_search_by_key(&chain_id.id, |entry| entry.chain_id.id)
+        {
+            self.peers.remove(index);
+        }
+    }
+
+    pub fn set_transceiver(&mut self, transceiver: DirectoryTransceiver) -> Result<()> {
+        match self
+            .transceivers
+            .binary_search_by_key(&transceiver.id, |entry| entry.id)
+        {
+            Ok(index) => self.transceivers[index] = transceiver,
+            Err(index) => {
+                if self.transceivers.len() >= usize::from(DIRECTORY_MAX_TRANSCEIVERS) {
+                    return Err(NTTError::DirectoryFull.into());
+                }
+                self.transceivers.insert(index, transceiver);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer(chain_id: u16, token_decimals: u8) -> DirectoryPeer {
+        DirectoryPeer {
+            chain_id: ChainId { id: chain_id },
+            address: [chain_id as u8; 32],
+            token_decimals,
+        }
+    }
+
+    #[test]
+    fn test_peers_sorted() {
+        let mut directory = Directory {
+            bump: 0,
+            peers: vec![],
+            transceivers: vec![],
+        };
+
+        directory.set_peer(peer(5, 8)).unwrap();
+        directory.set_peer(peer(2, 8)).unwrap();
+        directory.set_peer(peer(9, 8)).unwrap();
+        // updates in place
+        directory.set_peer(peer(5, 6)).unwrap();
+        assert_eq!(directory.peers, vec![peer(2, 8), peer(5, 6), peer(9, 8)]);
+
+        directory.remove_peer(ChainId { id: 5 });
+        // removing a missing peer is a no-op
+        directory.remove_peer(ChainId { id: 7 });
+        assert_eq!(directory.peers, vec![peer(2, 8), peer(9, 8)]);
+    }
+
+    #[test]
+    fn test_directory_full() {
+        let mut directory = Directory {
+            bump: 0,
+            peers: vec![],
+            transceivers: vec![],
+        };
+
+        for chain_id in 0..DIRECTORY_MAX_PEERS {
+            directory.set_peer(peer(chain_id, 8)).unwrap();
+        }
+        assert!(directory.set_peer(peer(DIRECTORY_MAX_PEERS, 8)).is_err());
+        // existing peers can still be updated
+        directory.set_peer(peer(0, 6)).unwrap();
+    }
+}
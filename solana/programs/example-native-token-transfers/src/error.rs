@@ -65,6 +65,112 @@ pub enum NTTError {
     ThresholdTooHigh,
     #[msg("InvalidTransceiverProgram")]
     InvalidTransceiverProgram,
+    #[msg("NotCouncilMember")]
+    NotCouncilMember,
+    #[msg("TooManyCouncilMembers")]
+    TooManyCouncilMembers,
+    #[msg("DuplicateCouncilMember")]
+    DuplicateCouncilMember,
+    #[msg("CouncilProposalAlreadyApproved")]
+    CouncilProposalAlreadyApproved,
+    #[msg("CouncilProposalAlreadyExecuted")]
+    CouncilProposalAlreadyExecuted,
+    #[msg("CouncilProposalStillActive")]
+    CouncilProposalStillActive,
+    #[msg("StaleCouncilProposal")]
+    StaleCouncilProposal,
+    #[msg("CouncilThresholdNotMet")]
+    CouncilThresholdNotMet,
+    #[msg("CouncilProposalSelfReference")]
+    CouncilProposalSelfReference,
+    #[msg("PendingOwnerExpired")]
+    PendingOwnerExpired,
+    #[msg("NoPendingOwner")]
+    NoPendingOwner,
+    #[msg("PendingTokenAuthorityExpired")]
+    PendingTokenAuthorityExpired,
+    #[msg("PendingTokenAuthorityNotExpired")]
+    PendingTokenAuthorityNotExpired,
+    #[msg("InvalidIntentSignature")]
+    InvalidIntentSignature,
+    #[msg("InvalidIntentNttManager")]
+    InvalidIntentNttManager,
+    #[msg("InvalidIntentSender")]
+    InvalidIntentSender,
+    #[msg("IntentExpired")]
+    IntentExpired,
+    #[msg("InvalidIntentNonce")]
+    InvalidIntentNonce,
+    #[msg("RelayerFeeTooHigh")]
+    RelayerFeeTooHigh,
+    #[msg("InvalidRelayerFeeRecipient")]
+    InvalidRelayerFeeRecipient,
+    #[msg("InvalidRecipientAddressFormat")]
+    InvalidRecipientAddressFormat,
+    #[msg("TransferEscrowed")]
+    TransferEscrowed,
+    #[msg("TransferNotEscrowed")]
+    TransferNotEscrowed,
+    #[msg("InboxItemDeliverable")]
+    InboxItemDeliverable,
+    #[msg("FailedAttemptTooSoon")]
+    FailedAttemptTooSoon,
+    #[msg("NotInboxItemRecipient")]
+    NotInboxItemRecipient,
+    #[msg("NoEscrowDestination")]
+    NoEscrowDestination,
+    #[msg("EscrowRecoveryTimelocked")]
+    EscrowRecoveryTimelocked,
+    #[msg("InvalidEscrowRecoveryDestination")]
+    InvalidEscrowRecoveryDestination,
+    #[msg("InvalidRecipientTokenAccount")]
+    InvalidRecipientTokenAccount,
+    #[msg("DirectoryFull")]
+    DirectoryFull,
+    #[msg("InvalidQueueIndex")]
+    InvalidQueueIndex,
+    #[msg("TransferStillQueued")]
+    TransferStillQueued,
+    #[msg("GuardianApprovalRequired")]
+    GuardianApprovalRequired,
+    #[msg("NotAwaitingGuardian")]
+    NotAwaitingGuardian,
+    #[msg("InvalidGuardian")]
+    InvalidGuardian,
+    #[msg("InvalidLargeTransferPolicy")]
+    InvalidLargeTransferPolicy,
+    #[msg("TransferReleasedInTranches")]
+    TransferReleasedInTranches,
+    #[msg("TransferNotReleasedInTranches")]
+    TransferNotReleasedInTranches,
+    #[msg("MissingRateLimit")]
+    MissingRateLimit,
+    #[msg("InvalidBackflow")]
+    InvalidBackflow,
+    #[msg("InvalidRateLimitExemption")]
+    InvalidRateLimitExemption,
+    #[msg("InvalidFee")]
+    InvalidFee,
+    #[msg("MissingFeeRecipient")]
+    MissingFeeRecipient,
+    #[msg("InvalidFeeRecipient")]
+    InvalidFeeRecipient,
+    #[msg("InvalidAmountLimits")]
+    InvalidAmountLimits,
+    #[msg("TransferBelowMinimum")]
+    TransferBelowMinimum,
+    #[msg("TransferAboveMaximum")]
+    TransferAboveMaximum,
+    #[msg("TransferHasDust")]
+    TransferHasDust,
+    #[msg("InvalidBatchSize")]
+    InvalidBatchSize,
+    #[msg("BatchAmountOverflow")]
+    BatchAmountOverflow,
+    #[msg("TransferRequiresSecurityDelay")]
+    TransferRequiresSecurityDelay,
+    #[msg("TokenAccountRecipientUnsupported")]
+    TokenAccountRecipientUnsupported,
 }
 
 impl From<ScalingError> for NTTError {
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
+
+/// Emitted when an outbound transfer is inserted into the outbox, whether it's
+/// released right away or queued.
+#[event]
+pub struct OutboundTransfer {
+    pub outbox_item: Pubkey,
+    pub sender: Pubkey,
+    pub recipient_chain: ChainId,
+    pub recipient_address: [u8; 32],
+    /// The amount that is transferred, after the protocol fee was deducted and
+    /// dust was removed (in the mint's decimals).
+    pub amount: u64,
+    pub release_timestamp: i64,
+    /// Whether the transfer was counted against the sender's rate limit
+    /// exemption instead of the outbound rate limit (see
+    /// [`crate::queue::outbox::OutboxProvenanceV1::rate_limit_exempt`]).
+    pub rate_limit_exempt: bool,
+    /// The protocol fee deducted from the requested amount (see
+    /// [`crate::config::FeeSettingsV1`]).
+    pub fee: u64,
+}
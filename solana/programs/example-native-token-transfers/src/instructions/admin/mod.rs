@@ -1,11 +1,26 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
 use ntt_messages::chain_id::ChainId;
 
 use crate::{
-    config::Config,
+    audit_log::{AuditAction, AuditLog},
+    config::{Config, DustSettings, DustSettingsV1, FeeSettings, FeeSettingsV1},
+    directory::{Directory, DirectoryPeer},
     error::NTTError,
-    peer::NttManagerPeer,
-    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit, rate_limit::RateLimitState},
+    instructions::directory_transceiver,
+    layout::grow_account,
+    peer::{
+        AddressFormat, AmountLimitSettings, AmountLimits, LargeTransferPolicy,
+        LargeTransferSettings, NttManagerPeer, PeerFeeSettings, PeerFeeSettingsV1, PeerSettings,
+        PeerSettingsV1,
+    },
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{OutboxRateLimit, RateLimitExemption},
+        rate_limit::{
+            Backflow, BackflowSettings, BackflowSettingsV1, RateLimitSettings, RateLimitState,
+        },
+    },
     registered_transceiver::RegisteredTransceiver,
 };
 
@@ -33,7 +48,13 @@ pub struct SetPeer<'info> {
 
     #[account(
         init_if_needed,
-        space = 8 + NttManagerPeer::INIT_SPACE,
+        // NOTE: peers created before the settings were introduced are smaller.
+        // They're accepted as they are here, and grown in [`set_peer`].
+        space = if peer.data_is_empty() {
+            8 + NttManagerPeer::INIT_SPACE
+        } else {
+            peer.data_len()
+        },
         payer = payer,
         seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
         bump
@@ -42,7 +63,14 @@ pub struct SetPeer<'info> {
 
     #[account(
         init_if_needed,
-        space = 8 + InboxRateLimit::INIT_SPACE,
+        // NOTE: rate limits created before the settings were introduced are
+        // smaller. They're accepted as they are here, and grown in
+        // [`set_peer`].
+        space = if inbox_rate_limit.data_is_empty() {
+            8 + InboxRateLimit::INIT_SPACE
+        } else {
+            inbox_rate_limit.data_len()
+        },
         payer = payer,
         seeds = [
             InboxRateLimit::SEED_PREFIX,
@@ -53,6 +81,22 @@ pub struct SetPeer<'info> {
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Directory::SEED_PREFIX],
+        bump = directory.bump,
+    )]
+    pub directory: Option<Account<'info, Directory>>,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -65,17 +109,43 @@ pub struct SetPeerArgs {
 }
 
 pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
+    let before = AuditLog::digest(&(
+        ctx.accounts.peer.address,
+        ctx.accounts.peer.token_decimals,
+        ctx.accounts.inbox_rate_limit.rate_limit.limit,
+    ))?;
+
     ctx.accounts.peer.set_inner(NttManagerPeer {
         bump: ctx.bumps.peer,
         address: args.address,
         token_decimals: args.token_decimals,
+        settings: ctx.accounts.peer.settings.clone(),
+        large_transfers: ctx.accounts.peer.large_transfers.clone(),
+        fee: ctx.accounts.peer.fee.clone(),
+        amount_limits: ctx.accounts.peer.amount_limits.clone(),
     });
+    ctx.accounts.peer.materialize_records();
+
+    grow_account(
+        &ctx.accounts.peer.to_account_info(),
+        8 + NttManagerPeer::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    grow_account(
+        &ctx.accounts.inbox_rate_limit.to_account_info(),
+        8 + InboxRateLimit::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
 
     // if rate limit is uninitialized/unused, set new rate limit
     if ctx.accounts.inbox_rate_limit.rate_limit.last_tx_timestamp == 0 {
         ctx.accounts.inbox_rate_limit.set_inner(InboxRateLimit {
             bump: ctx.bumps.inbox_rate_limit,
             rate_limit: RateLimitState::new(args.limit),
+            settings: ctx.accounts.inbox_rate_limit.settings.clone(),
+            backflow: ctx.accounts.inbox_rate_limit.backflow.clone(),
         });
     }
     // else update rate limit
@@ -83,6 +153,458 @@ pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
         ctx.accounts.inbox_rate_limit.set_limit(args.limit);
     }
 
+    if let Some(directory) = &mut ctx.accounts.directory {
+        directory.set_peer(DirectoryPeer {
+            chain_id: args.chain_id,
+            address: args.address,
+            token_decimals: args.token_decimals,
+        })?;
+    }
+
+    let after = AuditLog::digest(&(args.address, args.token_decimals, args.limit))?;
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetPeer,
+        ctx.accounts.owner.key(),
+        ctx.accounts.peer.key(),
+        before,
+        after,
+    )?;
+    Ok(())
+}
+
+// * Remove peers
+
+#[derive(Accounts)]
+#[instruction(chain_id: ChainId)]
+pub struct RemovePeer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Directory::SEED_PREFIX],
+        bump = directory.bump,
+    )]
+    pub directory: Option<Account<'info, Directory>>,
+}
+
+/// Removes the peer of `chain_id`, so that transfers to and from that chain are
+/// rejected. The rent is refunded to the owner.
+///
+/// NOTE: the inbound rate limit of the chain is kept, and is reused if the
+/// peer is set again. Inbound transfers that were already redeemed can still
+/// be released.
+pub fn remove_peer(ctx: Context<RemovePeer>, chain_id: ChainId) -> Result<()> {
+    let before = AuditLog::digest(&(ctx.accounts.peer.address, ctx.accounts.peer.token_decimals))?;
+
+    if let Some(directory) = &mut ctx.accounts.directory {
+        directory.remove_peer(chain_id);
+    }
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::RemovePeer,
+        ctx.accounts.owner.key(),
+        ctx.accounts.peer.key(),
+        before,
+        AuditLog::digest(&())?,
+    )?;
+    Ok(())
+}
+
+// * Address format
+
+#[derive(Accounts)]
+#[instruction(args: SetPeerAddressFormatArgs)]
+pub struct SetPeerAddressFormat<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetPeerAddressFormatArgs {
+    pub chain_id: ChainId,
+    /// The format of recipient addresses on the peer chain.
+    pub address_format: AddressFormat,
+}
+
+/// Sets the format outbound transfers to a peer must use for their recipient
+/// addresses (see [`AddressFormat`]). Peers sized for older settings are grown
+/// to fit it (the payer covers the additional rent).
+pub fn set_peer_address_format(
+    ctx: Context<SetPeerAddressFormat>,
+    args: SetPeerAddressFormatArgs,
+) -> Result<()> {
+    grow_account(
+        &ctx.accounts.peer.to_account_info(),
+        8 + NttManagerPeer::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let peer = &mut ctx.accounts.peer;
+    let before = AuditLog::digest(&peer.settings.current().address_format)?;
+    let after = AuditLog::digest(&args.address_format)?;
+    peer.materialize_records();
+    peer.settings = PeerSettings::V1(PeerSettingsV1 {
+        address_format: args.address_format,
+    });
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetPeerAddressFormat,
+        ctx.accounts.owner.key(),
+        ctx.accounts.peer.key(),
+        before,
+        after,
+    )?;
+    Ok(())
+}
+
+// * Large transfer policy
+
+#[derive(Accounts)]
+#[instruction(args: SetLargeTransferPolicyArgs)]
+pub struct SetLargeTransferPolicy<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetLargeTransferPolicyArgs {
+    pub chain_id: ChainId,
+    pub policy: LargeTransferPolicy,
+}
+
+/// Sets the security delay of large outbound transfers to a peer, and the
+/// guardian approval of large inbound transfers from it (see
+/// [`LargeTransferPolicy`]). Peers sized for older settings are grown to fit
+/// the policy (the payer covers the additional rent).
+///
+/// Only transfers sent or approved from then on are affected.
+pub fn set_large_transfer_policy(
+    ctx: Context<SetLargeTransferPolicy>,
+    args: SetLargeTransferPolicyArgs,
+) -> Result<()> {
+    if !args.policy.is_valid() {
+        return Err(NTTError::InvalidLargeTransferPolicy.into());
+    }
+
+    grow_account(
+        &ctx.accounts.peer.to_account_info(),
+        8 + NttManagerPeer::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let peer = &mut ctx.accounts.peer;
+    let before = AuditLog::digest(&peer.large_transfers.current())?;
+    let after = AuditLog::digest(&args.policy)?;
+    peer.materialize_records();
+    peer.large_transfers = LargeTransferSettings::V1(args.policy);
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetLargeTransferPolicy,
+        ctx.accounts.owner.key(),
+        ctx.accounts.peer.key(),
+        before,
+        after,
+    )?;
+    Ok(())
+}
+
+// * Amount limits
+
+#[derive(Accounts)]
+#[instruction(args: SetAmountLimitsArgs)]
+pub struct SetAmountLimits<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetAmountLimitsArgs {
+    pub chain_id: ChainId,
+    pub limits: AmountLimits,
+}
+
+/// Sets the minimum and maximum amount of transfers to (and optionally from)
+/// a peer (see [`AmountLimits`]). Peers sized for older settings are grown to
+/// fit the limits (the payer covers the additional rent).
+pub fn set_amount_limits(ctx: Context<SetAmountLimits>, args: SetAmountLimitsArgs) -> Result<()> {
+    if !args.limits.is_valid() {
+        return Err(NTTError::InvalidAmountLimits.into());
+    }
+
+    grow_account(
+        &ctx.accounts.peer.to_account_info(),
+        8 + NttManagerPeer::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let peer = &mut ctx.accounts.peer;
+    let before = AuditLog::digest(&peer.amount_limits.current())?;
+    let after = AuditLog::digest(&args.limits)?;
+    peer.materialize_records();
+    peer.amount_limits = AmountLimitSettings::V1(args.limits);
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetAmountLimits,
+        ctx.accounts.owner.key(),
+        ctx.accounts.peer.key(),
+        before,
+        after,
+    )?;
+    Ok(())
+}
+
+// * Protocol fee
+
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        token::mint = config.mint,
+    )]
+    /// The token account that receives the fees.
+    pub fee_recipient: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetFeeArgs {
+    pub fee_bps: u16,
+}
+
+/// Sets the protocol fee charged on outbound transfers, and the token account
+/// it is paid to (see [`FeeSettingsV1`]). Configs created before the fee was
+/// added are grown to fit it (the payer covers the additional rent).
+pub fn set_fee(ctx: Context<SetFee>, args: SetFeeArgs) -> Result<()> {
+    if args.fee_bps > FeeSettingsV1::MAX_FEE_BPS {
+        return Err(NTTError::InvalidFee.into());
+    }
+
+    let before = AuditLog::digest(&ctx.accounts.config.fees.current())?;
+    let fees = FeeSettingsV1 {
+        fee_bps: args.fee_bps,
+        fee_recipient: ctx.accounts.fee_recipient.key(),
+    };
+    ctx.accounts.config.materialize_records();
+    ctx.accounts.config.fees = FeeSettings::V1(fees.clone());
+
+    grow_account(
+        &ctx.accounts.config.to_account_info(),
+        8 + Config::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetFee,
+        ctx.accounts.owner.key(),
+        ctx.accounts.config.key(),
+        before,
+        AuditLog::digest(&fees)?,
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetPeerFeeArgs)]
+pub struct SetPeerFee<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetPeerFeeArgs {
+    pub chain_id: ChainId,
+    /// The fee on transfers to the peer, in basis points, or `None` to charge
+    /// the global fee.
+    pub fee_bps: Option<u16>,
+}
+
+/// Overrides the protocol fee on transfers to a peer. Peers sized for older
+/// settings are grown to fit the override (the payer covers the additional
+/// rent).
+pub fn set_peer_fee(ctx: Context<SetPeerFee>, args: SetPeerFeeArgs) -> Result<()> {
+    if args
+        .fee_bps
+        .is_some_and(|fee_bps| fee_bps > FeeSettingsV1::MAX_FEE_BPS)
+    {
+        return Err(NTTError::InvalidFee.into());
+    }
+
+    grow_account(
+        &ctx.accounts.peer.to_account_info(),
+        8 + NttManagerPeer::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let peer = &mut ctx.accounts.peer;
+    let before = AuditLog::digest(&peer.fee.current().fee_bps)?;
+    let after = AuditLog::digest(&args.fee_bps)?;
+    peer.materialize_records();
+    peer.fee = PeerFeeSettings::V1(PeerFeeSettingsV1 {
+        fee_bps: args.fee_bps,
+    });
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetPeerFee,
+        ctx.accounts.owner.key(),
+        ctx.accounts.peer.key(),
+        before,
+        after,
+    )?;
     Ok(())
 }
 
@@ -119,9 +641,27 @@ pub struct RegisterTransceiver<'info> {
     pub registered_transceiver: Account<'info, RegisteredTransceiver>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Directory::SEED_PREFIX],
+        bump = directory.bump,
+    )]
+    pub directory: Option<Account<'info, Directory>>,
 }
 
 pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
+    let before = AuditLog::digest(&ctx.accounts.config.enabled_transceivers)?;
+
     // initialize registered transceiver with new id on init
     if ctx.accounts.registered_transceiver.transceiver_address == Pubkey::default() {
         let id = ctx.accounts.config.next_transceiver_id;
@@ -139,6 +679,23 @@ pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
         .config
         .enabled_transceivers
         .set(ctx.accounts.registered_transceiver.id, true)?;
+
+    if let Some(directory) = &mut ctx.accounts.directory {
+        directory.set_transceiver(directory_transceiver(
+            &ctx.accounts.config,
+            &ctx.accounts.registered_transceiver,
+        )?)?;
+    }
+
+    let after = AuditLog::digest(&ctx.accounts.config.enabled_transceivers)?;
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::RegisterTransceiver,
+        ctx.accounts.owner.key(),
+        ctx.accounts.transceiver.key(),
+        before,
+        after,
+    )?;
     Ok(())
 }
 
@@ -158,9 +715,30 @@ pub struct DeregisterTransceiver<'info> {
         constraint = config.enabled_transceivers.get(registered_transceiver.id)? @ NTTError::DisabledTransceiver,
     )]
     pub registered_transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Directory::SEED_PREFIX],
+        bump = directory.bump,
+    )]
+    pub directory: Option<Account<'info, Directory>>,
 }
 
 pub fn deregister_transceiver(ctx: Context<DeregisterTransceiver>) -> Result<()> {
+    let before = AuditLog::digest(&(
+        ctx.accounts.config.enabled_transceivers,
+        ctx.accounts.config.threshold,
+    ))?;
+
     ctx.accounts
         .config
         .enabled_transceivers
@@ -172,6 +750,26 @@ pub fn deregister_transceiver(ctx: Context<DeregisterTransceiver>) -> Result<()>
         // threshold should be at least 1
         ctx.accounts.config.threshold = num_enabled_transceivers.max(1);
     }
+
+    if let Some(directory) = &mut ctx.accounts.directory {
+        directory.set_transceiver(directory_transceiver(
+            &ctx.accounts.config,
+            &ctx.accounts.registered_transceiver,
+        )?)?;
+    }
+
+    let after = AuditLog::digest(&(
+        ctx.accounts.config.enabled_transceivers,
+        ctx.accounts.config.threshold,
+    ))?;
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::DeregisterTransceiver,
+        ctx.accounts.owner.key(),
+        ctx.accounts.registered_transceiver.transceiver_address,
+        before,
+        after,
+    )?;
     Ok(())
 }
 
@@ -188,6 +786,15 @@ pub struct SetOutboundLimit<'info> {
 
     #[account(mut)]
     pub rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -199,7 +806,16 @@ pub fn set_outbound_limit(
     ctx: Context<SetOutboundLimit>,
     args: SetOutboundLimitArgs,
 ) -> Result<()> {
+    let before = AuditLog::digest(&ctx.accounts.rate_limit.limit)?;
     ctx.accounts.rate_limit.set_limit(args.limit);
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetOutboundLimit,
+        ctx.accounts.owner.key(),
+        ctx.accounts.rate_limit.key(),
+        before,
+        AuditLog::digest(&args.limit)?,
+    )?;
     Ok(())
 }
 
@@ -222,6 +838,15 @@ pub struct SetInboundLimit<'info> {
         bump = rate_limit.bump
     )]
     pub rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -231,7 +856,406 @@ pub struct SetInboundLimitArgs {
 }
 
 pub fn set_inbound_limit(ctx: Context<SetInboundLimit>, args: SetInboundLimitArgs) -> Result<()> {
+    let before = AuditLog::digest(&ctx.accounts.rate_limit.limit)?;
     ctx.accounts.rate_limit.set_limit(args.limit);
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetInboundLimit,
+        ctx.accounts.owner.key(),
+        ctx.accounts.rate_limit.key(),
+        before,
+        AuditLog::digest(&args.limit)?,
+    )?;
+    Ok(())
+}
+
+// * Rate limit exemptions
+
+#[derive(Accounts)]
+#[instruction(args: SetRateLimitExemptionArgs)]
+pub struct SetRateLimitExemption<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + RateLimitExemption::INIT_SPACE,
+        payer = payer,
+        seeds = [RateLimitExemption::SEED_PREFIX, args.sender.as_ref()],
+        bump,
+    )]
+    pub exemption: Account<'info, RateLimitExemption>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetRateLimitExemptionArgs {
+    pub sender: Pubkey,
+    /// The limit of the sender's dedicated rate limit.
+    pub limit: u64,
+}
+
+/// Exempts `sender` from the outbound rate limit: its transfers that pass the
+/// exemption account draw from a dedicated rate limit with `limit` instead.
+/// If the sender is already exempt, only its limit is updated.
+pub fn set_rate_limit_exemption(
+    ctx: Context<SetRateLimitExemption>,
+    args: SetRateLimitExemptionArgs,
+) -> Result<()> {
+    let exemption = &mut ctx.accounts.exemption;
+    let before = AuditLog::digest(&exemption.rate_limit.limit)?;
+
+    if exemption.sender == Pubkey::default() {
+        exemption.set_inner(RateLimitExemption {
+            bump: ctx.bumps.exemption,
+            sender: args.sender,
+            rate_limit: RateLimitState::new(args.limit),
+        });
+    } else {
+        exemption.rate_limit.set_limit(args.limit);
+    }
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetRateLimitExemption,
+        ctx.accounts.owner.key(),
+        ctx.accounts.exemption.key(),
+        before,
+        AuditLog::digest(&args.limit)?,
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(sender: Pubkey)]
+pub struct RemoveRateLimitExemption<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [RateLimitExemption::SEED_PREFIX, sender.as_ref()],
+        bump = exemption.bump,
+    )]
+    pub exemption: Account<'info, RateLimitExemption>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+/// Removes the exemption of `sender`, whose transfers then draw from the
+/// outbound rate limit again. The rent is refunded to the owner.
+pub fn remove_rate_limit_exemption(
+    ctx: Context<RemoveRateLimitExemption>,
+    _sender: Pubkey,
+) -> Result<()> {
+    let before = AuditLog::digest(&ctx.accounts.exemption.rate_limit.limit)?;
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::RemoveRateLimitExemption,
+        ctx.accounts.owner.key(),
+        ctx.accounts.exemption.key(),
+        before,
+        AuditLog::digest(&())?,
+    )?;
+    Ok(())
+}
+
+// * Rate limit strict mode
+
+#[derive(Accounts)]
+pub struct SetOutboundStrictMode<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub rate_limit: Account<'info, OutboxRateLimit>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+/// Sets whether outbound transfers queued by the rate limit reserve capacity
+/// (see [`crate::queue::rate_limit::RateLimitSettingsV1::strict`]). Rate
+/// limits created before the settings were added are grown to fit them (the
+/// payer covers the additional rent).
+pub fn set_outbound_strict_mode(ctx: Context<SetOutboundStrictMode>, strict: bool) -> Result<()> {
+    grow_account(
+        &ctx.accounts.rate_limit.to_account_info(),
+        8 + OutboxRateLimit::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut settings = ctx.accounts.rate_limit.settings.current();
+    let before = AuditLog::digest(&settings.strict)?;
+    settings.strict = strict;
+    ctx.accounts.rate_limit.settings = RateLimitSettings::V1(settings);
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetOutboundStrictMode,
+        ctx.accounts.owner.key(),
+        ctx.accounts.rate_limit.key(),
+        before,
+        AuditLog::digest(&strict)?,
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetInboundStrictModeArgs)]
+pub struct SetInboundStrictMode<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, InboxRateLimit>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetInboundStrictModeArgs {
+    pub chain_id: ChainId,
+    pub strict: bool,
+}
+
+/// Inbound counterpart of [`set_outbound_strict_mode`], for the rate limit of
+/// one chain.
+pub fn set_inbound_strict_mode(
+    ctx: Context<SetInboundStrictMode>,
+    args: SetInboundStrictModeArgs,
+) -> Result<()> {
+    grow_account(
+        &ctx.accounts.rate_limit.to_account_info(),
+        8 + InboxRateLimit::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut settings = ctx.accounts.rate_limit.settings.current();
+    let before = AuditLog::digest(&settings.strict)?;
+    settings.strict = args.strict;
+    ctx.accounts.rate_limit.settings = RateLimitSettings::V1(settings);
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetInboundStrictMode,
+        ctx.accounts.owner.key(),
+        ctx.accounts.rate_limit.key(),
+        before,
+        AuditLog::digest(&args.strict)?,
+    )?;
+    Ok(())
+}
+
+// * Strict dust mode
+
+#[derive(Accounts)]
+pub struct SetStrictDustMode<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+/// Sets whether outbound transfers with dust are rejected instead of having
+/// it removed (see [`DustSettingsV1::strict`]). Configs created before the
+/// setting was added are grown to fit it (the payer covers the additional
+/// rent).
+pub fn set_strict_dust_mode(ctx: Context<SetStrictDustMode>, strict: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let before = AuditLog::digest(&config.dust.current().strict)?;
+    config.materialize_records();
+    config.dust = DustSettings::V1(DustSettingsV1 { strict });
+
+    grow_account(
+        &ctx.accounts.config.to_account_info(),
+        8 + Config::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetStrictDustMode,
+        ctx.accounts.owner.key(),
+        ctx.accounts.config.key(),
+        before,
+        AuditLog::digest(&strict)?,
+    )?;
+    Ok(())
+}
+
+// * Backflow
+
+#[derive(Accounts)]
+#[instruction(args: SetBackflowArgs)]
+pub struct SetBackflow<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref()
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, InboxRateLimit>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetBackflowArgs {
+    pub chain_id: ChainId,
+    /// Backflow of transfers sent to the chain, into its inbound rate limit.
+    pub outbound: Backflow,
+    /// Backflow of transfers received from the chain, into the outbound rate
+    /// limit.
+    pub inbound: Backflow,
+}
+
+/// Sets how much of the transfers to and from a chain flows back into the
+/// rate limit of the opposite direction (see
+/// [`crate::queue::rate_limit::Backflow`]). Rate limits created before the
+/// backflow settings were added are grown to fit them (the payer covers the
+/// additional rent).
+pub fn set_backflow(ctx: Context<SetBackflow>, args: SetBackflowArgs) -> Result<()> {
+    if !args.outbound.is_valid() || !args.inbound.is_valid() {
+        return Err(NTTError::InvalidBackflow.into());
+    }
+
+    grow_account(
+        &ctx.accounts.rate_limit.to_account_info(),
+        8 + InboxRateLimit::INIT_SPACE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let before = AuditLog::digest(&ctx.accounts.rate_limit.backflow.current())?;
+    let backflow = BackflowSettingsV1 {
+        outbound: args.outbound,
+        inbound: args.inbound,
+    };
+    // the backflow settings are stored after the rate limit settings, so
+    // those must be written too
+    let settings = ctx.accounts.rate_limit.settings.current();
+    ctx.accounts.rate_limit.settings = RateLimitSettings::V1(settings);
+    ctx.accounts.rate_limit.backflow = BackflowSettings::V1(backflow.clone());
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetBackflow,
+        ctx.accounts.owner.key(),
+        ctx.accounts.rate_limit.key(),
+        before,
+        AuditLog::digest(&backflow)?,
+    )?;
     Ok(())
 }
 
@@ -246,10 +1270,28 @@ pub struct SetPaused<'info> {
         has_one = owner,
     )]
     pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let before = AuditLog::digest(&ctx.accounts.config.paused)?;
     ctx.accounts.config.paused = paused;
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetPaused,
+        ctx.accounts.owner.key(),
+        ctx.accounts.config.key(),
+        before,
+        AuditLog::digest(&paused)?,
+    )?;
     Ok(())
 }
 
@@ -266,12 +1308,30 @@ pub struct SetThreshold<'info> {
         constraint = threshold <= config.enabled_transceivers.len() @ NTTError::ThresholdTooHigh
     )]
     pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 pub fn set_threshold(ctx: Context<SetThreshold>, threshold: u8) -> Result<()> {
     if threshold == 0 {
         return Err(NTTError::ZeroThreshold.into());
     }
+    let before = AuditLog::digest(&ctx.accounts.config.threshold)?;
     ctx.accounts.config.threshold = threshold;
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetThreshold,
+        ctx.accounts.owner.key(),
+        ctx.accounts.config.key(),
+        before,
+        AuditLog::digest(&threshold)?,
+    )?;
     Ok(())
 }
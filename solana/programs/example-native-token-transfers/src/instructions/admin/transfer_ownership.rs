@@ -4,7 +4,13 @@ use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradea
 #[cfg(feature = "idl-build")]
 use crate::messages::Hack;
 
-use crate::{config::Config, error::NTTError};
+use crate::{
+    audit_log::{AuditAction, AuditDigest, AuditLog},
+    clock::current_timestamp,
+    config::Config,
+    error::NTTError,
+    layout::grow_account,
+};
 
 // * Transfer ownership
 
@@ -13,8 +19,10 @@ use crate::{config::Config, error::NTTError};
 /// This is to prevent a situation where the ownership is transferred to an
 /// address that is not able to claim the ownership (by mistake).
 ///
-/// The transfer can be cancelled by the existing owner invoking the [`claim_ownership`]
-/// instruction.
+/// The new owner has [`crate::PENDING_AUTHORITY_VALIDITY`] seconds to claim the ownership,
+/// after which the transfer expires. The transfer can be cancelled (whether it has expired or
+/// not) by the existing owner invoking the [`cancel_ownership_transfer`] (or the
+/// [`claim_ownership`]) instruction, which also returns the upgrade authority to the owner.
 ///
 /// Alternatively, the ownership can be transferred in a single step by calling the
 /// [`transfer_ownership_one_step_unchecked`] instruction. This can be dangerous because if the new owner
@@ -53,10 +61,58 @@ pub struct TransferOwnership<'info> {
     program_data: Account<'info, ProgramData>,
 
     bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+
+    // NOTE: the accounts below are only needed to grow configs created before
+    // [`Config::pending_owner_expiry`] and the trailing records were
+    // introduced, the first time an ownership transfer is initiated.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
 }
 
 pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
+    let before = ownership_digest(&ctx.accounts.config)?;
     ctx.accounts.config.pending_owner = Some(ctx.accounts.new_owner.key());
+    ctx.accounts.config.pending_owner_expiry =
+        current_timestamp() + crate::PENDING_AUTHORITY_VALIDITY;
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::TransferOwnership,
+        ctx.accounts.owner.key(),
+        ctx.accounts.new_owner.key(),
+        before,
+        ownership_digest(&ctx.accounts.config)?,
+    )?;
+
+    // the config must fit the pending owner, and the trailing records must be
+    // written so that clearing the pending owner doesn't leave them to be read
+    // from its stale bytes
+    ctx.accounts.config.materialize_records();
+    let space = 8 + Config::INIT_SPACE;
+    let config = ctx.accounts.config.to_account_info();
+    if config.data_len() < space {
+        let (Some(payer), Some(system_program)) =
+            (&ctx.accounts.payer, &ctx.accounts.system_program)
+        else {
+            return Err(ErrorCode::AccountNotEnoughKeys.into());
+        };
+        grow_account(
+            &config,
+            space,
+            &payer.to_account_info(),
+            &system_program.to_account_info(),
+        )?;
+    }
 
     // only transfer authority when the authority is not already the upgrade lock
     if ctx.accounts.program_data.upgrade_authority_address != Some(ctx.accounts.upgrade_lock.key())
@@ -80,8 +136,18 @@ pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
 }
 
 pub fn transfer_ownership_one_step_unchecked(ctx: Context<TransferOwnership>) -> Result<()> {
+    let before = ownership_digest(&ctx.accounts.config)?;
     ctx.accounts.config.pending_owner = None;
+    ctx.accounts.config.pending_owner_expiry = 0;
     ctx.accounts.config.owner = ctx.accounts.new_owner.key();
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::TransferOwnershipOneStepUnchecked,
+        ctx.accounts.owner.key(),
+        ctx.accounts.new_owner.key(),
+        before,
+        ownership_digest(&ctx.accounts.config)?,
+    )?;
 
     // NOTE: unlike in `transfer_ownership`, we use the unchecked version of the
     // `set_upgrade_authority` instruction here. The checked version requires
@@ -132,11 +198,37 @@ pub struct ClaimOwnership<'info> {
     program_data: Account<'info, ProgramData>,
 
     bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 pub fn claim_ownership(ctx: Context<ClaimOwnership>) -> Result<()> {
+    // the current owner can always reclaim (i.e. cancel the transfer)
+    if ctx.accounts.config.owner != ctx.accounts.new_owner.key()
+        && ctx.accounts.config.pending_owner_expired()
+    {
+        return Err(NTTError::PendingOwnerExpired.into());
+    }
+
+    let before = ownership_digest(&ctx.accounts.config)?;
     ctx.accounts.config.pending_owner = None;
+    ctx.accounts.config.pending_owner_expiry = 0;
     ctx.accounts.config.owner = ctx.accounts.new_owner.key();
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::ClaimOwnership,
+        ctx.accounts.new_owner.key(),
+        ctx.accounts.new_owner.key(),
+        before,
+        ownership_digest(&ctx.accounts.config)?,
+    )?;
 
     bpf_loader_upgradeable::set_upgrade_authority_checked(
         CpiContext::new_with_signer(
@@ -153,3 +245,78 @@ pub fn claim_ownership(ctx: Context<ClaimOwnership>) -> Result<()> {
         &crate::ID,
     )
 }
+
+// * Cancel ownership transfer
+
+#[derive(Accounts)]
+pub struct CancelOwnershipTransfer<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = config.pending_owner.is_some() @ NTTError::NoPendingOwner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"upgrade_lock"],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct address
+    upgrade_lock: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable_program,
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+/// Cancels a pending (possibly expired) ownership transfer, and returns the
+/// upgrade authority from the upgrade lock to the owner.
+pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+    let before = ownership_digest(&ctx.accounts.config)?;
+    let cancelled_owner = ctx.accounts.config.pending_owner.take();
+    ctx.accounts.config.pending_owner_expiry = 0;
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::CancelOwnershipTransfer,
+        ctx.accounts.owner.key(),
+        cancelled_owner.unwrap_or_default(),
+        before,
+        ownership_digest(&ctx.accounts.config)?,
+    )?;
+
+    bpf_loader_upgradeable::set_upgrade_authority_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts
+                .bpf_loader_upgradeable_program
+                .to_account_info(),
+            bpf_loader_upgradeable::SetUpgradeAuthorityChecked {
+                program_data: ctx.accounts.program_data.to_account_info(),
+                current_authority: ctx.accounts.upgrade_lock.to_account_info(),
+                new_authority: ctx.accounts.owner.to_account_info(),
+            },
+            &[&[b"upgrade_lock", &[ctx.bumps.upgrade_lock]]],
+        ),
+        &crate::ID,
+    )
+}
+
+fn ownership_digest(config: &Config) -> Result<AuditDigest> {
+    AuditLog::digest(&(config.owner, config.pending_owner))
+}
@@ -2,7 +2,14 @@ use anchor_lang::prelude::*;
 use anchor_spl::{token_2022::spl_token_2022::instruction::AuthorityType, token_interface};
 
 use crate::{
-    config::Config, error::NTTError, pending_token_authority::PendingTokenAuthority,
+    audit_log::{AuditAction, AuditDigest, AuditLog},
+    clock::current_timestamp,
+    config::Config,
+    error::NTTError,
+    layout::grow_account,
+    pending_token_authority::{
+        PendingTokenAuthority, PendingTokenAuthorityExpiry, PendingTokenAuthorityExpiryV1,
+    },
     spl_multisig::SplMultisig,
 };
 
@@ -41,9 +48,24 @@ pub struct AcceptTokenAuthority<'info> {
     pub common: AcceptTokenAuthorityBase<'info>,
 
     pub current_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 pub fn accept_token_authority(ctx: Context<AcceptTokenAuthority>) -> Result<()> {
+    let new_authority = match &ctx.accounts.common.multisig_token_authority {
+        Some(multisig_token_authority) => multisig_token_authority.key(),
+        None => ctx.accounts.common.token_authority.key(),
+    };
+    let before = mint_authority_digest(&ctx.accounts.common.mint)?;
+
     token_interface::set_authority(
         CpiContext::new(
             ctx.accounts.common.token_program.to_account_info(),
@@ -53,11 +75,18 @@ pub fn accept_token_authority(ctx: Context<AcceptTokenAuthority>) -> Result<()>
             },
         ),
         AuthorityType::MintTokens,
-        Some(match &ctx.accounts.common.multisig_token_authority {
-            Some(multisig_token_authority) => multisig_token_authority.key(),
-            None => ctx.accounts.common.token_authority.key(),
-        }),
-    )
+        Some(new_authority),
+    )?;
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::AcceptTokenAuthority,
+        ctx.accounts.current_authority.key(),
+        ctx.accounts.common.mint.key(),
+        before,
+        AuditLog::digest(&Some(new_authority))?,
+    )?;
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -66,6 +95,15 @@ pub struct AcceptTokenAuthorityFromMultisig<'info> {
 
     /// CHECK: The remaining accounts are treated as required signers for the multisig
     pub current_multisig_authority: InterfaceAccount<'info, SplMultisig>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 pub fn accept_token_authority_from_multisig<'info>(
@@ -75,6 +113,7 @@ pub fn accept_token_authority_from_multisig<'info>(
         Some(multisig_token_authority) => multisig_token_authority.to_account_info(),
         None => ctx.accounts.common.token_authority.to_account_info(),
     };
+    let before = mint_authority_digest(&ctx.accounts.common.mint)?;
 
     let mut signer_pubkeys: Vec<&Pubkey> = Vec::new();
     let mut account_infos = vec![
@@ -100,6 +139,15 @@ pub fn accept_token_authority_from_multisig<'info>(
         )?,
         account_infos.as_slice(),
     )?;
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::AcceptTokenAuthorityFromMultisig,
+        ctx.accounts.current_multisig_authority.key(),
+        ctx.accounts.common.mint.key(),
+        before,
+        AuditLog::digest(&Some(new_authority.key()))?,
+    )?;
     Ok(())
 }
 
@@ -142,11 +190,22 @@ pub struct SetTokenAuthorityUnchecked<'info> {
     pub common: SetTokenAuthority<'info>,
 
     pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 pub fn set_token_authority_one_step_unchecked(
     ctx: Context<SetTokenAuthorityUnchecked>,
 ) -> Result<()> {
+    let before = mint_authority_digest(&ctx.accounts.common.mint)?;
+
     match &ctx.accounts.common.multisig_token_authority {
         Some(multisig_token_authority) => claim_from_multisig_token_authority(
             ctx.accounts.token_program.to_account_info(),
@@ -155,15 +214,25 @@ pub fn set_token_authority_one_step_unchecked(
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.common.new_authority.key(),
-        ),
+        )?,
         None => claim_from_token_authority(
             ctx.accounts.token_program.to_account_info(),
             ctx.accounts.common.mint.to_account_info(),
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.common.new_authority.key(),
-        ),
-    }
+        )?,
+    };
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetTokenAuthorityOneStepUnchecked,
+        ctx.accounts.common.owner.key(),
+        ctx.accounts.common.mint.key(),
+        before,
+        AuditLog::digest(&Some(ctx.accounts.common.new_authority.key()))?,
+    )?;
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -183,7 +252,14 @@ pub struct SetTokenAuthorityChecked<'info> {
 
     #[account(
         init_if_needed,
-        space = 8 + PendingTokenAuthority::INIT_SPACE,
+        // NOTE: pending token authorities created before the expiry was
+        // introduced are smaller. They're accepted as they are here, and grown
+        // in [`set_token_authority`].
+        space = if pending_token_authority.data_is_empty() {
+            8 + PendingTokenAuthority::INIT_SPACE
+        } else {
+            pending_token_authority.data_len()
+        },
         payer = rent_payer,
         seeds = [PendingTokenAuthority::SEED_PREFIX],
         bump
@@ -191,9 +267,27 @@ pub struct SetTokenAuthorityChecked<'info> {
     pub pending_token_authority: Account<'info, PendingTokenAuthority>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 pub fn set_token_authority(ctx: Context<SetTokenAuthorityChecked>) -> Result<()> {
+    let before = AuditLog::digest(&ctx.accounts.pending_token_authority.pending_authority)?;
+
+    grow_account(
+        &ctx.accounts.pending_token_authority.to_account_info(),
+        8 + PendingTokenAuthority::INIT_SPACE,
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
     ctx.accounts
         .pending_token_authority
         .set_inner(PendingTokenAuthority {
@@ -205,7 +299,19 @@ pub fn set_token_authority(ctx: Context<SetTokenAuthorityChecked>) -> Result<()>
             } else {
                 ctx.accounts.rent_payer.key()
             },
+            expiry: PendingTokenAuthorityExpiry::V1(PendingTokenAuthorityExpiryV1 {
+                expiry: current_timestamp() + crate::PENDING_AUTHORITY_VALIDITY,
+            }),
         });
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetTokenAuthority,
+        ctx.accounts.common.owner.key(),
+        ctx.accounts.pending_token_authority.key(),
+        before,
+        AuditLog::digest(&ctx.accounts.common.new_authority.key())?,
+    )?;
     Ok(())
 }
 
@@ -263,23 +369,59 @@ pub struct RevertTokenAuthority<'info> {
         address = common.config.owner
     )]
     pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
-pub fn revert_token_authority(_ctx: Context<RevertTokenAuthority>) -> Result<()> {
+pub fn revert_token_authority(ctx: Context<RevertTokenAuthority>) -> Result<()> {
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::RevertTokenAuthority,
+        ctx.accounts.owner.key(),
+        ctx.accounts.common.pending_token_authority.key(),
+        AuditLog::digest(
+            &ctx.accounts
+                .common
+                .pending_token_authority
+                .pending_authority,
+        )?,
+        AuditLog::digest(&Pubkey::default())?,
+    )?;
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct ClaimTokenAuthority<'info> {
+    #[account(
+        constraint = !common.pending_token_authority.is_expired() @ NTTError::PendingTokenAuthorityExpired,
+    )]
     pub common: ClaimTokenAuthorityBase<'info>,
 
     #[account(
         address = common.pending_token_authority.pending_authority @ NTTError::InvalidPendingTokenAuthority
     )]
     pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 pub fn claim_token_authority(ctx: Context<ClaimTokenAuthority>) -> Result<()> {
+    let before = mint_authority_digest(&ctx.accounts.common.mint)?;
+
     match &ctx.accounts.common.multisig_token_authority {
         Some(multisig_token_authority) => claim_from_multisig_token_authority(
             ctx.accounts.common.token_program.to_account_info(),
@@ -288,19 +430,32 @@ pub fn claim_token_authority(ctx: Context<ClaimTokenAuthority>) -> Result<()> {
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.new_authority.key(),
-        ),
+        )?,
         None => claim_from_token_authority(
             ctx.accounts.common.token_program.to_account_info(),
             ctx.accounts.common.mint.to_account_info(),
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.new_authority.key(),
-        ),
-    }
+        )?,
+    };
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::ClaimTokenAuthority,
+        ctx.accounts.new_authority.key(),
+        ctx.accounts.common.mint.key(),
+        before,
+        AuditLog::digest(&Some(ctx.accounts.new_authority.key()))?,
+    )?;
+    Ok(())
 }
 
 #[derive(Accounts)]
 pub struct ClaimTokenAuthorityToMultisig<'info> {
+    #[account(
+        constraint = !common.pending_token_authority.is_expired() @ NTTError::PendingTokenAuthorityExpired,
+    )]
     pub common: ClaimTokenAuthorityBase<'info>,
 
     #[account(
@@ -308,11 +463,22 @@ pub struct ClaimTokenAuthorityToMultisig<'info> {
     )]
     /// CHECK: The remaining accounts are treated as required signers for the multisig to be validated
     pub new_multisig_authority: InterfaceAccount<'info, SplMultisig>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 pub fn claim_token_authority_to_multisig(
     ctx: Context<ClaimTokenAuthorityToMultisig>,
 ) -> Result<()> {
+    let before = mint_authority_digest(&ctx.accounts.common.mint)?;
+
     // SPL Multisig cannot be a Signer so we simulate multisig signing using ctx.remaining_accounts as
     // required signers to validate it
     {
@@ -334,15 +500,53 @@ pub fn claim_token_authority_to_multisig(
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.new_multisig_authority.key(),
-        ),
+        )?,
         None => claim_from_token_authority(
             ctx.accounts.common.token_program.to_account_info(),
             ctx.accounts.common.mint.to_account_info(),
             ctx.accounts.common.token_authority.to_account_info(),
             ctx.bumps.common.token_authority,
             ctx.accounts.new_multisig_authority.key(),
-        ),
-    }
+        )?,
+    };
+
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::ClaimTokenAuthorityToMultisig,
+        ctx.accounts.new_multisig_authority.key(),
+        ctx.accounts.common.mint.key(),
+        before,
+        AuditLog::digest(&Some(ctx.accounts.new_multisig_authority.key()))?,
+    )?;
+    Ok(())
+}
+
+// * Close expired pending token authority
+
+/// Once a pending token authority has expired, it can no longer be claimed. This
+/// permissionless instruction closes it, refunding the rent to the rent payer.
+/// The owner can also close it (expired or not) with [`revert_token_authority`].
+#[derive(Accounts)]
+pub struct CloseExpiredPendingTokenAuthority<'info> {
+    #[account(mut)]
+    /// CHECK: the `pending_token_authority` constraint enforces that this is the correct address
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PendingTokenAuthority::SEED_PREFIX],
+        bump = pending_token_authority.bump,
+        has_one = rent_payer @ NTTError::IncorrectRentPayer,
+        constraint = pending_token_authority.is_expired() @ NTTError::PendingTokenAuthorityNotExpired,
+        close = rent_payer
+     )]
+    pub pending_token_authority: Account<'info, PendingTokenAuthority>,
+}
+
+pub fn close_expired_pending_token_authority(
+    _ctx: Context<CloseExpiredPendingTokenAuthority>,
+) -> Result<()> {
+    Ok(())
 }
 
 fn claim_from_token_authority<'info>(
@@ -389,3 +593,7 @@ fn claim_from_multisig_token_authority<'info>(
     )?;
     Ok(())
 }
+
+fn mint_authority_digest(mint: &token_interface::Mint) -> Result<AuditDigest> {
+    AuditLog::digest(&Option::<Pubkey>::from(mint.mint_authority))
+}
@@ -0,0 +1,102 @@
+//! The audit log is a fixed-size account that records every administrative
+//! action (see [`crate::audit_log::AuditLog`]).
+//!
+//! Admin instructions always take the account, but only record entries once it
+//! has been created (either right after [`crate::instructions::initialize`],
+//! or after upgrading an existing deployment).
+//!
+//! Creating the account is permissionless: it takes no arguments, and its
+//! contents are entirely determined by the program, so there is nothing for a
+//! third party to tamper with.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    audit_log::{AuditAction, AuditLog},
+    config::Config,
+    registered_transceiver::RegisteredTransceiver,
+};
+
+#[derive(Accounts)]
+pub struct InitializeAuditLog<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AuditLog::INIT_SPACE,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_audit_log(ctx: Context<InitializeAuditLog>) -> Result<()> {
+    ctx.accounts.audit_log.set_inner(AuditLog {
+        bump: ctx.bumps.audit_log,
+        head: 0,
+        total_entries: 0,
+        entries: Vec::new(),
+    });
+    Ok(())
+}
+
+// * Transceiver admin actions
+
+pub const AUDIT_LOG_SIGNER_SEED: &[u8] = b"audit_log_signer";
+
+/// Lets a standalone transceiver program record its own admin actions (e.g.
+/// setting a transceiver peer) in the audit log, as it can't write to an
+/// account owned by this program.
+///
+/// The entry is attributed to the owner, whose signature is forwarded by the
+/// transceiver program through the CPI.
+#[derive(Accounts)]
+pub struct RecordTransceiverAdminAction<'info> {
+    #[account(
+        seeds = [AUDIT_LOG_SIGNER_SEED],
+        seeds::program = transceiver.transceiver_address,
+        bump
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    pub transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct RecordTransceiverAdminActionArgs {
+    pub action: AuditAction,
+    pub subject: Pubkey,
+    pub before: [u8; 32],
+    pub after: [u8; 32],
+}
+
+pub fn record_transceiver_admin_action(
+    ctx: Context<RecordTransceiverAdminAction>,
+    args: RecordTransceiverAdminActionArgs,
+) -> Result<()> {
+    ctx.accounts.audit_log.record(
+        args.action,
+        ctx.accounts.owner.key(),
+        args.subject,
+        args.before,
+        args.after,
+    )
+}
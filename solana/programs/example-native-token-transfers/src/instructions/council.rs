@@ -0,0 +1,360 @@
+//! Native M-of-N ownership.
+//!
+//! Instead of a single key (or an external multisig / governance program), the
+//! program can be owned by a [`Council`]. Setting it up is a 3-step process:
+//!
+//! 1. the current owner creates the council with [`initialize_council`],
+//! 2. the current owner transfers ownership to the council PDA with
+//!    [`crate::instructions::transfer_ownership`] (which moves the upgrade
+//!    authority to the upgrade lock),
+//! 3. the council executes a proposal calling
+//!    [`crate::instructions::claim_ownership`], signed by the council PDA.
+//!    This makes the council the owner and the upgrade authority of the
+//!    program.
+//!
+//! From then on, any member can propose an instruction with
+//! [`propose_council_instruction`], the other members approve it with
+//! [`approve_council_proposal`], and once the threshold is met, anyone can
+//! execute it with [`execute_council_proposal`]. The council PDA signs the
+//! executed instruction, so it can call any owner-gated instruction of this
+//! program (including [`set_council_members`] and the ownership transfer
+//! instructions, to leave council mode), as well as upgrade the program through
+//! the BPF loader.
+
+use anchor_lang::prelude::*;
+use solana_program::instruction::Instruction;
+
+use crate::{
+    audit_log::{AuditAction, AuditDigest, AuditLog},
+    config::Config,
+    council::{Council, CouncilInstruction, CouncilProposal, COUNCIL_PAYER, COUNCIL_PAYER_SEED},
+    error::NTTError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetCouncilMembersArgs {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+// * Initialize council
+
+#[derive(Accounts)]
+pub struct InitializeCouncil<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(has_one = owner)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Council::INIT_SPACE,
+        seeds = [Council::SEED_PREFIX],
+        bump,
+    )]
+    pub council: Account<'info, Council>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+pub fn initialize_council(
+    ctx: Context<InitializeCouncil>,
+    args: SetCouncilMembersArgs,
+) -> Result<()> {
+    ctx.accounts.council.set_inner(Council {
+        bump: ctx.bumps.council,
+        members: Vec::new(),
+        threshold: 0,
+        generation: 0,
+        next_proposal_id: 0,
+    });
+    let before = council_digest(&ctx.accounts.council)?;
+    ctx.accounts
+        .council
+        .set_members(args.members, args.threshold)?;
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::InitializeCouncil,
+        ctx.accounts.owner.key(),
+        ctx.accounts.council.key(),
+        before,
+        council_digest(&ctx.accounts.council)?,
+    )?;
+    Ok(())
+}
+
+// * Set council members
+
+/// Replaces the members and threshold of the council. Once the council owns the
+/// program, this is only callable through a council proposal.
+///
+/// All outstanding proposals are invalidated.
+#[derive(Accounts)]
+pub struct SetCouncilMembers<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(has_one = owner)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Council::SEED_PREFIX],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+pub fn set_council_members(
+    ctx: Context<SetCouncilMembers>,
+    args: SetCouncilMembersArgs,
+) -> Result<()> {
+    let before = council_digest(&ctx.accounts.council)?;
+    ctx.accounts
+        .council
+        .set_members(args.members, args.threshold)?;
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetCouncilMembers,
+        ctx.accounts.owner.key(),
+        ctx.accounts.council.key(),
+        before,
+        council_digest(&ctx.accounts.council)?,
+    )?;
+    Ok(())
+}
+
+// * Propose
+
+#[derive(Accounts)]
+#[instruction(council_instruction: CouncilInstruction)]
+pub struct ProposeCouncilInstruction<'info> {
+    /// The proposing member. Pays for the proposal account, and is refunded
+    /// when it's closed.
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Council::SEED_PREFIX],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + CouncilProposal::space(&council_instruction),
+        seeds = [
+            CouncilProposal::SEED_PREFIX,
+            council.next_proposal_id.to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a new proposal to execute `council_instruction`. The proposer's
+/// approval is recorded straight away.
+///
+/// The instruction may reference [`COUNCIL_PAYER`] in place of an account that
+/// needs to pay for something, which will be substituted with the council
+/// payer PDA in [`execute_council_proposal`].
+///
+/// The instruction may not reference the proposal account itself: the executed
+/// instruction could close or modify it, which would then be clobbered (or fail
+/// to be written back) when [`execute_council_proposal`] returns.
+pub fn propose_council_instruction(
+    ctx: Context<ProposeCouncilInstruction>,
+    council_instruction: CouncilInstruction,
+) -> Result<()> {
+    if council_instruction
+        .accounts
+        .iter()
+        .any(|acc| acc.pubkey == ctx.accounts.proposal.key())
+    {
+        return Err(NTTError::CouncilProposalSelfReference.into());
+    }
+
+    let council = &mut ctx.accounts.council;
+    let proposer_index = council.member_index(&ctx.accounts.proposer.key())?;
+
+    let mut proposal = CouncilProposal {
+        bump: ctx.bumps.proposal,
+        id: council.next_proposal_id,
+        generation: council.generation,
+        proposer: ctx.accounts.proposer.key(),
+        approvals: Default::default(),
+        executed: false,
+        instruction: council_instruction,
+    };
+    proposal.approvals.set(proposer_index, true)?;
+
+    ctx.accounts.proposal.set_inner(proposal);
+    council.next_proposal_id += 1;
+
+    Ok(())
+}
+
+// * Approve
+
+#[derive(Accounts)]
+pub struct ApproveCouncilProposal<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [Council::SEED_PREFIX],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [CouncilProposal::SEED_PREFIX, proposal.id.to_be_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ NTTError::CouncilProposalAlreadyExecuted,
+        constraint = proposal.generation == council.generation @ NTTError::StaleCouncilProposal,
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+}
+
+pub fn approve_council_proposal(ctx: Context<ApproveCouncilProposal>) -> Result<()> {
+    let member_index = ctx
+        .accounts
+        .council
+        .member_index(&ctx.accounts.member.key())?;
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.approvals.get(member_index)? {
+        return Err(NTTError::CouncilProposalAlreadyApproved.into());
+    }
+    proposal.approvals.set(member_index, true)?;
+    Ok(())
+}
+
+// * Execute
+
+#[derive(Accounts)]
+pub struct ExecuteCouncilProposal<'info> {
+    #[account(
+        mut,
+        seeds = [COUNCIL_PAYER_SEED],
+        bump,
+    )]
+    /// CHECK: system-owned PDA that the program signs for in place of
+    /// [`COUNCIL_PAYER`]. Its lamports are only spent by approved proposals.
+    pub council_payer: UncheckedAccount<'info>,
+
+    // NOTE: this account is intentionally not `mut`, even if the executed
+    // instruction modifies it (e.g. [`set_council_members`]). Otherwise the
+    // stale copy held here would be written back after the CPI returns.
+    #[account(
+        seeds = [Council::SEED_PREFIX],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [CouncilProposal::SEED_PREFIX, proposal.id.to_be_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ NTTError::CouncilProposalAlreadyExecuted,
+        constraint = proposal.generation == council.generation @ NTTError::StaleCouncilProposal,
+        constraint = proposal.approvals.len() >= council.threshold @ NTTError::CouncilThresholdNotMet,
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+}
+
+/// Executes an approved proposal, with the council PDA (and the council payer
+/// PDA) as a signer. This instruction is permissionless.
+///
+/// All the accounts referenced by the proposed instruction (including the
+/// program being invoked) must be passed in `ctx.remaining_accounts`.
+pub fn execute_council_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteCouncilProposal<'info>>,
+) -> Result<()> {
+    ctx.accounts.proposal.executed = true;
+    // persist the executed flag before the CPI, so the proposal can't be
+    // executed again from within the proposed instruction
+    ctx.accounts.proposal.exit(&crate::ID)?;
+
+    let mut instruction: Instruction = ctx.accounts.proposal.instruction.clone().into();
+    instruction.accounts.iter_mut().for_each(|acc| {
+        if acc.pubkey == COUNCIL_PAYER {
+            acc.pubkey = ctx.accounts.council_payer.key();
+        }
+    });
+
+    let mut all_account_infos = ctx.accounts.to_account_infos();
+    all_account_infos.extend_from_slice(ctx.remaining_accounts);
+
+    solana_program::program::invoke_signed(
+        &instruction,
+        &all_account_infos,
+        &[
+            &[Council::SEED_PREFIX, &[ctx.accounts.council.bump]],
+            &[COUNCIL_PAYER_SEED, &[ctx.bumps.council_payer]],
+        ],
+    )?;
+
+    Ok(())
+}
+
+// * Close
+
+#[derive(Accounts)]
+pub struct CloseCouncilProposal<'info> {
+    #[account(mut, address = proposal.proposer)]
+    /// CHECK: the address constraint ensures the rent is refunded to the proposer
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [Council::SEED_PREFIX],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [CouncilProposal::SEED_PREFIX, proposal.id.to_be_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = (
+            proposal.executed
+            || proposal.generation != council.generation
+        ) @ NTTError::CouncilProposalStillActive,
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+}
+
+/// Closes an executed or stale proposal, refunding the rent to the proposer.
+/// This instruction is permissionless.
+pub fn close_council_proposal(_ctx: Context<CloseCouncilProposal>) -> Result<()> {
+    Ok(())
+}
+
+fn council_digest(council: &Council) -> Result<AuditDigest> {
+    AuditLog::digest(&(&council.members, council.threshold))
+}
@@ -0,0 +1,122 @@
+//! The directory lists the peers and registered transceivers of the deployment
+//! (see [`crate::directory::Directory`]).
+//!
+//! Admin instructions only keep it up to date once the account has been
+//! created, and passed in by the client. The sync instructions below bring
+//! entries up to date with the peer and transceiver accounts, e.g. to backfill
+//! the directory of an existing deployment after upgrading.
+//!
+//! None of these need the owner. The sync instructions only copy what the
+//! peer and transceiver accounts already say (and unlist peers whose account
+//! is gone), so a caller can bring the directory in line with the
+//! configuration, but never list anything the owner didn't set up.
+
+use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
+
+use crate::{
+    config::Config,
+    directory::{Directory, DirectoryPeer, DirectoryTransceiver},
+    peer::NttManagerPeer,
+    registered_transceiver::RegisteredTransceiver,
+};
+
+#[derive(Accounts)]
+pub struct InitializeDirectory<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Directory::INIT_SPACE,
+        seeds = [Directory::SEED_PREFIX],
+        bump,
+    )]
+    pub directory: Account<'info, Directory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_directory(ctx: Context<InitializeDirectory>) -> Result<()> {
+    ctx.accounts.directory.set_inner(Directory {
+        bump: ctx.bumps.directory,
+        peers: Vec::new(),
+        transceivers: Vec::new(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: ChainId)]
+pub struct SyncDirectoryPeer<'info> {
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.id.to_be_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the peer account of
+    /// `chain_id`. It's deserialized in [`sync_directory_peer`] if it exists.
+    pub peer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Directory::SEED_PREFIX],
+        bump = directory.bump,
+    )]
+    pub directory: Account<'info, Directory>,
+}
+
+/// Lists the peer of `chain_id` in the directory, or unlists it if there's no
+/// peer for that chain (anymore).
+pub fn sync_directory_peer(ctx: Context<SyncDirectoryPeer>, chain_id: ChainId) -> Result<()> {
+    let peer = ctx.accounts.peer.to_account_info();
+    if peer.data_is_empty() {
+        ctx.accounts.directory.remove_peer(chain_id);
+        return Ok(());
+    }
+
+    let peer: Account<NttManagerPeer> = Account::try_from(&peer)?;
+    ctx.accounts.directory.set_peer(DirectoryPeer {
+        chain_id,
+        address: peer.address,
+        token_decimals: peer.token_decimals,
+    })
+}
+
+#[derive(Accounts)]
+pub struct SyncDirectoryTransceiver<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, registered_transceiver.transceiver_address.as_ref()],
+        bump = registered_transceiver.bump,
+    )]
+    pub registered_transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        mut,
+        seeds = [Directory::SEED_PREFIX],
+        bump = directory.bump,
+    )]
+    pub directory: Account<'info, Directory>,
+}
+
+pub fn sync_directory_transceiver(ctx: Context<SyncDirectoryTransceiver>) -> Result<()> {
+    let accs = ctx.accounts;
+    accs.directory.set_transceiver(directory_transceiver(
+        &accs.config,
+        &accs.registered_transceiver,
+    )?)
+}
+
+/// The directory entry of `transceiver`, as currently registered in `config`.
+pub(crate) fn directory_transceiver(
+    config: &Config,
+    transceiver: &RegisteredTransceiver,
+) -> Result<DirectoryTransceiver> {
+    Ok(DirectoryTransceiver {
+        id: transceiver.id,
+        transceiver_address: transceiver.transceiver_address,
+        enabled: config.enabled_transceivers.get(transceiver.id)?,
+    })
+}
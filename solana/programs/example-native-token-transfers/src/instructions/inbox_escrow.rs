@@ -0,0 +1,444 @@
+//! Fallback for inbound transfers that can't be delivered.
+//!
+//! [`crate::instructions::release_inbound_mint`] and
+//! [`crate::instructions::release_inbound_unlock`] release to the recipient's
+//! associated token account (creating it if needed), unless the recipient
+//! address is itself a token account that accepted the transfer. If that
+//! account is frozen, the tokens
+//! would stay in custody forever. Instead, the inbox item can be escrowed:
+//!
+//! * by anyone, after [`InboxEscrow::MAX_FAILED_ATTEMPTS`] failed release
+//!   attempts (see [`report_failed_inbound_release`]), or
+//! * by the recipient, at any time after the transfer becomes releasable (see
+//!   [`request_inbound_escrow`]).
+//!
+//! Escrowed tokens are released with [`release_escrowed_inbound_mint`] or
+//! [`release_escrowed_inbound_unlock`] to the destination chosen by the
+//! recipient ([`redirect_escrowed_inbound`]), or, if the recipient doesn't
+//! act, by the owner ([`set_escrow_recovery`]) after a timelock. The owner can
+//! only route the tokens to another token account of the recipient, never
+//! elsewhere.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address_with_program_id, token_interface};
+use ntt_messages::mode::Mode;
+use spl_token_2022::onchain;
+
+use crate::{
+    audit_log::{AuditAction, AuditLog},
+    clock::current_timestamp,
+    config::*,
+    error::NTTError,
+    instructions::release_inbound::{
+        mint_to_custody_from_multisig_token_authority, mint_to_custody_from_token_authority,
+    },
+    queue::{
+        escrow::InboxEscrow,
+        inbox::{InboxItem, ReleaseStatus},
+    },
+    spl_multisig::SplMultisig,
+};
+
+// * Report failed release
+
+#[derive(Accounts)]
+pub struct ReportFailedInboundRelease<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub config: NotPausedConfig<'info>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + InboxEscrow::INIT_SPACE,
+        seeds = [InboxEscrow::SEED_PREFIX, inbox_item.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, InboxEscrow>,
+
+    #[account(
+        address = inbox_item.recipient_address,
+    )]
+    /// CHECK: the address constraint ensures this is the recipient address
+    pub recipient_address: UncheckedAccount<'info>,
+
+    /// CHECK: checked in [`is_undeliverable`] to be the token account the
+    /// transfer is released to, which may not exist yet.
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Records a failed attempt to release a releasable transfer, because the
+/// token account it's released to is frozen. After
+/// [`InboxEscrow::MAX_FAILED_ATTEMPTS`] attempts (at least
+/// [`InboxEscrow::FAILED_ATTEMPT_INTERVAL`] apart), the transfer is escrowed.
+///
+/// SECURITY: this instruction is permissionless. If the recipient's account
+/// becomes usable in the meantime, anyone can release the transfer as usual.
+pub fn report_failed_inbound_release(ctx: Context<ReportFailedInboundRelease>) -> Result<()> {
+    let accs = ctx.accounts;
+    accs.inbox_item.check_releasable()?;
+
+    if !is_undeliverable(
+        &accs.recipient,
+        &accs.recipient_address,
+        accs.inbox_item.token_account_recipient.owner(),
+        &accs.mint,
+        &accs.token_program,
+    )? {
+        return Err(NTTError::InboxItemDeliverable.into());
+    }
+
+    accs.escrow.bump = ctx.bumps.escrow;
+    accs.escrow.inbox_item = accs.inbox_item.key();
+    if accs.escrow.record_failed_attempt(current_timestamp())? {
+        accs.inbox_item.escrow()?;
+    }
+
+    Ok(())
+}
+
+/// Whether `recipient`, which must be the token account the transfer is
+/// released to (see [`crate::instructions::release_inbound::prepare_recipient`]),
+/// is frozen.
+fn is_undeliverable(
+    recipient: &AccountInfo,
+    recipient_address: &AccountInfo,
+    token_account_owner: Option<Pubkey>,
+    mint: &InterfaceAccount<token_interface::Mint>,
+    token_program: &Interface<token_interface::TokenInterface>,
+) -> Result<bool> {
+    if token_account_owner.is_some() {
+        // the recipient address is itself the token account
+        if recipient.key() != recipient_address.key() {
+            return Err(NTTError::InvalidRecipientTokenAccount.into());
+        }
+    } else {
+        let associated_token_account = get_associated_token_address_with_program_id(
+            &recipient_address.key(),
+            &mint.key(),
+            &token_program.key(),
+        );
+        if recipient.key() != associated_token_account {
+            return Err(NTTError::InvalidRecipientTokenAccount.into());
+        }
+        // a missing associated token account is created on release
+        if recipient.data_is_empty() {
+            return Ok(false);
+        }
+    }
+    let token_account =
+        token_interface::TokenAccount::try_deserialize(&mut &recipient.data.borrow()[..])?;
+    Ok(token_account.is_frozen())
+}
+
+// * Request escrow
+
+#[derive(Accounts)]
+pub struct RequestInboundEscrow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        address = inbox_item.recipient_address @ NTTError::NotInboxItemRecipient,
+    )]
+    pub recipient: Signer<'info>,
+
+    pub config: NotPausedConfig<'info>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + InboxEscrow::INIT_SPACE,
+        seeds = [InboxEscrow::SEED_PREFIX, inbox_item.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, InboxEscrow>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Escrows a releasable transfer at the recipient's request, so they can
+/// redirect it with [`redirect_escrowed_inbound`].
+pub fn request_inbound_escrow(ctx: Context<RequestInboundEscrow>) -> Result<()> {
+    let accs = ctx.accounts;
+    accs.inbox_item.escrow()?;
+    accs.escrow.bump = ctx.bumps.escrow;
+    accs.escrow.inbox_item = accs.inbox_item.key();
+    Ok(())
+}
+
+// * Redirect
+
+#[derive(Accounts)]
+pub struct RedirectEscrowedInbound<'info> {
+    #[account(
+        address = inbox_item.recipient_address @ NTTError::NotInboxItemRecipient,
+    )]
+    pub recipient: Signer<'info>,
+
+    pub config: NotPausedConfig<'info>,
+
+    #[account(
+        constraint = inbox_item.release_status == ReleaseStatus::Escrowed @ NTTError::TransferNotEscrowed,
+    )]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        mut,
+        seeds = [InboxEscrow::SEED_PREFIX, inbox_item.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, InboxEscrow>,
+
+    #[account(
+        token::mint = config.mint,
+        token::authority = recipient,
+    )]
+    pub destination: InterfaceAccount<'info, token_interface::TokenAccount>,
+}
+
+/// Redirects an escrowed transfer to another token account owned by the
+/// recipient. Takes effect immediately, and overrides any pending recovery
+/// destination set by the owner.
+pub fn redirect_escrowed_inbound(ctx: Context<RedirectEscrowedInbound>) -> Result<()> {
+    let destination = ctx.accounts.destination.key();
+    ctx.accounts
+        .escrow
+        .redirect(destination, current_timestamp());
+    Ok(())
+}
+
+// * Recovery
+
+#[derive(Accounts)]
+pub struct SetEscrowRecovery<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(has_one = owner)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = inbox_item.release_status == ReleaseStatus::Escrowed @ NTTError::TransferNotEscrowed,
+    )]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        mut,
+        seeds = [InboxEscrow::SEED_PREFIX, inbox_item.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, InboxEscrow>,
+
+    #[account(
+        token::mint = config.mint,
+        constraint = destination.owner == inbox_item.recipient_address
+            @ NTTError::InvalidEscrowRecoveryDestination,
+    )]
+    pub destination: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+/// Routes an escrowed transfer to another token account of the recipient
+/// (e.g. one that isn't frozen), effective after
+/// [`InboxEscrow::RECOVERY_TIMELOCK`]. Until then, the recipient can still
+/// redirect the transfer themselves.
+///
+/// This is for recipients that can't act themselves, like program PDAs: the
+/// destination must be owned by the recipient, so the owner can't take the
+/// tokens.
+pub fn set_escrow_recovery(ctx: Context<SetEscrowRecovery>) -> Result<()> {
+    let accs = ctx.accounts;
+    let before = AuditLog::digest(&accs.escrow.destination)?;
+    accs.escrow
+        .recover(accs.destination.key(), current_timestamp());
+    AuditLog::record_into(
+        &accs.audit_log,
+        AuditAction::SetEscrowRecovery,
+        accs.owner.key(),
+        accs.inbox_item.key(),
+        before,
+        AuditLog::digest(&accs.escrow.destination)?,
+    )?;
+    Ok(())
+}
+
+// * Release escrowed
+
+#[derive(Accounts)]
+pub struct ReleaseEscrowedInbound<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub config: NotPausedConfig<'info>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        seeds = [InboxEscrow::SEED_PREFIX, inbox_item.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.destination == Some(destination.key()) @ NTTError::NoEscrowDestination,
+    )]
+    pub escrow: Account<'info, InboxEscrow>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub destination: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.mint,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        mut,
+        address = config.custody
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+}
+
+impl<'info> ReleaseEscrowedInbound<'info> {
+    fn release(&mut self) -> Result<()> {
+        if current_timestamp() < self.escrow.destination_effective_at {
+            return Err(NTTError::EscrowRecoveryTimelocked.into());
+        }
+        self.inbox_item.release_escrowed()
+    }
+}
+
+#[derive(Accounts)]
+pub struct ReleaseEscrowedInboundMint<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+    )]
+    common: ReleaseEscrowedInbound<'info>,
+
+    #[account(
+        constraint = multisig_token_authority.m == 1
+            && multisig_token_authority.signers.contains(&common.token_authority.key())
+            @ NTTError::InvalidMultisig,
+    )]
+    pub multisig_token_authority: Option<InterfaceAccount<'info, SplMultisig>>,
+}
+
+/// Same as [`crate::instructions::release_inbound_mint`], but releases an
+/// escrowed transfer to its escrow destination.
+/// SECURITY: Signer checks are disabled here because anyone is permitted to send a release
+/// transaction.
+pub fn release_escrowed_inbound_mint<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseEscrowedInboundMint<'info>>,
+) -> Result<()> {
+    ctx.accounts.common.release()?;
+
+    let common = &ctx.accounts.common;
+    let amount = common.inbox_item.amount;
+    let token_authority_sig: &[&[&[u8]]] = &[&[
+        crate::TOKEN_AUTHORITY_SEED,
+        &[ctx.bumps.common.token_authority],
+    ]];
+
+    // mint to custody first, then transfer, as in
+    // [`crate::instructions::release_inbound_mint`]
+    match &ctx.accounts.multisig_token_authority {
+        Some(multisig_token_authority) => mint_to_custody_from_multisig_token_authority(
+            common.token_program.to_account_info(),
+            common.mint.to_account_info(),
+            common.custody.to_account_info(),
+            multisig_token_authority.to_account_info(),
+            common.token_authority.to_account_info(),
+            token_authority_sig,
+            amount,
+        )?,
+        None => mint_to_custody_from_token_authority(
+            common.token_program.to_account_info(),
+            common.mint.to_account_info(),
+            common.custody.to_account_info(),
+            common.token_authority.to_account_info(),
+            token_authority_sig,
+            amount,
+        )?,
+    };
+
+    onchain::invoke_transfer_checked(
+        &common.token_program.key(),
+        common.custody.to_account_info(),
+        common.mint.to_account_info(),
+        common.destination.to_account_info(),
+        common.token_authority.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        common.mint.decimals,
+        token_authority_sig,
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseEscrowedInboundUnlock<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    common: ReleaseEscrowedInbound<'info>,
+}
+
+/// Same as [`crate::instructions::release_inbound_unlock`], but releases an
+/// escrowed transfer to its escrow destination.
+/// SECURITY: Signer checks are disabled here because anyone is permitted to send a release
+/// transaction.
+pub fn release_escrowed_inbound_unlock<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseEscrowedInboundUnlock<'info>>,
+) -> Result<()> {
+    ctx.accounts.common.release()?;
+
+    let common = &ctx.accounts.common;
+    onchain::invoke_transfer_checked(
+        &common.token_program.key(),
+        common.custody.to_account_info(),
+        common.mint.to_account_info(),
+        common.destination.to_account_info(),
+        common.token_authority.to_account_info(),
+        ctx.remaining_accounts,
+        common.inbox_item.amount,
+        common.mint.decimals,
+        &[&[
+            crate::TOKEN_AUTHORITY_SEED,
+            &[ctx.bumps.common.token_authority],
+        ]],
+    )?;
+    Ok(())
+}
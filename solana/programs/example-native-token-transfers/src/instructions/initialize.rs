@@ -8,9 +8,12 @@ use crate::messages::Hack;
 
 use crate::{
     bitmap::Bitmap,
-    config::Config,
+    config::{Config, DustSettings, DustSettingsV1, FeeSettings, FeeSettingsV1},
     error::NTTError,
-    queue::{outbox::OutboxRateLimit, rate_limit::RateLimitState},
+    queue::{
+        outbox::OutboxRateLimit,
+        rate_limit::{RateLimitSettings, RateLimitSettingsV1, RateLimitState},
+    },
     spl_multisig::SplMultisig,
 };
 
@@ -136,10 +139,14 @@ fn initialize_config_and_rate_limit(
         threshold: 1,
         enabled_transceivers: Bitmap::new(),
         custody: common.custody.key(),
+        pending_owner_expiry: 0,
+        fees: FeeSettings::V1(FeeSettingsV1::default()),
+        dust: DustSettings::V1(DustSettingsV1::default()),
     });
 
     common.rate_limit.set_inner(OutboxRateLimit {
         rate_limit: RateLimitState::new(limit),
+        settings: RateLimitSettings::V1(RateLimitSettingsV1::default()),
     });
 
     Ok(())
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
+
+use crate::{
+    audit_log::{AuditAction, AuditLog},
+    config::Config,
+    error::NTTError,
+    peer::NttManagerPeer,
+    queue::inbox::{InboxItem, ReleaseStatus},
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: ChainId)]
+pub struct ApproveLargeInbound<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+        constraint = peer.large_transfers.current().guardian == guardian.key()
+            @ NTTError::InvalidGuardian,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        mut,
+        constraint = matches!(inbox_item.release_status, ReleaseStatus::AwaitingGuardian(_))
+            @ NTTError::NotAwaitingGuardian,
+        // only items with provenance can await the guardian
+        constraint = inbox_item.provenance.source_chain() == Some(chain_id)
+            @ NTTError::InvalidChainId,
+    )]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+/// Approves a large inbound transfer on behalf of the guardian of its source
+/// chain's peer (see [`crate::peer::LargeTransferPolicy`]). It can then be
+/// released as usual, once the rate limit allows it.
+///
+/// If the guardian can't act (e.g. it lost its key, or the peer was removed),
+/// the owner can approve the transfer instead with
+/// [`approve_large_inbound_by_owner`].
+pub fn approve_large_inbound(ctx: Context<ApproveLargeInbound>, _chain_id: ChainId) -> Result<()> {
+    let accs = ctx.accounts;
+    let before = AuditLog::digest(&accs.inbox_item.release_status)?;
+    accs.inbox_item.approve_by_guardian()?;
+    AuditLog::record_into(
+        &accs.audit_log,
+        AuditAction::ApproveLargeInbound,
+        accs.guardian.key(),
+        accs.inbox_item.key(),
+        before,
+        AuditLog::digest(&accs.inbox_item.release_status)?,
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveLargeInboundByOwner<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = matches!(inbox_item.release_status, ReleaseStatus::AwaitingGuardian(_))
+            @ NTTError::NotAwaitingGuardian,
+    )]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
+}
+
+/// Approves a large inbound transfer in place of the guardian, so that it
+/// doesn't get stuck when the guardian is unavailable. Unlike
+/// [`approve_large_inbound`], this doesn't need the peer, which may have been
+/// removed since the transfer was received.
+///
+/// The transfer is still released to its recipient, once the rate limit
+/// allows it. The audit log entry records the owner as the signer.
+pub fn approve_large_inbound_by_owner(ctx: Context<ApproveLargeInboundByOwner>) -> Result<()> {
+    let accs = ctx.accounts;
+    let before = AuditLog::digest(&accs.inbox_item.release_status)?;
+    accs.inbox_item.approve_by_guardian()?;
+    AuditLog::record_into(
+        &accs.audit_log,
+        AuditAction::ApproveLargeInbound,
+        accs.owner.key(),
+        accs.inbox_item.key(),
+        before,
+        AuditLog::digest(&accs.inbox_item.release_status)?,
+    )?;
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use crate::{
-    config::*, error::NTTError, queue::outbox::OutboxItem,
+    config::*,
+    error::NTTError,
+    queue::{index::QueueIndex, outbox::OutboxItem},
     registered_transceiver::RegisteredTransceiver,
 };
 use anchor_lang::prelude::*;
@@ -27,10 +29,24 @@ pub struct MarkOutboxItemAsReleased<'info> {
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
     )]
     pub transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        mut,
+        seeds = [QueueIndex::OUTBOUND_SEED_PREFIX],
+        bump = outbound_queue.bump,
+    )]
+    pub outbound_queue: Option<Account<'info, QueueIndex>>,
 }
 
 pub fn mark_outbox_item_as_released(ctx: Context<MarkOutboxItemAsReleased>) -> Result<bool> {
     let accs = ctx.accounts;
     let released = accs.outbox_item.try_release(accs.transceiver.id)?;
+    if released {
+        if let Some(outbound_queue) = &mut accs.outbound_queue {
+            let index = outbound_queue.key();
+            let outbox_item = accs.outbox_item.key();
+            outbound_queue.dequeue(index, outbox_item, &mut accs.outbox_item.queue)?;
+        }
+    }
     Ok(released)
 }
@@ -1,15 +1,35 @@
 pub mod admin;
+pub mod audit_log;
+pub mod council;
+pub mod directory;
+pub mod inbox_escrow;
 pub mod initialize;
+pub mod large_transfer;
 pub mod luts;
 pub mod mark_outbox_item_as_released;
+pub mod query;
+pub mod queue_index;
 pub mod redeem;
 pub mod release_inbound;
 pub mod transfer;
+pub mod transfer_batch;
+pub mod transfer_intent;
+pub mod transfer_sequenced;
 
 pub use admin::*;
+pub use audit_log::*;
+pub use council::*;
+pub use directory::*;
+pub use inbox_escrow::*;
 pub use initialize::*;
+pub use large_transfer::*;
 pub use luts::*;
 pub use mark_outbox_item_as_released::*;
+pub use query::*;
+pub use queue_index::*;
 pub use redeem::*;
 pub use release_inbound::*;
 pub use transfer::*;
+pub use transfer_batch::*;
+pub use transfer_intent::*;
+pub use transfer_sequenced::*;
@@ -0,0 +1,223 @@
+//! Read-only instructions that return data (see
+//! [`anchor_lang::solana_program::program::set_return_data`]), so that clients
+//! can simulate them instead of reimplementing the program's logic off-chain.
+//!
+//! None of these modify any account. [`simulate_transfer`] runs the same dust
+//! removal, rate limit and large transfer delay computation as the transfer
+//! instructions, on a copy of the rate limit.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::{chain_id::ChainId, trimmed_amount::TrimmedAmount};
+
+use crate::{
+    bitmap::Bitmap,
+    clock::current_timestamp,
+    config::*,
+    error::NTTError,
+    instructions::TransferArgs,
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        outbox::{OutboxItem, OutboxRateLimit, RateLimitExemption},
+        rate_limit::{RateLimitResult, RateLimitState},
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitCapacity {
+    pub limit: u64,
+    /// The amount that can be transferred right now without being queued.
+    pub capacity: u64,
+}
+
+impl From<&RateLimitState> for RateLimitCapacity {
+    fn from(rate_limit: &RateLimitState) -> Self {
+        RateLimitCapacity {
+            limit: rate_limit.limit,
+            capacity: rate_limit.capacity(),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct QueryOutboundCapacity<'info> {
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+pub fn query_outbound_capacity(ctx: Context<QueryOutboundCapacity>) -> Result<RateLimitCapacity> {
+    Ok((&ctx.accounts.outbox_rate_limit.rate_limit).into())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: ChainId)]
+pub struct QueryInboundCapacity<'info> {
+    #[account(
+        seeds = [InboxRateLimit::SEED_PREFIX, chain_id.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+}
+
+pub fn query_inbound_capacity(
+    ctx: Context<QueryInboundCapacity>,
+    _chain_id: ChainId,
+) -> Result<RateLimitCapacity> {
+    Ok((&ctx.accounts.inbox_rate_limit.rate_limit).into())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OutboxItemStatus {
+    pub release_timestamp: i64,
+    /// Whether the release timestamp has passed.
+    pub releasable: bool,
+    /// The transceivers that have released the transfer.
+    pub released: Bitmap,
+}
+
+#[derive(Accounts)]
+pub struct QueryOutboxItemStatus<'info> {
+    pub outbox_item: Account<'info, OutboxItem>,
+}
+
+pub fn query_outbox_item_status(ctx: Context<QueryOutboxItemStatus>) -> Result<OutboxItemStatus> {
+    let outbox_item = &ctx.accounts.outbox_item;
+    Ok(OutboxItemStatus {
+        release_timestamp: outbox_item.release_timestamp,
+        releasable: outbox_item.release_timestamp <= current_timestamp(),
+        released: outbox_item.released,
+    })
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InboxItemStatus {
+    pub release_status: ReleaseStatus,
+    /// The transceivers that have voted for the transfer.
+    pub votes: Bitmap,
+    /// Whether the transfer can be released right now (see
+    /// [`InboxItem::check_releasable`]).
+    pub releasable: bool,
+}
+
+#[derive(Accounts)]
+pub struct QueryInboxItemStatus<'info> {
+    pub inbox_item: Account<'info, InboxItem>,
+}
+
+pub fn query_inbox_item_status(ctx: Context<QueryInboxItemStatus>) -> Result<InboxItemStatus> {
+    let inbox_item = &ctx.accounts.inbox_item;
+    Ok(InboxItemStatus {
+        release_status: inbox_item.release_status.clone(),
+        votes: inbox_item.votes,
+        releasable: inbox_item.check_releasable().is_ok(),
+    })
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TransferSimulation {
+    /// The amount that would be taken from the sender, after deducting the
+    /// protocol fee and removing dust.
+    pub amount: u64,
+    /// The protocol fee that would be paid to the fee recipient.
+    pub fee: u64,
+    /// The dust that would be left with the sender.
+    pub dust: u64,
+    /// The amount in the message.
+    pub trimmed_amount: TrimmedAmount,
+    /// Whether the transfer would be queued, by the outbound rate limit or the
+    /// peer's large transfer delay.
+    pub queued: bool,
+    /// When the transfer could be released: now, unless it's queued.
+    pub release_timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: TransferArgs)]
+pub struct SimulateTransfer<'info> {
+    #[account(
+        constraint = !config.enabled_transceivers.is_empty() @ NTTError::NoRegisteredTransceivers,
+    )]
+    pub config: NotPausedConfig<'info>,
+
+    #[account(
+        address = config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    /// The sender's exemption from the outbound rate limit, to simulate a
+    /// transfer that draws from it instead.
+    pub rate_limit_exemption: Option<Account<'info, RateLimitExemption>>,
+}
+
+/// Returns what a transfer with `args` would do if it was sent now, or the
+/// error it would fail with.
+pub fn simulate_transfer(
+    ctx: Context<SimulateTransfer>,
+    args: TransferArgs,
+) -> Result<TransferSimulation> {
+    let accs = ctx.accounts;
+
+    accs.peer.validate_recipient(&args.recipient_address)?;
+
+    let fee = accs
+        .config
+        .fees
+        .current()
+        .fee(args.amount, accs.peer.fee.current().fee_bps);
+    let mut amount = args.amount - fee;
+    let trimmed_amount =
+        TrimmedAmount::remove_dust(&mut amount, accs.mint.decimals, accs.peer.token_decimals)
+            .map_err(NTTError::from)?;
+    if accs.config.dust.current().strict && amount != args.amount - fee {
+        return Err(NTTError::TransferHasDust.into());
+    }
+    accs.peer.amount_limits.current().check(amount)?;
+
+    let mut rate_limit = match &accs.rate_limit_exemption {
+        Some(exemption) => exemption.rate_limit.clone(),
+        None => accs.outbox_rate_limit.rate_limit.clone(),
+    };
+    let (queued, release_timestamp) = match rate_limit
+        .consume_or_delay(amount, accs.outbox_rate_limit.settings.current().strict)
+    {
+        RateLimitResult::Consumed(now) => (false, now),
+        RateLimitResult::Delayed(release_timestamp) => {
+            if !args.should_queue {
+                return Err(NTTError::TransferExceedsRateLimit.into());
+            }
+            (true, release_timestamp)
+        }
+    };
+
+    let (queued, release_timestamp) = match accs
+        .peer
+        .large_transfers
+        .current()
+        .outbound_release_timestamp(amount, current_timestamp())
+    {
+        Some(security_release) if security_release > release_timestamp => {
+            if !args.should_queue {
+                return Err(NTTError::TransferRequiresSecurityDelay.into());
+            }
+            (true, security_release)
+        }
+        _ => (queued, release_timestamp),
+    };
+
+    Ok(TransferSimulation {
+        amount,
+        fee,
+        dust: args.amount - fee - amount,
+        trimmed_amount,
+        queued,
+        release_timestamp,
+    })
+}
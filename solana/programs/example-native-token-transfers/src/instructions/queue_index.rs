@@ -0,0 +1,134 @@
+//! The queue indices list the transfers delayed by the rate limits (see
+//! [`crate::queue::index::QueueIndex`]).
+//!
+//! Transfers are only tracked once the index has been created. The dequeue
+//! instructions below remove transfers that were released (or escrowed)
+//! without the index, so that its totals don't drift.
+//!
+//! None of these need the owner. Creating an index only starts tracking new
+//! transfers, and a transfer can only be dequeued once its outbox or inbox
+//! item shows it was released, so a caller can't hide a transfer that's still
+//! waiting.
+
+use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
+
+use crate::{
+    error::NTTError,
+    queue::{
+        inbox::{InboxItem, ReleaseStatus},
+        index::QueueIndex,
+        outbox::OutboxItem,
+    },
+};
+
+#[derive(Accounts)]
+pub struct InitializeOutboundQueue<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + QueueIndex::INIT_SPACE,
+        seeds = [QueueIndex::OUTBOUND_SEED_PREFIX],
+        bump,
+    )]
+    pub outbound_queue: Account<'info, QueueIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_outbound_queue(ctx: Context<InitializeOutboundQueue>) -> Result<()> {
+    ctx.accounts.outbound_queue.set_inner(QueueIndex {
+        bump: ctx.bumps.outbound_queue,
+        queued_amount: 0,
+        queued_count: 0,
+        entries: Vec::new(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: ChainId)]
+pub struct InitializeInboundQueue<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + QueueIndex::INIT_SPACE,
+        seeds = [QueueIndex::INBOUND_SEED_PREFIX, chain_id.id.to_be_bytes().as_ref()],
+        bump,
+    )]
+    pub inbound_queue: Account<'info, QueueIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_inbound_queue(
+    ctx: Context<InitializeInboundQueue>,
+    _chain_id: ChainId,
+) -> Result<()> {
+    ctx.accounts.inbound_queue.set_inner(QueueIndex {
+        bump: ctx.bumps.inbound_queue,
+        queued_amount: 0,
+        queued_count: 0,
+        entries: Vec::new(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DequeueReleasedOutbound<'info> {
+    #[account(
+        mut,
+        constraint = !outbox_item.released.is_empty() @ NTTError::TransferStillQueued,
+    )]
+    pub outbox_item: Account<'info, OutboxItem>,
+
+    #[account(
+        mut,
+        seeds = [QueueIndex::OUTBOUND_SEED_PREFIX],
+        bump = outbound_queue.bump,
+    )]
+    pub outbound_queue: Account<'info, QueueIndex>,
+}
+
+/// Removes an outbound transfer that has been released by at least one
+/// transceiver from the outbound queue.
+pub fn dequeue_released_outbound(ctx: Context<DequeueReleasedOutbound>) -> Result<()> {
+    let accs = ctx.accounts;
+    let index = accs.outbound_queue.key();
+    let item = accs.outbox_item.key();
+    accs.outbound_queue
+        .dequeue(index, item, &mut accs.outbox_item.queue)
+}
+
+#[derive(Accounts)]
+pub struct DequeueReleasedInbound<'info> {
+    #[account(
+        mut,
+        constraint = matches!(
+            inbox_item.release_status,
+            ReleaseStatus::Released | ReleaseStatus::Escrowed
+        ) @ NTTError::TransferStillQueued,
+    )]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(mut)]
+    /// NOTE: checked against the inbox item's queue membership in
+    /// [`QueueIndex::dequeue`]
+    pub inbound_queue: Account<'info, QueueIndex>,
+}
+
+/// Removes an inbound transfer that has been released (or escrowed) from the
+/// inbound queue of its source chain.
+pub fn dequeue_released_inbound(ctx: Context<DequeueReleasedInbound>) -> Result<()> {
+    let accs = ctx.accounts;
+    let index = accs.inbound_queue.key();
+    let item = accs.inbox_item.key();
+    accs.inbound_queue
+        .dequeue(index, item, &mut accs.inbox_item.queue)
+}
@@ -1,15 +1,23 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program};
 use anchor_spl::token_interface;
-use ntt_messages::{ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
+use ntt_messages::{
+    chain_id::ChainId, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
+    transceiver::TransceiverMessageData,
+};
 
 use crate::{
     bitmap::Bitmap,
+    clock::current_timestamp,
     config::*,
     error::NTTError,
     messages::ValidatedTransceiverMessage,
     peer::NttManagerPeer,
     queue::{
-        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        inbox::{
+            InboxGasDropoff, InboxItem, InboxProvenance, InboxRateLimit,
+            InboxTokenAccountRecipient, InboxTranches, ReleaseStatus,
+        },
+        index::{QueueIndex, QueueMembership},
         outbox::OutboxRateLimit,
         rate_limit::RateLimitResult,
     },
@@ -28,24 +36,16 @@ pub struct Redeem<'info> {
     )]
     pub config: Account<'info, Config>,
 
-    #[account(
-        seeds = [NttManagerPeer::SEED_PREFIX, ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?.id.to_be_bytes().as_ref()],
-        constraint = peer.address == ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.source_ntt_manager() @ NTTError::InvalidNttManagerPeer,
-        bump = peer.bump,
-    )]
+    /// NOTE: checked against the message in [`Redeem::check_message`]
     pub peer: Account<'info, NttManagerPeer>,
 
-    #[account(
-        // check that the message is targeted to this chain
-        constraint = ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload().payload.to_chain == config.chain_id @ NTTError::InvalidChainId,
-        // check that we're the intended recipient
-        constraint = ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.recipient_ntt_manager() == crate::ID.to_bytes() @ NTTError::InvalidRecipientNttManager,
-        // NOTE: we don't replay protect VAAs. Instead, we replay protect
-        // executing the messages themselves with the [`released`] flag.
-        owner = transceiver.transceiver_address
-    )]
     /// CHECK: `transceiver_message` has to be manually deserialized as Anchor
-    /// `Account<T>` and `owner` constraints are mutually-exclusive
+    /// `Account<T>` and `owner` constraints are mutually-exclusive. It's
+    /// deserialized (and its owner checked) exactly once, in [`redeem`], and
+    /// the other accounts are checked against it there rather than in
+    /// constraints, which would each parse it again.
+    /// NOTE: we don't replay protect VAAs. Instead, we replay protect
+    /// executing the messages themselves with the [`released`] flag.
     pub transceiver_message: UncheckedAccount<'info>,
 
     #[account(
@@ -58,46 +58,131 @@ pub struct Redeem<'info> {
     )]
     pub mint: InterfaceAccount<'info, token_interface::Mint>,
 
-    #[account(
-        init_if_needed,
-        payer = payer,
-        space = 8 + InboxItem::INIT_SPACE,
-        seeds = [
-            InboxItem::SEED_PREFIX,
-            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload().keccak256(
-                ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?
-            ).as_ref(),
-        ],
-        bump,
-    )]
+    #[account(mut)]
     /// NOTE: This account is content-addressed (PDA seeded by the message hash).
     /// This is because in a multi-transceiver configuration, the different
     /// transceivers "vote" on messages (by delivering them). By making the inbox
     /// items content-addressed, we can ensure that disagreeing votes don't
     /// interfere with each other.
-    /// CHECK: init_if_needed is used here to allow for allocation and for voiting.
-    /// On the first call to [`redeem()`], [`InboxItem`] will be allocated and initialized with
-    /// default values.
-    /// On subsequent calls, we want to modify the `InboxItem` by "voting" on it. Therefore the
-    /// program should not fail which would occur when using the `init` constraint.
-    /// The [`InboxItem::init`] field is used to guard against malicious or accidental modification
-    /// InboxItem fields that should remain constant.
-    pub inbox_item: Account<'info, InboxItem>,
+    /// CHECK: the address is checked against the message hash in
+    /// [`Redeem::load_inbox_item`]. On the first call to [`redeem()`], the
+    /// [`InboxItem`] is allocated and initialized there; on subsequent calls,
+    /// it's loaded so that it can be "voted" on.
+    pub inbox_item: UncheckedAccount<'info>,
 
-    #[account(
-        mut,
-        seeds = [
-            InboxRateLimit::SEED_PREFIX,
-            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?.id.to_be_bytes().as_ref(),
-        ],
-        bump,
-    )]
+    #[account(mut)]
+    /// NOTE: checked against the message in [`Redeem::check_message`]
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
     #[account(mut)]
     pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    /// CHECK: checked against the message in [`Redeem::check_message`], and
+    /// loaded with [`QueueIndex::load`] if it has been created
+    pub inbound_queue: UncheckedAccount<'info>,
+}
+
+impl<'info> Redeem<'info> {
+    /// The checks against the contents of the message.
+    fn check_message(
+        &self,
+        from_chain: ChainId,
+        message: &TransceiverMessageData<NativeTokenTransfer<Payload>>,
+    ) -> Result<()> {
+        check_pda(
+            &self.peer.key(),
+            &[
+                NttManagerPeer::SEED_PREFIX,
+                from_chain.id.to_be_bytes().as_ref(),
+                &[self.peer.bump],
+            ],
+        )?;
+        require!(
+            self.peer.address == message.source_ntt_manager,
+            NTTError::InvalidNttManagerPeer
+        );
+        // check that the message is targeted to this chain
+        require!(
+            message.ntt_manager_payload.payload.to_chain == self.config.chain_id,
+            NTTError::InvalidChainId
+        );
+        // check that we're the intended recipient
+        require!(
+            message.recipient_ntt_manager == crate::ID.to_bytes(),
+            NTTError::InvalidRecipientNttManager
+        );
+        check_pda(
+            &self.inbox_rate_limit.key(),
+            &[
+                InboxRateLimit::SEED_PREFIX,
+                from_chain.id.to_be_bytes().as_ref(),
+                &[self.inbox_rate_limit.bump],
+            ],
+        )?;
+        // required even before the index is created, so that it can't be
+        // skipped once it is
+        let (inbound_queue, _) = Pubkey::find_program_address(
+            &[
+                QueueIndex::INBOUND_SEED_PREFIX,
+                from_chain.id.to_be_bytes().as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            self.inbound_queue.key(),
+            inbound_queue,
+            NTTError::InvalidQueueIndex
+        );
+        Ok(())
+    }
+
+    /// Loads the inbox item of the message with the given hash, or allocates
+    /// and initializes it if this is the first vote.
+    fn load_inbox_item(
+        &self,
+        message_hash: &[u8],
+        from_chain: ChainId,
+        message: &TransceiverMessageData<NativeTokenTransfer<Payload>>,
+        gas_dropoff: u64,
+    ) -> Result<InboxItem> {
+        let info = self.inbox_item.to_account_info();
+
+        if info.owner == &crate::ID {
+            let inbox_item = InboxItem::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+            // the stored bump avoids searching for it again
+            check_pda(
+                info.key,
+                &[InboxItem::SEED_PREFIX, message_hash, &[inbox_item.bump]],
+            )?;
+            return Ok(inbox_item);
+        }
+
+        let (address, bump) =
+            Pubkey::find_program_address(&[InboxItem::SEED_PREFIX, message_hash], &crate::ID);
+        if address != *info.key {
+            return Err(Error::from(ErrorCode::ConstraintSeeds).with_pubkeys((*info.key, address)));
+        }
+
+        create_program_account(
+            &info,
+            8 + InboxItem::INIT_SPACE,
+            &[InboxItem::SEED_PREFIX, message_hash, &[bump]],
+            &self.payer,
+            &self.system_program,
+        )?;
+
+        new_inbox_item(
+            bump,
+            from_chain,
+            message.source_ntt_manager,
+            &message.ntt_manager_payload,
+            self.mint.decimals,
+            gas_dropoff,
+        )
+    }
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -111,9 +196,151 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
             &accs.transceiver_message,
             &accs.transceiver.transceiver_address,
         )?;
-    let message: NttManagerMessage<NativeTokenTransfer<Payload>> =
-        transceiver_message.message.ntt_manager_payload.clone();
+    let from_chain = transceiver_message.from_chain;
+    let message = &transceiver_message.message;
+
+    accs.check_message(from_chain, message)?;
+
+    let message_hash = message.ntt_manager_payload.keccak256(from_chain);
+    let mut inbox_item = accs.load_inbox_item(
+        message_hash.as_ref(),
+        from_chain,
+        message,
+        transceiver_message.gas_dropoff.lamports(),
+    )?;
 
+    vote(
+        accs.inbox_item.key(),
+        &mut inbox_item,
+        accs.transceiver.id,
+        &accs.config,
+        &accs.peer,
+        &mut accs.inbox_rate_limit,
+        &mut accs.outbox_rate_limit,
+        &accs.inbound_queue,
+    )?;
+
+    inbox_item.try_serialize(&mut &mut accs.inbox_item.try_borrow_mut_data()?[..])
+}
+
+/// Records the transceiver's vote, and approves the transfer once it reaches
+/// the threshold.
+fn vote(
+    inbox_item_key: Pubkey,
+    inbox_item: &mut InboxItem,
+    transceiver_id: u8,
+    config: &Config,
+    peer: &NttManagerPeer,
+    inbox_rate_limit: &mut InboxRateLimit,
+    outbox_rate_limit: &mut OutboxRateLimit,
+    inbound_queue: &AccountInfo,
+) -> Result<()> {
+    // idempotent
+    inbox_item.votes.set(transceiver_id, true)?;
+
+    if inbox_item
+        .votes
+        .count_enabled_votes(config.enabled_transceivers)
+        < config.threshold
+    {
+        return Ok(());
+    }
+
+    approve_inbox_item(
+        inbox_item_key,
+        inbox_item,
+        peer,
+        inbox_rate_limit,
+        outbox_rate_limit,
+        inbound_queue,
+    )
+}
+
+/// Checks that `address` is the program address derived from `seeds`
+/// (including the bump).
+pub(crate) fn check_pda(address: &Pubkey, seeds: &[&[u8]]) -> Result<()> {
+    match Pubkey::create_program_address(seeds, &crate::ID) {
+        Ok(expected) if expected == *address => Ok(()),
+        Ok(expected) => {
+            Err(Error::from(ErrorCode::ConstraintSeeds).with_pubkeys((*address, expected)))
+        }
+        Err(_) => Err(ErrorCode::ConstraintSeeds.into()),
+    }
+}
+
+/// Allocates `account` as a `space`-byte account of this program, at the
+/// program address derived from `seeds`, the same way Anchor's `init`
+/// constraint does (including when it's been pre-funded).
+pub(crate) fn create_program_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let required_lamports = rent.saturating_sub(current_lamports);
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+/// Creates the inbox item of an inbound transfer, before any votes.
+pub(crate) fn new_inbox_item(
+    bump: u8,
+    source_chain: ChainId,
+    source_ntt_manager: [u8; 32],
+    message: &NttManagerMessage<NativeTokenTransfer<Payload>>,
+    mint_decimals: u8,
+    gas_dropoff: u64,
+) -> Result<InboxItem> {
     // Calculate the scaled amount based on the appropriate decimal encoding for the token.
     // Return an error if the resulting amount overflows.
     // Ideally this state should never be reached: the sender should avoid sending invalid
@@ -121,46 +348,95 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
     let amount = message
         .payload
         .amount
-        .untrim(accs.mint.decimals)
+        .untrim(mint_decimals)
         .map_err(NTTError::from)?;
 
-    if !accs.inbox_item.init {
-        let recipient_address =
-            Pubkey::try_from(message.payload.to).map_err(|_| NTTError::InvalidRecipientAddress)?;
-
-        accs.inbox_item.set_inner(InboxItem {
-            init: true,
-            bump: ctx.bumps.inbox_item,
-            amount,
-            recipient_address,
-            release_status: ReleaseStatus::NotApproved,
-            votes: Bitmap::new(),
-        });
-    }
+    let recipient_address =
+        Pubkey::try_from(message.payload.to).map_err(|_| NTTError::InvalidRecipientAddress)?;
 
-    // idempotent
-    accs.inbox_item.votes.set(accs.transceiver.id, true)?;
+    Ok(InboxItem {
+        init: true,
+        bump,
+        amount,
+        recipient_address,
+        release_status: ReleaseStatus::NotApproved,
+        votes: Bitmap::new(),
+        gas_dropoff: InboxGasDropoff::new(gas_dropoff),
+        queue: QueueMembership::new(),
+        provenance: InboxProvenance::new(
+            source_chain,
+            source_ntt_manager,
+            message.sender,
+            message.id,
+        ),
+        tranches: InboxTranches::new(),
+        token_account_recipient: InboxTokenAccountRecipient::new(),
+    })
+}
 
-    if accs
-        .inbox_item
-        .votes
-        .count_enabled_votes(accs.config.enabled_transceivers)
-        < accs.config.threshold
-    {
-        return Ok(());
-    }
+/// Approves an inbound transfer that reached the threshold: it consumes the
+/// inbound rate limit, and becomes releasable immediately if there's enough
+/// capacity, or once the rate limit allows it otherwise.
+///
+/// Transfers larger than the limit are instead released in tranches, each
+/// consuming the capacity available at the time (see
+/// [`InboxItem::try_release_tranche`]), starting right away. Inbox items
+/// created before the tranches were added are delayed as usual.
+///
+/// Delayed transfers are added to `inbound_queue` (the index of the source
+/// chain, whose address the caller checks), once it has been created. Inbox
+/// items created before the queue indices were added can't record their
+/// membership, so they're never added.
+///
+/// Transfers over the inbound threshold of the peer's large transfer policy
+/// also need the guardian's approval. Inbox items created before their
+/// provenance was recorded don't say which peer's guardian that is, so they're
+/// exempt.
+pub(crate) fn approve_inbox_item(
+    inbox_item_key: Pubkey,
+    inbox_item: &mut InboxItem,
+    peer: &NttManagerPeer,
+    inbox_rate_limit: &mut InboxRateLimit,
+    outbox_rate_limit: &mut OutboxRateLimit,
+    inbound_queue: &AccountInfo,
+) -> Result<()> {
+    let amount = inbox_item.amount;
+    peer.amount_limits.current().check_inbound(amount)?;
 
-    let release_timestamp = match accs.inbox_rate_limit.rate_limit.consume_or_delay(amount) {
-        RateLimitResult::Consumed(now) => {
-            // When receiving a transfer, we refill the outbound rate limit with
-            // the same amount (we call this "backflow")
-            accs.outbox_rate_limit.rate_limit.refill(now, amount);
-            now
+    let release_timestamp = if amount > inbox_rate_limit.limit && inbox_item.tranches.enable() {
+        let now = current_timestamp();
+        inbox_item.queue = QueueIndex::enqueue_into(inbound_queue, inbox_item_key, amount, now)?;
+        now
+    } else {
+        match inbox_rate_limit.consume_or_delay(amount) {
+            RateLimitResult::Consumed(now) => {
+                // When receiving a transfer, we refill the outbound rate limit with
+                // the same amount (we call this "backflow"), or the share of it
+                // configured for the peer
+                let backflow = inbox_rate_limit.inbound_backflow(amount);
+                outbox_rate_limit.rate_limit.refill(now, backflow);
+                now
+            }
+            RateLimitResult::Delayed(release_timestamp) => {
+                if inbox_item.queue != QueueMembership::Unavailable {
+                    inbox_item.queue = QueueIndex::enqueue_into(
+                        inbound_queue,
+                        inbox_item_key,
+                        amount,
+                        release_timestamp,
+                    )?;
+                }
+                release_timestamp
+            }
         }
-        RateLimitResult::Delayed(release_timestamp) => release_timestamp,
     };
 
-    accs.inbox_item.release_after(release_timestamp)?;
-
-    Ok(())
+    inbox_item.provenance.record_approval(current_timestamp());
+    if inbox_item.provenance != InboxProvenance::Unavailable
+        && peer.large_transfers.current().requires_guardian(amount)
+    {
+        inbox_item.await_guardian(release_timestamp)
+    } else {
+        inbox_item.release_after(release_timestamp)
+    }
 }
@@ -1,12 +1,20 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface;
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface,
+};
 use ntt_messages::mode::Mode;
 use spl_token_2022::onchain;
 
 use crate::{
     config::*,
     error::NTTError,
-    queue::inbox::{InboxItem, ReleaseStatus},
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        index::QueueIndex,
+        outbox::OutboxRateLimit,
+        rate_limit::RateLimitResult,
+    },
     spl_multisig::SplMultisig,
 };
 
@@ -20,13 +28,14 @@ pub struct ReleaseInbound<'info> {
     #[account(mut)]
     pub inbox_item: Account<'info, InboxItem>,
 
-    #[account(
-        mut,
-        associated_token::authority = inbox_item.recipient_address,
-        associated_token::mint = mint,
-        associated_token::token_program = token_program,
-    )]
-    pub recipient: InterfaceAccount<'info, token_interface::TokenAccount>,
+    #[account(mut)]
+    /// CHECK: validated in [`ReleaseInbound::prepare_recipient`]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(address = inbox_item.recipient_address)]
+    /// CHECK: the address constraint ensures this is the recipient address of
+    /// the transfer
+    pub recipient_address: UncheckedAccount<'info>,
 
     #[account(
         seeds = [crate::TOKEN_AUTHORITY_SEED],
@@ -50,6 +59,226 @@ pub struct ReleaseInbound<'info> {
         address = config.custody
     )]
     pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    /// NOTE: checked against the inbox item's queue membership in
+    /// [`QueueIndex::dequeue`]. Required when the inbox item is queued in it.
+    pub inbound_queue: Option<Account<'info, QueueIndex>>,
+
+    /// Required for transfers released in tranches, which consume the
+    /// inbound rate limit of their source chain as they're released.
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            inbox_item.provenance.source_chain().map_or(0, |chain| chain.id).to_be_bytes().as_ref(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Option<Account<'info, InboxRateLimit>>,
+
+    /// Required for transfers released in tranches, whose tranches flow back
+    /// into the outbound rate limit.
+    #[account(mut)]
+    pub outbox_rate_limit: Option<Account<'info, OutboxRateLimit>>,
+}
+
+impl<'info> ReleaseInbound<'info> {
+    /// Releases the inbox item, or its next tranche, and returns the amount
+    /// to deliver (see [`release_inbox_item`]).
+    fn release(&mut self, revert_when_not_ready: bool) -> Result<Option<u64>> {
+        let rate_limits = match (&mut self.inbox_rate_limit, &mut self.outbox_rate_limit) {
+            (Some(inbox_rate_limit), Some(outbox_rate_limit)) => {
+                Some((&mut **inbox_rate_limit, &mut **outbox_rate_limit))
+            }
+            _ => None,
+        };
+        release_inbox_item(
+            &mut self.inbox_item,
+            self.inbound_queue.as_mut(),
+            rate_limits,
+            revert_when_not_ready,
+        )
+    }
+
+    fn prepare_recipient(&self) -> Result<()> {
+        prepare_recipient(
+            &self.payer,
+            &self.recipient,
+            &self.recipient_address,
+            self.inbox_item.token_account_recipient.owner(),
+            &self.mint,
+            &self.token_program,
+            &self.associated_token_program,
+            &self.system_program,
+        )
+    }
+}
+
+/// Checks that `recipient` is the token account a transfer to
+/// `recipient_address` is released to, creating it first if needed:
+///
+/// * If the recipient address is a token account of the mint whose owner
+/// accepted the transfer (see [`accept_inbound_to_token_account`]), that token
+/// account. `token_account_owner` is the owner that accepted it, which must
+/// still own it.
+/// * Otherwise, the associated token account of the recipient address,
+/// which is created (paid by `payer`) if it doesn't exist yet. Token accounts
+/// that didn't accept the transfer are rejected instead, as nobody controls
+/// their associated token accounts.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn prepare_recipient<'info>(
+    payer: &Signer<'info>,
+    recipient: &UncheckedAccount<'info>,
+    recipient_address: &UncheckedAccount<'info>,
+    token_account_owner: Option<Pubkey>,
+    mint: &InterfaceAccount<'info, token_interface::Mint>,
+    token_program: &Interface<'info, token_interface::TokenInterface>,
+    associated_token_program: &Program<'info, AssociatedToken>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if recipient_address.owner == &token_program.key() {
+        let Some(token_account_owner) = token_account_owner else {
+            return Err(NTTError::TokenAccountRecipientUnsupported.into());
+        };
+        let token_account = token_interface::TokenAccount::try_deserialize(
+            &mut &recipient_address.data.borrow()[..],
+        )?;
+        if token_account.mint != mint.key()
+            || token_account.owner != token_account_owner
+            || recipient.key() != recipient_address.key()
+        {
+            return Err(NTTError::InvalidRecipientTokenAccount.into());
+        }
+        return Ok(());
+    }
+    if token_account_owner.is_some() {
+        // the token account was closed since it accepted the transfer
+        return Err(NTTError::InvalidRecipientTokenAccount.into());
+    }
+
+    let associated_token_account = get_associated_token_address_with_program_id(
+        &recipient_address.key(),
+        &mint.key(),
+        &token_program.key(),
+    );
+    if recipient.key() != associated_token_account {
+        return Err(NTTError::InvalidRecipientTokenAccount.into());
+    }
+    if recipient.data_is_empty() {
+        associated_token::create_idempotent(CpiContext::new(
+            associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: payer.to_account_info(),
+                associated_token: recipient.to_account_info(),
+                authority: recipient_address.to_account_info(),
+                mint: mint.to_account_info(),
+                system_program: system_program.to_account_info(),
+                token_program: token_program.to_account_info(),
+            },
+        ))?;
+    }
+    Ok(())
+}
+
+// Gas dropoff
+
+#[derive(Accounts)]
+pub struct DeliverGasDropoff<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        mut,
+        address = inbox_item.recipient_address,
+    )]
+    /// CHECK: the address constraint ensures this is the recipient address of
+    /// the transfer
+    pub recipient_address: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Drops off the native gas requested by the sender of an inbound transfer
+/// (see [`crate::queue::inbox::InboxGasDropoff`]) to the recipient address,
+/// funded by the payer, and records the amount delivered. Relayers
+/// compensated for the dropoff on the source chain bundle this with the
+/// release, but it can be delivered at any time, including after the release.
+/// Only the part of the dropoff that's still undelivered is dropped off.
+/// SECURITY: Signer checks are disabled here because anyone is permitted to
+/// fund the dropoff.
+pub fn deliver_gas_dropoff(ctx: Context<DeliverGasDropoff>) -> Result<()> {
+    let accs = ctx.accounts;
+    let lamports = accs.inbox_item.gas_dropoff.undelivered();
+    if lamports == 0 {
+        return Ok(());
+    }
+    system_program::transfer(
+        CpiContext::new(
+            accs.system_program.to_account_info(),
+            system_program::Transfer {
+                from: accs.payer.to_account_info(),
+                to: accs.recipient_address.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+    accs.inbox_item.gas_dropoff.record_delivery(lamports);
+    Ok(())
+}
+
+// Accept release to a token account
+
+#[derive(Accounts)]
+pub struct AcceptInboundToTokenAccount<'info> {
+    pub config: NotPausedConfig<'info>,
+
+    #[account(mut)]
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        address = inbox_item.recipient_address,
+        constraint = recipient_token_account.mint == config.mint
+            @ NTTError::InvalidRecipientTokenAccount,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        address = recipient_token_account.owner @ NTTError::NotInboxItemRecipient,
+    )]
+    pub owner: Signer<'info>,
+}
+
+/// Accepts an inbound transfer whose recipient address is a token account of
+/// the mint (e.g. a vault owned by a program PDA) into that token account.
+/// Without this, the transfer can't be released, as it would go to the
+/// associated token account of the token account, which nobody controls.
+///
+/// SECURITY: only the owner of the token account can accept, and the token
+/// account must still have the same owner when the transfer is released (see
+/// [`prepare_recipient`]).
+pub fn accept_inbound_to_token_account(ctx: Context<AcceptInboundToTokenAccount>) -> Result<()> {
+    let accs = ctx.accounts;
+    match accs.inbox_item.release_status {
+        ReleaseStatus::Released => return Err(NTTError::TransferAlreadyRedeemed.into()),
+        ReleaseStatus::Escrowed => return Err(NTTError::TransferEscrowed.into()),
+        _ => {}
+    }
+    if !accs
+        .inbox_item
+        .token_account_recipient
+        .accept(accs.owner.key())
+    {
+        return Err(NTTError::TokenAccountRecipientUnsupported.into());
+    }
+    Ok(())
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -87,15 +316,11 @@ pub fn release_inbound_mint<'info>(
     ctx: Context<'_, '_, '_, 'info, ReleaseInboundMint<'info>>,
     args: ReleaseInboundArgs,
 ) -> Result<()> {
-    let inbox_item = release_inbox_item(
-        &mut ctx.accounts.common.inbox_item,
-        args.revert_when_not_ready,
-    )?;
-    if inbox_item.is_none() {
+    ctx.accounts.common.prepare_recipient()?;
+
+    let Some(amount) = ctx.accounts.common.release(args.revert_when_not_ready)? else {
         return Ok(());
-    }
-    let inbox_item = inbox_item.unwrap();
-    assert!(inbox_item.release_status == ReleaseStatus::Released);
+    };
 
     // NOTE: minting tokens is a two-step process:
     // 1. Mint tokens to the custody account
@@ -126,7 +351,7 @@ pub fn release_inbound_mint<'info>(
             multisig_token_authority.to_account_info(),
             ctx.accounts.common.token_authority.to_account_info(),
             token_authority_sig,
-            inbox_item.amount,
+            amount,
         )?,
         None => mint_to_custody_from_token_authority(
             ctx.accounts.common.token_program.to_account_info(),
@@ -134,7 +359,7 @@ pub fn release_inbound_mint<'info>(
             ctx.accounts.common.custody.to_account_info(),
             ctx.accounts.common.token_authority.to_account_info(),
             token_authority_sig,
-            inbox_item.amount,
+            amount,
         )?,
     };
 
@@ -146,14 +371,14 @@ pub fn release_inbound_mint<'info>(
         ctx.accounts.common.recipient.to_account_info(),
         ctx.accounts.common.token_authority.to_account_info(),
         ctx.remaining_accounts,
-        inbox_item.amount,
+        amount,
         ctx.accounts.common.mint.decimals,
         token_authority_sig,
     )?;
     Ok(())
 }
 
-fn mint_to_custody_from_token_authority<'info>(
+pub(crate) fn mint_to_custody_from_token_authority<'info>(
     token_program: AccountInfo<'info>,
     mint: AccountInfo<'info>,
     custody: AccountInfo<'info>,
@@ -176,7 +401,7 @@ fn mint_to_custody_from_token_authority<'info>(
     Ok(())
 }
 
-fn mint_to_custody_from_multisig_token_authority<'info>(
+pub(crate) fn mint_to_custody_from_multisig_token_authority<'info>(
     token_program: AccountInfo<'info>,
     mint: AccountInfo<'info>,
     custody: AccountInfo<'info>,
@@ -223,15 +448,11 @@ pub fn release_inbound_unlock<'info>(
     ctx: Context<'_, '_, '_, 'info, ReleaseInboundUnlock<'info>>,
     args: ReleaseInboundArgs,
 ) -> Result<()> {
-    let inbox_item = release_inbox_item(
-        &mut ctx.accounts.common.inbox_item,
-        args.revert_when_not_ready,
-    )?;
-    if inbox_item.is_none() {
+    ctx.accounts.common.prepare_recipient()?;
+
+    let Some(amount) = ctx.accounts.common.release(args.revert_when_not_ready)? else {
         return Ok(());
-    }
-    let inbox_item = inbox_item.unwrap();
-    assert!(inbox_item.release_status == ReleaseStatus::Released);
+    };
 
     onchain::invoke_transfer_checked(
         &ctx.accounts.common.token_program.key(),
@@ -240,7 +461,7 @@ pub fn release_inbound_unlock<'info>(
         ctx.accounts.common.recipient.to_account_info(),
         ctx.accounts.common.token_authority.to_account_info(),
         ctx.remaining_accounts,
-        inbox_item.amount,
+        amount,
         ctx.accounts.common.mint.decimals,
         &[&[
             crate::TOKEN_AUTHORITY_SEED,
@@ -250,12 +471,56 @@ pub fn release_inbound_unlock<'info>(
     Ok(())
 }
 
-fn release_inbox_item(
-    inbox_item: &mut InboxItem,
+/// Releases the inbox item, and removes it from `inbound_queue` (which must
+/// be passed if the item is queued in it, so that the index doesn't list
+/// released transfers). Returns the amount to deliver, or `None` if it isn't
+/// ready (unless `revert_when_not_ready` is set, in which case that's an
+/// error).
+///
+/// For transfers released in tranches, that's the next tranche, which
+/// consumes the inbound rate limit (and flows back into the outbound one) in
+/// `rate_limits`. They're removed from the queue once fully released.
+fn release_inbox_item<'info>(
+    inbox_item: &mut Account<'info, InboxItem>,
+    inbound_queue: Option<&mut Account<'info, QueueIndex>>,
+    rate_limits: Option<(&mut InboxRateLimit, &mut OutboxRateLimit)>,
     revert_when_not_ready: bool,
-) -> Result<Option<&mut InboxItem>> {
-    if inbox_item.try_release()? {
-        Ok(Some(inbox_item))
+) -> Result<Option<u64>> {
+    let amount = if inbox_item.tranches.is_enabled() {
+        let (inbox_rate_limit, outbox_rate_limit) =
+            rate_limits.ok_or(NTTError::MissingRateLimit)?;
+        let tranche = inbox_item.try_release_tranche(inbox_rate_limit.capacity())?;
+        if tranche > 0 {
+            let RateLimitResult::Consumed(now) = inbox_rate_limit.consume_or_delay(tranche) else {
+                // unreachable: the tranche fits in the capacity
+                return Err(NTTError::CantReleaseYet.into());
+            };
+            let backflow = inbox_rate_limit.inbound_backflow(tranche);
+            outbox_rate_limit.rate_limit.refill(now, backflow);
+        }
+        tranche
+    } else if inbox_item.try_release()? {
+        inbox_item.amount
+    } else {
+        0
+    };
+
+    if inbox_item.release_status == ReleaseStatus::Released {
+        match inbound_queue {
+            Some(inbound_queue) => {
+                let index = inbound_queue.key();
+                let item = inbox_item.key();
+                inbound_queue.dequeue(index, item, &mut inbox_item.queue)?;
+            }
+            None if inbox_item.queue.is_queued() => {
+                return Err(NTTError::InvalidQueueIndex.into());
+            }
+            None => {}
+        }
+    }
+
+    if amount > 0 {
+        Ok(Some(amount))
     } else if revert_when_not_ready {
         match inbox_item.release_status {
             ReleaseStatus::NotApproved => Err(NTTError::TransferNotApproved.into()),
@@ -263,6 +528,8 @@ fn release_inbox_item(
             // Unreachable: if released, [`InboxItem::try_release`] will return an Error immediately
             // rather than Ok(bool).
             ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
+            ReleaseStatus::Escrowed => Err(NTTError::TransferEscrowed.into()),
+            ReleaseStatus::AwaitingGuardian(_) => Err(NTTError::GuardianApprovalRequired.into()),
         }
     } else {
         Ok(None)
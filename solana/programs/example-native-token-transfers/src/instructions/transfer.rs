@@ -11,6 +11,52 @@
 //!
 //! See the documentation of [`crate::SESSION_AUTHORITY_SEED`] for an
 //! explanation of the approval flow.
+//!
+//! The `transfer_*_direct` variants skip the session authority, and instead
+//! require the owner (or delegate) of the `from` token account to sign the
+//! instruction. These are meant for other programs (vaults, smart wallets,
+//! DAO treasuries) whose PDAs hold the tokens, and which can sign for them
+//! directly in a CPI. Build this crate with the `cpi` feature, then:
+//!
+//! ```ignore
+//! example_native_token_transfers::cpi::transfer_lock_direct(
+//!     CpiContext::new_with_signer(
+//!         ntt_program.to_account_info(),
+//!         example_native_token_transfers::cpi::accounts::TransferLockDirect {
+//!             common: example_native_token_transfers::cpi::accounts::Transfer {
+//!                 payer,
+//!                 config: example_native_token_transfers::cpi::accounts::NotPausedConfig {
+//!                     config,
+//!                 },
+//!                 mint,
+//!                 from: vault_token_account,
+//!                 token_program,
+//!                 outbox_item,
+//!                 outbox_rate_limit,
+//!                 custody,
+//!                 system_program,
+//!                 outbound_queue,
+//!                 rate_limit_exemption: None,
+//!                 fee_recipient: None,
+//!             },
+//!             inbox_rate_limit,
+//!             peer,
+//!             authority: vault.to_account_info(),
+//!         },
+//!         &[&[b"vault", &[vault_bump]]],
+//!     ),
+//!     TransferArgs {
+//!         amount,
+//!         recipient_chain,
+//!         recipient_address,
+//!         should_queue: false,
+//!     },
+//! )?;
+//! ```
+//!
+//! `transfer_burn_direct` additionally takes the `token_authority` account.
+//! In both cases, the resulting [`OutboxItem::sender`] is the signing
+//! authority (here the vault PDA).
 
 #![allow(clippy::too_many_arguments)]
 use anchor_lang::prelude::*;
@@ -20,12 +66,15 @@ use spl_token_2022::onchain;
 
 use crate::{
     bitmap::Bitmap,
+    clock::current_timestamp,
     config::*,
     error::NTTError,
+    events::OutboundTransfer,
     peer::NttManagerPeer,
     queue::{
         inbox::InboxRateLimit,
-        outbox::{OutboxItem, OutboxRateLimit},
+        index::{QueueIndex, QueueMembership},
+        outbox::{OutboxItem, OutboxProvenance, OutboxRateLimit, RateLimitExemption},
         rate_limit::RateLimitResult,
     },
 };
@@ -80,6 +129,92 @@ pub struct Transfer<'info> {
     pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [QueueIndex::OUTBOUND_SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the outbound queue index,
+    /// which is loaded in [`QueueIndex::enqueue_into`] if it has been created
+    pub outbound_queue: UncheckedAccount<'info>,
+
+    /// The sender's exemption from the outbound rate limit, if it has one.
+    /// NOTE: checked against the sender when the transfer is inserted into
+    /// the outbox
+    #[account(mut)]
+    pub rate_limit_exemption: Option<Account<'info, RateLimitExemption>>,
+
+    #[account(
+        mut,
+        address = config.fees.current().fee_recipient @ NTTError::InvalidFeeRecipient,
+    )]
+    /// The token account that receives the protocol fee. Required when the
+    /// transfer is charged a fee (see [`FeeSettingsV1`]).
+    pub fee_recipient: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+}
+
+/// The accounts the token movement and outbox insertion need, shared between
+/// [`Transfer`] (keypair outbox items) and
+/// [`crate::instructions::TransferSequenced`] (PDA outbox items).
+pub(crate) trait TransferAccounts<'info> {
+    fn payer(&self) -> &Signer<'info>;
+    fn mint(&self) -> &InterfaceAccount<'info, token_interface::Mint>;
+    fn from(&self) -> &InterfaceAccount<'info, token_interface::TokenAccount>;
+    fn token_program(&self) -> &Interface<'info, token_interface::TokenInterface>;
+    fn custody(&mut self) -> &mut InterfaceAccount<'info, token_interface::TokenAccount>;
+    fn outbox_rate_limit(&mut self) -> &mut Account<'info, OutboxRateLimit>;
+    fn outbox_item(&mut self) -> &mut Account<'info, OutboxItem>;
+    fn outbound_queue(&self) -> &UncheckedAccount<'info>;
+    fn rate_limit_exemption(&mut self) -> Option<&mut Account<'info, RateLimitExemption>>;
+    fn config(&self) -> &Config;
+    fn fee_recipient(&self) -> Option<&InterfaceAccount<'info, token_interface::TokenAccount>>;
+}
+
+impl<'info> TransferAccounts<'info> for Transfer<'info> {
+    fn payer(&self) -> &Signer<'info> {
+        &self.payer
+    }
+
+    fn mint(&self) -> &InterfaceAccount<'info, token_interface::Mint> {
+        &self.mint
+    }
+
+    fn from(&self) -> &InterfaceAccount<'info, token_interface::TokenAccount> {
+        &self.from
+    }
+
+    fn token_program(&self) -> &Interface<'info, token_interface::TokenInterface> {
+        &self.token_program
+    }
+
+    fn custody(&mut self) -> &mut InterfaceAccount<'info, token_interface::TokenAccount> {
+        &mut self.custody
+    }
+
+    fn outbox_rate_limit(&mut self) -> &mut Account<'info, OutboxRateLimit> {
+        &mut self.outbox_rate_limit
+    }
+
+    fn outbox_item(&mut self) -> &mut Account<'info, OutboxItem> {
+        &mut self.outbox_item
+    }
+
+    fn outbound_queue(&self) -> &UncheckedAccount<'info> {
+        &self.outbound_queue
+    }
+
+    fn rate_limit_exemption(&mut self) -> Option<&mut Account<'info, RateLimitExemption>> {
+        self.rate_limit_exemption.as_mut()
+    }
+
+    fn config(&self) -> &Config {
+        &self.config
+    }
+
+    fn fee_recipient(&self) -> Option<&InterfaceAccount<'info, token_interface::TokenAccount>> {
+        self.fee_recipient.as_ref()
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -159,7 +294,100 @@ pub fn transfer_burn<'info>(
     args: TransferArgs,
 ) -> Result<()> {
     let accs = ctx.accounts;
+    let sender = accs.common.from.owner;
+    let args_hash = args.keccak256();
 
+    burn_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        accs.token_authority.to_account_info(),
+        ctx.bumps.token_authority,
+        accs.session_authority.to_account_info(),
+        &[&[
+            crate::SESSION_AUTHORITY_SEED,
+            sender.as_ref(),
+            args_hash.as_ref(),
+            &[ctx.bumps.session_authority],
+        ]],
+        ctx.remaining_accounts,
+        sender,
+        args,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(args: TransferArgs)]
+pub struct TransferBurnDirect<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+    )]
+    pub common: Transfer<'info>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    /// The owner of `common.from`, or its delegate. The spl token program
+    /// checks that it can spend the tokens.
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    pub token_authority: UncheckedAccount<'info>,
+}
+
+/// Same as [`transfer_burn`], but the tokens are spent by `authority` (the
+/// owner or the delegate of the `from` token account) signing directly,
+/// instead of going through a session authority. See the [module
+/// documentation](self) for an example of invoking this instruction via CPI.
+///
+/// The outbox item's `sender` is set to `authority`, which may be a program
+/// PDA.
+pub fn transfer_burn_direct<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBurnDirect<'info>>,
+    args: TransferArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    burn_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        accs.token_authority.to_account_info(),
+        ctx.bumps.token_authority,
+        accs.authority.to_account_info(),
+        &[],
+        ctx.remaining_accounts,
+        accs.authority.key(),
+        args,
+    )
+}
+
+pub(crate) fn burn_and_insert_into_outbox<'info>(
+    common: &mut impl TransferAccounts<'info>,
+    inbox_rate_limit: &mut InboxRateLimit,
+    peer: &NttManagerPeer,
+    token_authority: AccountInfo<'info>,
+    token_authority_bump: u8,
+    authority: AccountInfo<'info>,
+    authority_signer_seeds: &[&[&[u8]]],
+    remaining_accounts: &[AccountInfo<'info>],
+    sender: Pubkey,
+    args: TransferArgs,
+) -> Result<()> {
     let TransferArgs {
         mut amount,
         recipient_chain,
@@ -167,15 +395,21 @@ pub fn transfer_burn<'info>(
         should_queue,
     } = args;
 
-    // TODO: should we revert if we have dust?
-    let trimmed_amount = TrimmedAmount::remove_dust(
-        &mut amount,
-        accs.common.mint.decimals,
-        accs.peer.token_decimals,
-    )
-    .map_err(NTTError::from)?;
+    peer.validate_recipient(&recipient_address)?;
+
+    let requested_amount = amount;
+    let fee = pay_protocol_fee(
+        common,
+        peer,
+        authority.clone(),
+        authority_signer_seeds,
+        remaining_accounts,
+        amount,
+    )?;
+    amount -= fee;
+    let trimmed_amount = trim_amount(common.config(), common.mint().decimals, peer, &mut amount)?;
 
-    let before = accs.common.custody.amount;
+    let before = common.custody().amount;
 
     // NOTE: burning tokens is a two-step process:
     // 1. Transfer the tokens to the custody account
@@ -194,38 +428,33 @@ pub fn transfer_burn<'info>(
 
     // Step 1: transfer to custody account
     onchain::invoke_transfer_checked(
-        &accs.common.token_program.key(),
-        accs.common.from.to_account_info(),
-        accs.common.mint.to_account_info(),
-        accs.common.custody.to_account_info(),
-        accs.session_authority.to_account_info(),
-        ctx.remaining_accounts,
+        &common.token_program().key(),
+        common.from().to_account_info(),
+        common.mint().to_account_info(),
+        common.custody().to_account_info(),
+        authority,
+        remaining_accounts,
         amount,
-        accs.common.mint.decimals,
-        &[&[
-            crate::SESSION_AUTHORITY_SEED,
-            accs.common.from.owner.as_ref(),
-            args.keccak256().as_ref(),
-            &[ctx.bumps.session_authority],
-        ]],
+        common.mint().decimals,
+        authority_signer_seeds,
     )?;
 
     // Step 2: burn the tokens from the custody account
     token_interface::burn(
         CpiContext::new_with_signer(
-            accs.common.token_program.to_account_info(),
+            common.token_program().to_account_info(),
             token_interface::Burn {
-                mint: accs.common.mint.to_account_info(),
-                from: accs.common.custody.to_account_info(),
-                authority: accs.token_authority.to_account_info(),
+                mint: common.mint().to_account_info(),
+                from: common.custody().to_account_info(),
+                authority: token_authority,
             },
-            &[&[crate::TOKEN_AUTHORITY_SEED, &[ctx.bumps.token_authority]]],
+            &[&[crate::TOKEN_AUTHORITY_SEED, &[token_authority_bump]]],
         ),
         amount,
     )?;
 
-    accs.common.custody.reload()?;
-    let after = accs.common.custody.amount;
+    common.custody().reload()?;
+    let after = common.custody().amount;
 
     // NOTE: we currently do not support tokens with fees. Support could be
     // added, but it would require the client to calculate the amount _before_
@@ -241,15 +470,16 @@ pub fn transfer_burn<'info>(
         return Err(NTTError::BadAmountAfterBurn.into());
     }
 
-    let recipient_ntt_manager = accs.peer.address;
-
     insert_into_outbox(
-        &mut accs.common,
-        &mut accs.inbox_rate_limit,
+        common,
+        inbox_rate_limit,
+        peer,
+        requested_amount,
+        fee,
         amount,
         trimmed_amount,
+        sender,
         recipient_chain,
-        recipient_ntt_manager,
         recipient_address,
         should_queue,
     )
@@ -301,7 +531,87 @@ pub fn transfer_lock<'info>(
     args: TransferArgs,
 ) -> Result<()> {
     let accs = ctx.accounts;
+    let sender = accs.common.from.owner;
+    let args_hash = args.keccak256();
 
+    lock_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        accs.session_authority.to_account_info(),
+        &[&[
+            crate::SESSION_AUTHORITY_SEED,
+            sender.as_ref(),
+            args_hash.as_ref(),
+            &[ctx.bumps.session_authority],
+        ]],
+        ctx.remaining_accounts,
+        sender,
+        args,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(args: TransferArgs)]
+pub struct TransferLockDirect<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    pub common: Transfer<'info>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    /// The owner of `common.from`, or its delegate. The spl token program
+    /// checks that it can spend the tokens.
+    pub authority: Signer<'info>,
+}
+
+/// Same as [`transfer_lock`], but the tokens are spent by `authority` (the
+/// owner or the delegate of the `from` token account) signing directly,
+/// instead of going through a session authority. See the [module
+/// documentation](self) for an example of invoking this instruction via CPI.
+///
+/// The outbox item's `sender` is set to `authority`, which may be a program
+/// PDA.
+pub fn transfer_lock_direct<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferLockDirect<'info>>,
+    args: TransferArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    lock_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        accs.authority.to_account_info(),
+        &[],
+        ctx.remaining_accounts,
+        accs.authority.key(),
+        args,
+    )
+}
+
+pub(crate) fn lock_and_insert_into_outbox<'info>(
+    common: &mut impl TransferAccounts<'info>,
+    inbox_rate_limit: &mut InboxRateLimit,
+    peer: &NttManagerPeer,
+    authority: AccountInfo<'info>,
+    authority_signer_seeds: &[&[&[u8]]],
+    remaining_accounts: &[AccountInfo<'info>],
+    sender: Pubkey,
+    args: TransferArgs,
+) -> Result<()> {
     let TransferArgs {
         mut amount,
         recipient_chain,
@@ -309,35 +619,36 @@ pub fn transfer_lock<'info>(
         should_queue,
     } = args;
 
-    // TODO: should we revert if we have dust?
-    let trimmed_amount = TrimmedAmount::remove_dust(
-        &mut amount,
-        accs.common.mint.decimals,
-        accs.peer.token_decimals,
-    )
-    .map_err(NTTError::from)?;
+    peer.validate_recipient(&recipient_address)?;
 
-    let before = accs.common.custody.amount;
+    let requested_amount = amount;
+    let fee = pay_protocol_fee(
+        common,
+        peer,
+        authority.clone(),
+        authority_signer_seeds,
+        remaining_accounts,
+        amount,
+    )?;
+    amount -= fee;
+    let trimmed_amount = trim_amount(common.config(), common.mint().decimals, peer, &mut amount)?;
+
+    let before = common.custody().amount;
 
     onchain::invoke_transfer_checked(
-        &accs.common.token_program.key(),
-        accs.common.from.to_account_info(),
-        accs.common.mint.to_account_info(),
-        accs.common.custody.to_account_info(),
-        accs.session_authority.to_account_info(),
-        ctx.remaining_accounts,
+        &common.token_program().key(),
+        common.from().to_account_info(),
+        common.mint().to_account_info(),
+        common.custody().to_account_info(),
+        authority,
+        remaining_accounts,
         amount,
-        accs.common.mint.decimals,
-        &[&[
-            crate::SESSION_AUTHORITY_SEED,
-            accs.common.from.owner.as_ref(),
-            args.keccak256().as_ref(),
-            &[ctx.bumps.session_authority],
-        ]],
+        common.mint().decimals,
+        authority_signer_seeds,
     )?;
 
-    accs.common.custody.reload()?;
-    let after = accs.common.custody.amount;
+    common.custody().reload()?;
+    let after = common.custody().amount;
 
     // NOTE: we currently do not support tokens with fees. Support could be
     // added, but it would require the client to calculate the amount _before_
@@ -351,54 +662,180 @@ pub fn transfer_lock<'info>(
         return Err(NTTError::BadAmountAfterTransfer.into());
     }
 
-    let recipient_ntt_manager = accs.peer.address;
-
     insert_into_outbox(
-        &mut accs.common,
-        &mut accs.inbox_rate_limit,
+        common,
+        inbox_rate_limit,
+        peer,
+        requested_amount,
+        fee,
         amount,
         trimmed_amount,
+        sender,
         recipient_chain,
-        recipient_ntt_manager,
         recipient_address,
         should_queue,
     )
 }
 
-fn insert_into_outbox(
-    common: &mut Transfer<'_>,
+/// Removes the dust from `amount`, or rejects it in strict dust mode (see
+/// [`DustSettingsV1::strict`]), and checks the result against the peer's
+/// amount limits.
+pub(crate) fn trim_amount(
+    config: &Config,
+    decimals: u8,
+    peer: &NttManagerPeer,
+    amount: &mut u64,
+) -> Result<TrimmedAmount> {
+    let untrimmed_amount = *amount;
+    let trimmed_amount = TrimmedAmount::remove_dust(amount, decimals, peer.token_decimals)
+        .map_err(NTTError::from)?;
+    if config.dust.current().strict && *amount != untrimmed_amount {
+        return Err(NTTError::TransferHasDust.into());
+    }
+    peer.amount_limits.current().check(*amount)?;
+    Ok(trimmed_amount)
+}
+
+/// Transfers the protocol fee on `amount` (at the peer's rate, if it overrides
+/// the global one) from the sender to the fee recipient, and returns it.
+///
+/// The fee is deducted before the dust is removed, so the dust of the
+/// remaining amount stays with the sender as usual.
+fn pay_protocol_fee<'info>(
+    common: &impl TransferAccounts<'info>,
+    peer: &NttManagerPeer,
+    authority: AccountInfo<'info>,
+    authority_signer_seeds: &[&[&[u8]]],
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<u64> {
+    let fee = common
+        .config()
+        .fees
+        .current()
+        .fee(amount, peer.fee.current().fee_bps);
+    if fee == 0 {
+        return Ok(0);
+    }
+
+    let fee_recipient = common
+        .fee_recipient()
+        .ok_or(NTTError::MissingFeeRecipient)?;
+
+    onchain::invoke_transfer_checked(
+        &common.token_program().key(),
+        common.from().to_account_info(),
+        common.mint().to_account_info(),
+        fee_recipient.to_account_info(),
+        authority,
+        remaining_accounts,
+        fee,
+        common.mint().decimals,
+        authority_signer_seeds,
+    )?;
+
+    Ok(fee)
+}
+
+fn insert_into_outbox<'info>(
+    common: &mut impl TransferAccounts<'info>,
     inbox_rate_limit: &mut InboxRateLimit,
+    peer: &NttManagerPeer,
+    requested_amount: u64,
+    fee: u64,
     amount: u64,
     trimmed_amount: TrimmedAmount,
+    sender: Pubkey,
     recipient_chain: ChainId,
-    recipient_ntt_manager: [u8; 32],
     recipient_address: [u8; 32],
     should_queue: bool,
 ) -> Result<()> {
-    // consume the rate limit, or delay the transfer if it's outside the limit
-    let release_timestamp = match common.outbox_rate_limit.rate_limit.consume_or_delay(amount) {
+    // consume the rate limit, or delay the transfer if it's outside the limit.
+    // Exempt senders draw from their dedicated rate limit instead, in the
+    // same mode.
+    let strict = common.outbox_rate_limit().settings.current().strict;
+    let (result, rate_limit_exempt) = match common.rate_limit_exemption() {
+        Some(exemption) => {
+            if exemption.sender != sender {
+                return Err(NTTError::InvalidRateLimitExemption.into());
+            }
+            (exemption.rate_limit.consume_or_delay(amount, strict), true)
+        }
+        None => (common.outbox_rate_limit().consume_or_delay(amount), false),
+    };
+    let (release_timestamp, delayed) = match result {
         RateLimitResult::Consumed(now) => {
             // When sending a transfer, we refill the inbound rate limit for
-            // that chain the same amount (we call this "backflow")
-            inbox_rate_limit.rate_limit.refill(now, amount);
-            now
+            // that chain the same amount (we call this "backflow"), or the
+            // share of it configured for the peer
+            inbox_rate_limit.refill_outbound_backflow(now, amount);
+            (now, false)
         }
         RateLimitResult::Delayed(release_timestamp) => {
             if !should_queue {
                 return Err(NTTError::TransferExceedsRateLimit.into());
             }
-            release_timestamp
+            (release_timestamp, true)
+        }
+    };
+
+    // large transfers wait for the peer's security delay, even if the rate
+    // limit has capacity for them. Like rate limit delays, that's only
+    // allowed if the sender asked for it.
+    let (release_timestamp, delayed) = match peer
+        .large_transfers
+        .current()
+        .outbound_release_timestamp(amount, current_timestamp())
+    {
+        Some(security_release) if security_release > release_timestamp => {
+            if !should_queue {
+                return Err(NTTError::TransferRequiresSecurityDelay.into());
+            }
+            (security_release, true)
         }
+        _ => (release_timestamp, delayed),
+    };
+
+    let outbox_item = common.outbox_item().key();
+    let queue = if delayed {
+        QueueIndex::enqueue_into(
+            common.outbound_queue(),
+            outbox_item,
+            amount,
+            release_timestamp,
+        )?
+    } else {
+        QueueMembership::new()
     };
 
-    common.outbox_item.set_inner(OutboxItem {
+    let provenance = OutboxProvenance::new(
+        requested_amount,
+        common.payer().key(),
+        rate_limit_exempt,
+        fee,
+    );
+
+    common.outbox_item().set_inner(OutboxItem {
         amount: trimmed_amount,
-        sender: common.from.owner,
+        sender,
         recipient_chain,
-        recipient_ntt_manager,
+        recipient_ntt_manager: peer.address,
         recipient_address,
         release_timestamp,
         released: Bitmap::new(),
+        queue,
+        provenance,
+    });
+
+    emit!(OutboundTransfer {
+        outbox_item,
+        sender,
+        recipient_chain,
+        recipient_address,
+        amount,
+        release_timestamp,
+        rate_limit_exempt,
+        fee,
     });
 
     Ok(())
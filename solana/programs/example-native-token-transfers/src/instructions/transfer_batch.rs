@@ -0,0 +1,531 @@
+//! Batch transfers to multiple recipients (and chains) in one instruction.
+//!
+//! The `transfer_*_batch` variants take a list of [`TransferLeg`]s, move the
+//! tokens of all legs into custody with a single token transfer, and consume
+//! the outbound rate limit once, for the aggregate amount. Each leg still gets
+//! its own [`OutboxItem`] (and so its own message), derived from the sender's
+//! [`SenderSequence`] like in the `transfer_*_sequenced` variants. The only
+//! signers are the payer and `authority` (the owner or delegate of the `from`
+//! token account, and the sender), so no outbox item keypairs or session
+//! authority approvals are needed.
+//!
+//! The accounts of the legs are passed as remaining accounts, in this order:
+//!
+//! 1. the outbox item of each leg (writable), at the sender's consecutive
+//!    sequence numbers, starting from the next one
+//! 2. the peer and the inbox rate limit (writable) of each distinct recipient
+//!    chain, in the order the chains first appear in the legs
+//!
+//! followed by any extra accounts the token transfers need (e.g. for transfer
+//! hooks).
+//!
+//! The protocol fee, strict dust mode, amount limits, backflow and large
+//! transfer delays apply to each leg as they would to a single transfer. If the
+//! aggregate exceeds the rate limit, either every leg is queued (if
+//! `should_queue` is set) or the whole batch fails. The same goes for legs
+//! held back by their peer's security delay.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::{chain_id::ChainId, mode::Mode, trimmed_amount::TrimmedAmount};
+use spl_token_2022::onchain;
+
+use crate::{
+    bitmap::Bitmap,
+    clock::current_timestamp,
+    config::*,
+    error::NTTError,
+    events::OutboundTransfer,
+    instructions::{
+        redeem::{check_pda, create_program_account},
+        transfer::trim_amount,
+    },
+    peer::NttManagerPeer,
+    queue::{
+        inbox::InboxRateLimit,
+        index::{QueueIndex, QueueMembership},
+        outbox::{
+            OutboxItem, OutboxProvenance, OutboxRateLimit, RateLimitExemption, SenderSequence,
+        },
+        rate_limit::RateLimitResult,
+    },
+};
+
+/// The maximum number of legs in a batch. Each leg adds an outbox item to the
+/// transaction, and creating it takes compute units.
+pub const MAX_BATCH_LEGS: usize = 10;
+
+#[derive(Accounts)]
+pub struct TransferBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Ensure that there exists at least one enabled transceiver
+    #[account(
+        constraint = !config.enabled_transceivers.is_empty() @ NTTError::NoRegisteredTransceivers,
+    )]
+    pub config: NotPausedConfig<'info>,
+
+    #[account(
+        mut,
+        address = config.mint,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    /// CHECK: the spl token program will check that `authority` can spend
+    ///        these tokens.
+    pub from: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    /// The owner of `from`, or its delegate. The spl token program checks that
+    /// it can spend the tokens.
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SenderSequence::INIT_SPACE,
+        seeds = [SenderSequence::SEED_PREFIX, authority.key().as_ref()],
+        bump,
+    )]
+    pub sender_sequence: Account<'info, SenderSequence>,
+
+    #[account(mut)]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        address = config.custody
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [QueueIndex::OUTBOUND_SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the outbound queue index,
+    /// which is loaded with [`QueueIndex::load`] if it has been created
+    pub outbound_queue: UncheckedAccount<'info>,
+
+    /// The sender's exemption from the outbound rate limit, if it has one.
+    /// NOTE: checked against the sender when the legs are inserted into the
+    /// outbox
+    #[account(mut)]
+    pub rate_limit_exemption: Option<Account<'info, RateLimitExemption>>,
+
+    #[account(
+        mut,
+        address = config.fees.current().fee_recipient @ NTTError::InvalidFeeRecipient,
+    )]
+    /// The token account that receives the protocol fee. Required when any
+    /// leg is charged a fee (see [`FeeSettingsV1`]).
+    pub fee_recipient: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferLeg {
+    pub recipient_chain: ChainId,
+    pub recipient_address: [u8; 32],
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferBatchArgs {
+    pub legs: Vec<TransferLeg>,
+    pub should_queue: bool,
+}
+
+// Burn/mint
+
+#[derive(Accounts)]
+pub struct TransferBurnBatch<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+    )]
+    pub common: TransferBatch<'info>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    pub token_authority: UncheckedAccount<'info>,
+}
+
+/// Burns the tokens of all legs, and issues a notification to the outbox of
+/// each leg's [`NttManagerPeer`]. See the [module documentation](self) for the
+/// accounts of the legs.
+pub fn transfer_burn_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBurnBatch<'info>>,
+    args: TransferBatchArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    batch_and_insert_into_outbox(
+        &mut accs.common,
+        ctx.bumps.common.sender_sequence,
+        Some((
+            accs.token_authority.to_account_info(),
+            ctx.bumps.token_authority,
+        )),
+        ctx.remaining_accounts,
+        args,
+    )
+}
+
+// Lock/unlock
+
+#[derive(Accounts)]
+pub struct TransferLockBatch<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    pub common: TransferBatch<'info>,
+}
+
+/// Locks the tokens of all legs, and issues a notification to the outbox of
+/// each leg's [`NttManagerPeer`]. See the [module documentation](self) for the
+/// accounts of the legs.
+pub fn transfer_lock_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferLockBatch<'info>>,
+    args: TransferBatchArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    batch_and_insert_into_outbox(
+        &mut accs.common,
+        ctx.bumps.common.sender_sequence,
+        None,
+        ctx.remaining_accounts,
+        args,
+    )
+}
+
+/// The accounts of a recipient chain of the batch.
+struct BatchChain<'info> {
+    chain_id: ChainId,
+    peer: Account<'info, NttManagerPeer>,
+    inbox_rate_limit: Account<'info, InboxRateLimit>,
+}
+
+impl<'info> BatchChain<'info> {
+    fn load<'a>(
+        chain_id: ChainId,
+        accounts: &mut impl Iterator<Item = &'a AccountInfo<'info>>,
+    ) -> Result<Self>
+    where
+        'info: 'a,
+    {
+        let (Some(peer), Some(inbox_rate_limit)) = (accounts.next(), accounts.next()) else {
+            return Err(ErrorCode::AccountNotEnoughKeys.into());
+        };
+        if !inbox_rate_limit.is_writable {
+            return Err(ErrorCode::ConstraintMut.into());
+        }
+
+        let peer = Account::<NttManagerPeer>::try_from(peer)?;
+        check_pda(
+            &peer.key(),
+            &[
+                NttManagerPeer::SEED_PREFIX,
+                chain_id.id.to_be_bytes().as_ref(),
+                &[peer.bump],
+            ],
+        )?;
+        let inbox_rate_limit = Account::<InboxRateLimit>::try_from(inbox_rate_limit)?;
+        check_pda(
+            &inbox_rate_limit.key(),
+            &[
+                InboxRateLimit::SEED_PREFIX,
+                chain_id.id.to_be_bytes().as_ref(),
+                &[inbox_rate_limit.bump],
+            ],
+        )?;
+
+        Ok(BatchChain {
+            chain_id,
+            peer,
+            inbox_rate_limit,
+        })
+    }
+}
+
+/// A leg after deducting the protocol fee and removing dust.
+struct TrimmedLeg {
+    /// Index of the leg's chain in the loaded [`BatchChain`]s.
+    chain: usize,
+    fee: u64,
+    amount: u64,
+    trimmed_amount: TrimmedAmount,
+}
+
+fn batch_and_insert_into_outbox<'info>(
+    common: &mut TransferBatch<'info>,
+    sender_sequence_bump: u8,
+    token_authority: Option<(AccountInfo<'info>, u8)>,
+    remaining_accounts: &[AccountInfo<'info>],
+    args: TransferBatchArgs,
+) -> Result<()> {
+    let TransferBatchArgs { legs, should_queue } = args;
+
+    if legs.is_empty() || legs.len() > MAX_BATCH_LEGS {
+        return Err(NTTError::InvalidBatchSize.into());
+    }
+    if remaining_accounts.len() < legs.len() {
+        return Err(ErrorCode::AccountNotEnoughKeys.into());
+    }
+    let (outbox_items, remaining_accounts) = remaining_accounts.split_at(legs.len());
+    let mut remaining_accounts = remaining_accounts.iter();
+
+    let sender = common.authority.key();
+    let fees = common.config.fees.current();
+    let mut chains: Vec<BatchChain<'info>> = Vec::new();
+    let mut trimmed_legs = Vec::with_capacity(legs.len());
+    let mut total_amount: u64 = 0;
+    let mut total_fee: u64 = 0;
+    for leg in &legs {
+        let chain = match chains
+            .iter()
+            .position(|chain| chain.chain_id == leg.recipient_chain)
+        {
+            Some(chain) => chain,
+            None => {
+                chains.push(BatchChain::load(
+                    leg.recipient_chain,
+                    &mut remaining_accounts,
+                )?);
+                chains.len() - 1
+            }
+        };
+        let peer = &chains[chain].peer;
+
+        peer.validate_recipient(&leg.recipient_address)?;
+
+        let fee = fees.fee(leg.amount, peer.fee.current().fee_bps);
+        let mut amount = leg.amount - fee;
+        let trimmed_amount = trim_amount(&common.config, common.mint.decimals, peer, &mut amount)?;
+
+        total_amount = total_amount
+            .checked_add(amount)
+            .ok_or(NTTError::BatchAmountOverflow)?;
+        total_fee = total_fee
+            .checked_add(fee)
+            .ok_or(NTTError::BatchAmountOverflow)?;
+        trimmed_legs.push(TrimmedLeg {
+            chain,
+            fee,
+            amount,
+            trimmed_amount,
+        });
+    }
+    let remaining_accounts = remaining_accounts.as_slice();
+
+    let authority = common.authority.to_account_info();
+
+    if total_fee > 0 {
+        let fee_recipient = common
+            .fee_recipient
+            .as_ref()
+            .ok_or(NTTError::MissingFeeRecipient)?;
+        onchain::invoke_transfer_checked(
+            &common.token_program.key(),
+            common.from.to_account_info(),
+            common.mint.to_account_info(),
+            fee_recipient.to_account_info(),
+            authority.clone(),
+            remaining_accounts,
+            total_fee,
+            common.mint.decimals,
+            &[],
+        )?;
+    }
+
+    let before = common.custody.amount;
+
+    // NOTE: like in single transfers, the tokens are transferred to the
+    // custody account even when burning, so that transfer hooks are called
+    // (see [`crate::instructions::transfer_burn`])
+    onchain::invoke_transfer_checked(
+        &common.token_program.key(),
+        common.from.to_account_info(),
+        common.mint.to_account_info(),
+        common.custody.to_account_info(),
+        authority,
+        remaining_accounts,
+        total_amount,
+        common.mint.decimals,
+        &[],
+    )?;
+
+    match token_authority {
+        Some((token_authority, token_authority_bump)) => {
+            token_interface::burn(
+                CpiContext::new_with_signer(
+                    common.token_program.to_account_info(),
+                    token_interface::Burn {
+                        mint: common.mint.to_account_info(),
+                        from: common.custody.to_account_info(),
+                        authority: token_authority,
+                    },
+                    &[&[crate::TOKEN_AUTHORITY_SEED, &[token_authority_bump]]],
+                ),
+                total_amount,
+            )?;
+
+            common.custody.reload()?;
+            if common.custody.amount != before {
+                return Err(NTTError::BadAmountAfterBurn.into());
+            }
+        }
+        None => {
+            common.custody.reload()?;
+            if common.custody.amount != before + total_amount {
+                return Err(NTTError::BadAmountAfterTransfer.into());
+            }
+        }
+    }
+
+    // consume the rate limit for the aggregate amount, or delay all legs if
+    // it's outside the limit
+    let strict = common.outbox_rate_limit.settings.current().strict;
+    let (result, rate_limit_exempt) = match &mut common.rate_limit_exemption {
+        Some(exemption) => {
+            if exemption.sender != sender {
+                return Err(NTTError::InvalidRateLimitExemption.into());
+            }
+            (
+                exemption.rate_limit.consume_or_delay(total_amount, strict),
+                true,
+            )
+        }
+        None => (
+            common.outbox_rate_limit.consume_or_delay(total_amount),
+            false,
+        ),
+    };
+    let (release_timestamp, delayed) = match result {
+        RateLimitResult::Consumed(now) => {
+            // each leg flows back into the inbound rate limit of its chain
+            for leg in &trimmed_legs {
+                chains[leg.chain]
+                    .inbox_rate_limit
+                    .refill_outbound_backflow(now, leg.amount);
+            }
+            (now, false)
+        }
+        RateLimitResult::Delayed(release_timestamp) => {
+            if !should_queue {
+                return Err(NTTError::TransferExceedsRateLimit.into());
+            }
+            (release_timestamp, true)
+        }
+    };
+
+    common.sender_sequence.bump = sender_sequence_bump;
+    let now = current_timestamp();
+    let mut outbound_queue = QueueIndex::load(&common.outbound_queue)?;
+    for ((leg, trimmed_leg), outbox_item) in legs.iter().zip(&trimmed_legs).zip(outbox_items) {
+        let chain = &chains[trimmed_leg.chain];
+
+        // large legs wait for their peer's security delay, even if the rate
+        // limit has capacity for the batch
+        let (release_timestamp, delayed) = match chain
+            .peer
+            .large_transfers
+            .current()
+            .outbound_release_timestamp(trimmed_leg.amount, now)
+        {
+            Some(security_release) if security_release > release_timestamp => {
+                if !should_queue {
+                    return Err(NTTError::TransferRequiresSecurityDelay.into());
+                }
+                (security_release, true)
+            }
+            _ => (release_timestamp, delayed),
+        };
+
+        let sequence = common.sender_sequence.next_sequence.to_be_bytes();
+        let (address, bump) = Pubkey::find_program_address(
+            &[OutboxItem::SEED_PREFIX, sender.as_ref(), sequence.as_ref()],
+            &crate::ID,
+        );
+        if address != outbox_item.key() {
+            return Err(
+                Error::from(ErrorCode::ConstraintSeeds).with_pubkeys((outbox_item.key(), address))
+            );
+        }
+        create_program_account(
+            outbox_item,
+            8 + OutboxItem::INIT_SPACE,
+            &[
+                OutboxItem::SEED_PREFIX,
+                sender.as_ref(),
+                sequence.as_ref(),
+                &[bump],
+            ],
+            &common.payer,
+            &common.system_program,
+        )?;
+        common.sender_sequence.next_sequence += 1;
+
+        let queue = match &mut outbound_queue {
+            Some(outbound_queue) if delayed => {
+                let index = outbound_queue.key();
+                outbound_queue.enqueue(
+                    index,
+                    outbox_item.key(),
+                    trimmed_leg.amount,
+                    release_timestamp,
+                )
+            }
+            _ => QueueMembership::new(),
+        };
+
+        OutboxItem {
+            amount: trimmed_leg.trimmed_amount,
+            sender,
+            recipient_chain: leg.recipient_chain,
+            recipient_ntt_manager: chain.peer.address,
+            recipient_address: leg.recipient_address,
+            release_timestamp,
+            released: Bitmap::new(),
+            queue,
+            provenance: OutboxProvenance::new(
+                leg.amount,
+                common.payer.key(),
+                rate_limit_exempt,
+                trimmed_leg.fee,
+            ),
+        }
+        .try_serialize(&mut &mut outbox_item.try_borrow_mut_data()?[..])?;
+
+        emit!(OutboundTransfer {
+            outbox_item: outbox_item.key(),
+            sender,
+            recipient_chain: leg.recipient_chain,
+            recipient_address: leg.recipient_address,
+            amount: trimmed_leg.amount,
+            release_timestamp,
+            rate_limit_exempt,
+            fee: trimmed_leg.fee,
+        });
+    }
+
+    if let Some(outbound_queue) = &outbound_queue {
+        outbound_queue.exit(&crate::ID)?;
+    }
+    for chain in &chains {
+        chain.inbox_rate_limit.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,307 @@
+//! Gasless transfers. The sender signs a [`TransferIntent`] off-chain, and a
+//! relayer submits it (paying for the transaction and the accounts), in exchange
+//! for a fee in the bridged token.
+//!
+//! The transaction must contain an ed25519 precompile instruction verifying the
+//! sender's signature over [`TransferIntent::message`], immediately before the
+//! transfer instruction. The tokens (both the transferred amount and the relayer
+//! fee) are spent by the sender's intent authority, which the sender has to
+//! approve beforehand. See [`crate::intent::INTENT_AUTHORITY_SEED`].
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::mode::Mode;
+use solana_program::sysvar::instructions as instructions_sysvar;
+use spl_token_2022::onchain;
+
+use crate::{
+    clock::current_timestamp,
+    error::NTTError,
+    instructions::transfer::*,
+    intent::{verify_intent_signature, IntentNonce, TransferIntent, INTENT_AUTHORITY_SEED},
+    peer::NttManagerPeer,
+    queue::inbox::InboxRateLimit,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferWithIntentArgs {
+    pub intent: TransferIntent,
+    /// The fee paid to the relayer, at most `intent.max_relayer_fee`.
+    pub relayer_fee: u64,
+}
+
+impl From<&TransferIntent> for TransferArgs {
+    fn from(intent: &TransferIntent) -> Self {
+        TransferArgs {
+            amount: intent.amount,
+            recipient_chain: intent.recipient_chain,
+            recipient_address: intent.recipient_address,
+            should_queue: intent.should_queue,
+        }
+    }
+}
+
+// Burn/mint
+
+#[derive(Accounts)]
+#[instruction(args: TransferWithIntentArgs)]
+pub struct TransferBurnWithIntent<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+        constraint = common.from.owner == args.intent.sender @ NTTError::InvalidIntentSender,
+    )]
+    pub common: Transfer<'info>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, args.intent.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.intent.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        init_if_needed,
+        payer = common.payer,
+        space = 8 + IntentNonce::INIT_SPACE,
+        seeds = [IntentNonce::SEED_PREFIX, args.intent.sender.as_ref()],
+        bump,
+    )]
+    pub intent_nonce: Account<'info, IntentNonce>,
+
+    #[account(
+        seeds = [INTENT_AUTHORITY_SEED, args.intent.sender.as_ref()],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    /// See [`crate::intent::INTENT_AUTHORITY_SEED`] for an explanation of the flow.
+    pub intent_authority: UncheckedAccount<'info>,
+
+    /// The relayer fee goes to a token account owned by the relayer, i.e. the
+    /// payer of the transaction, so that the fee can't be redirected by
+    /// someone front-running the relayer with the same intent.
+    #[account(
+        mut,
+        token::mint = common.mint,
+        constraint = relayer_fee_recipient.owner == common.payer.key() @ NTTError::InvalidRelayerFeeRecipient,
+    )]
+    pub relayer_fee_recipient: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(address = instructions_sysvar::ID)]
+    /// CHECK: The address constraint enforces that this is the instructions sysvar.
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    pub token_authority: UncheckedAccount<'info>,
+}
+
+pub fn transfer_burn_with_intent<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBurnWithIntent<'info>>,
+    args: TransferWithIntentArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+    let sender = args.intent.sender;
+
+    use_intent(
+        &mut accs.intent_nonce,
+        ctx.bumps.intent_nonce,
+        &accs.instructions,
+        &args,
+    )?;
+
+    let intent_authority_seeds: &[&[&[u8]]] = &[&[
+        INTENT_AUTHORITY_SEED,
+        sender.as_ref(),
+        &[ctx.bumps.intent_authority],
+    ]];
+
+    pay_relayer_fee(
+        &accs.common,
+        accs.intent_authority.to_account_info(),
+        &accs.relayer_fee_recipient,
+        ctx.remaining_accounts,
+        args.relayer_fee,
+        intent_authority_seeds,
+    )?;
+
+    burn_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        accs.token_authority.to_account_info(),
+        ctx.bumps.token_authority,
+        accs.intent_authority.to_account_info(),
+        intent_authority_seeds,
+        ctx.remaining_accounts,
+        sender,
+        TransferArgs::from(&args.intent),
+    )
+}
+
+// Lock/unlock
+
+#[derive(Accounts)]
+#[instruction(args: TransferWithIntentArgs)]
+pub struct TransferLockWithIntent<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+        constraint = common.from.owner == args.intent.sender @ NTTError::InvalidIntentSender,
+    )]
+    pub common: Transfer<'info>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, args.intent.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.intent.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        init_if_needed,
+        payer = common.payer,
+        space = 8 + IntentNonce::INIT_SPACE,
+        seeds = [IntentNonce::SEED_PREFIX, args.intent.sender.as_ref()],
+        bump,
+    )]
+    pub intent_nonce: Account<'info, IntentNonce>,
+
+    #[account(
+        seeds = [INTENT_AUTHORITY_SEED, args.intent.sender.as_ref()],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    /// See [`crate::intent::INTENT_AUTHORITY_SEED`] for an explanation of the flow.
+    pub intent_authority: UncheckedAccount<'info>,
+
+    /// The relayer fee goes to a token account owned by the relayer, i.e. the
+    /// payer of the transaction, so that the fee can't be redirected by
+    /// someone front-running the relayer with the same intent.
+    #[account(
+        mut,
+        token::mint = common.mint,
+        constraint = relayer_fee_recipient.owner == common.payer.key() @ NTTError::InvalidRelayerFeeRecipient,
+    )]
+    pub relayer_fee_recipient: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(address = instructions_sysvar::ID)]
+    /// CHECK: The address constraint enforces that this is the instructions sysvar.
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer_lock_with_intent<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferLockWithIntent<'info>>,
+    args: TransferWithIntentArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+    let sender = args.intent.sender;
+
+    use_intent(
+        &mut accs.intent_nonce,
+        ctx.bumps.intent_nonce,
+        &accs.instructions,
+        &args,
+    )?;
+
+    let intent_authority_seeds: &[&[&[u8]]] = &[&[
+        INTENT_AUTHORITY_SEED,
+        sender.as_ref(),
+        &[ctx.bumps.intent_authority],
+    ]];
+
+    pay_relayer_fee(
+        &accs.common,
+        accs.intent_authority.to_account_info(),
+        &accs.relayer_fee_recipient,
+        ctx.remaining_accounts,
+        args.relayer_fee,
+        intent_authority_seeds,
+    )?;
+
+    lock_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        accs.intent_authority.to_account_info(),
+        intent_authority_seeds,
+        ctx.remaining_accounts,
+        sender,
+        TransferArgs::from(&args.intent),
+    )
+}
+
+/// Checks that the intent is valid and signed by the sender, and consumes its
+/// nonce.
+fn use_intent(
+    intent_nonce: &mut IntentNonce,
+    intent_nonce_bump: u8,
+    instructions: &AccountInfo,
+    args: &TransferWithIntentArgs,
+) -> Result<()> {
+    let intent = &args.intent;
+
+    if intent.ntt_manager != crate::ID {
+        return Err(NTTError::InvalidIntentNttManager.into());
+    }
+    if current_timestamp() >= intent.expiry {
+        return Err(NTTError::IntentExpired.into());
+    }
+    if args.relayer_fee > intent.max_relayer_fee {
+        return Err(NTTError::RelayerFeeTooHigh.into());
+    }
+    if intent.nonce != intent_nonce.next_nonce {
+        return Err(NTTError::InvalidIntentNonce.into());
+    }
+
+    verify_intent_signature(instructions, &intent.sender, &intent.message()?)?;
+
+    intent_nonce.bump = intent_nonce_bump;
+    intent_nonce.next_nonce += 1;
+
+    Ok(())
+}
+
+fn pay_relayer_fee<'info>(
+    common: &Transfer<'info>,
+    intent_authority: AccountInfo<'info>,
+    relayer_fee_recipient: &InterfaceAccount<'info, token_interface::TokenAccount>,
+    remaining_accounts: &[AccountInfo<'info>],
+    relayer_fee: u64,
+    intent_authority_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if relayer_fee == 0 {
+        return Ok(());
+    }
+
+    onchain::invoke_transfer_checked(
+        &common.token_program.key(),
+        common.from.to_account_info(),
+        common.mint.to_account_info(),
+        relayer_fee_recipient.to_account_info(),
+        intent_authority,
+        remaining_accounts,
+        relayer_fee,
+        common.mint.decimals,
+        intent_authority_seeds,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,282 @@
+//! Transfers with deterministic outbox item addresses.
+//!
+//! The regular transfer instructions create the [`OutboxItem`] at a fresh
+//! keypair address, which has to co-sign the transaction. The
+//! `transfer_*_sequenced` variants instead derive it from the sender and the
+//! sender's [`SenderSequence`]:
+//!
+//! ```text
+//! [OutboxItem::SEED_PREFIX, sender, next_sequence (u64 big endian)]
+//! ```
+//!
+//! so the only signers are the payer and the sender. Since the outbox item's
+//! address is the message id, clients (and CPI callers) know the message id
+//! before submitting the transfer, and can look up past transfers by iterating
+//! over the sequence numbers.
+//!
+//! Like the `transfer_*_direct` variants, the tokens are spent by `authority`
+//! (the owner or delegate of the `from` token account) signing directly, and
+//! the sender is `authority`.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::mode::Mode;
+
+use crate::{
+    config::*,
+    error::NTTError,
+    instructions::transfer::{
+        burn_and_insert_into_outbox, lock_and_insert_into_outbox, TransferAccounts, TransferArgs,
+    },
+    peer::NttManagerPeer,
+    queue::{
+        inbox::InboxRateLimit,
+        index::QueueIndex,
+        outbox::{OutboxItem, OutboxRateLimit, RateLimitExemption, SenderSequence},
+    },
+};
+
+/// Same as [`crate::instructions::Transfer`], but the outbox item is a PDA
+/// derived from `authority` and its sequence number.
+#[derive(Accounts)]
+pub struct TransferSequenced<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Ensure that there exists at least one enabled transceiver
+    #[account(
+        constraint = !config.enabled_transceivers.is_empty() @ NTTError::NoRegisteredTransceivers,
+    )]
+    pub config: NotPausedConfig<'info>,
+
+    #[account(
+        mut,
+        address = config.mint,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    /// CHECK: the spl token program will check that `authority` can spend
+    ///        these tokens.
+    pub from: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    /// The owner of `from`, or its delegate. The spl token program checks that
+    /// it can spend the tokens.
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SenderSequence::INIT_SPACE,
+        seeds = [SenderSequence::SEED_PREFIX, authority.key().as_ref()],
+        bump,
+    )]
+    pub sender_sequence: Account<'info, SenderSequence>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OutboxItem::INIT_SPACE,
+        seeds = [
+            OutboxItem::SEED_PREFIX,
+            authority.key().as_ref(),
+            sender_sequence.next_sequence.to_be_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub outbox_item: Account<'info, OutboxItem>,
+
+    #[account(mut)]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        address = config.custody
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [QueueIndex::OUTBOUND_SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the outbound queue index,
+    /// which is loaded in [`QueueIndex::enqueue_into`] if it has been created
+    pub outbound_queue: UncheckedAccount<'info>,
+
+    /// The sender's exemption from the outbound rate limit, if it has one.
+    /// NOTE: checked against the sender when the transfer is inserted into
+    /// the outbox
+    #[account(mut)]
+    pub rate_limit_exemption: Option<Account<'info, RateLimitExemption>>,
+
+    #[account(
+        mut,
+        address = config.fees.current().fee_recipient @ NTTError::InvalidFeeRecipient,
+    )]
+    /// The token account that receives the protocol fee. Required when the
+    /// transfer is charged a fee (see [`FeeSettingsV1`]).
+    pub fee_recipient: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+}
+
+impl<'info> TransferSequenced<'info> {
+    fn next_sequence(&mut self, bump: u8) {
+        self.sender_sequence.bump = bump;
+        self.sender_sequence.next_sequence += 1;
+    }
+}
+
+impl<'info> TransferAccounts<'info> for TransferSequenced<'info> {
+    fn payer(&self) -> &Signer<'info> {
+        &self.payer
+    }
+
+    fn mint(&self) -> &InterfaceAccount<'info, token_interface::Mint> {
+        &self.mint
+    }
+
+    fn from(&self) -> &InterfaceAccount<'info, token_interface::TokenAccount> {
+        &self.from
+    }
+
+    fn token_program(&self) -> &Interface<'info, token_interface::TokenInterface> {
+        &self.token_program
+    }
+
+    fn custody(&mut self) -> &mut InterfaceAccount<'info, token_interface::TokenAccount> {
+        &mut self.custody
+    }
+
+    fn outbox_rate_limit(&mut self) -> &mut Account<'info, OutboxRateLimit> {
+        &mut self.outbox_rate_limit
+    }
+
+    fn outbox_item(&mut self) -> &mut Account<'info, OutboxItem> {
+        &mut self.outbox_item
+    }
+
+    fn outbound_queue(&self) -> &UncheckedAccount<'info> {
+        &self.outbound_queue
+    }
+
+    fn rate_limit_exemption(&mut self) -> Option<&mut Account<'info, RateLimitExemption>> {
+        self.rate_limit_exemption.as_mut()
+    }
+
+    fn config(&self) -> &Config {
+        &self.config
+    }
+
+    fn fee_recipient(&self) -> Option<&InterfaceAccount<'info, token_interface::TokenAccount>> {
+        self.fee_recipient.as_ref()
+    }
+}
+
+// Burn/mint
+
+#[derive(Accounts)]
+#[instruction(args: TransferArgs)]
+pub struct TransferBurnSequenced<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+    )]
+    pub common: TransferSequenced<'info>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    pub token_authority: UncheckedAccount<'info>,
+}
+
+/// Same as [`crate::instructions::transfer_burn_direct`], but the outbox item
+/// is derived from the sender's sequence number.
+pub fn transfer_burn_sequenced<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBurnSequenced<'info>>,
+    args: TransferArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+    accs.common.next_sequence(ctx.bumps.common.sender_sequence);
+
+    let authority = accs.common.authority.to_account_info();
+    burn_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        accs.token_authority.to_account_info(),
+        ctx.bumps.token_authority,
+        authority.clone(),
+        &[],
+        ctx.remaining_accounts,
+        authority.key(),
+        args,
+    )
+}
+
+// Lock/unlock
+
+#[derive(Accounts)]
+#[instruction(args: TransferArgs)]
+pub struct TransferLockSequenced<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    pub common: TransferSequenced<'info>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+/// Same as [`crate::instructions::transfer_lock_direct`], but the outbox item
+/// is derived from the sender's sequence number.
+pub fn transfer_lock_sequenced<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferLockSequenced<'info>>,
+    args: TransferArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+    accs.common.next_sequence(ctx.bumps.common.sender_sequence);
+
+    let authority = accs.common.authority.to_account_info();
+    lock_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        authority.clone(),
+        &[],
+        ctx.remaining_accounts,
+        authority.key(),
+        args,
+    )
+}
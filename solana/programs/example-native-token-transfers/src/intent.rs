@@ -0,0 +1,138 @@
+//! Off-chain signed transfer intents.
+//!
+//! A [`TransferIntent`] is signed by the sender (with their ed25519 wallet
+//! key), and submitted by a relayer who pays for the transaction. The signature
+//! is verified by the ed25519 precompile, in an instruction immediately
+//! preceding the transfer instruction. See [`verify_intent_signature`].
+//!
+//! Instead of approving a session authority for each transfer, the sender
+//! approves their intent authority (see [`INTENT_AUTHORITY_SEED`]) once, which
+//! can then only spend the tokens as described by signed intents.
+
+use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
+use solana_program::{ed25519_program, sysvar::instructions as instructions_sysvar};
+
+use crate::error::NTTError;
+
+/// The seed for the intent authority account.
+///
+/// The intent authority is seeded by the sender's pubkey. The sender approves
+/// it (as the delegate of their token account) to spend the tokens, after which
+/// it can be used to execute any transfer intent signed by the sender.
+/// Since each intent is only valid once (see [`IntentNonce`]), and until its
+/// expiry, the delegated amount is only ever spent as authorised by the
+/// sender.
+///
+/// NOTE: the approval can be submitted with the relayer as the fee payer, so
+/// the sender doesn't need to hold SOL for it either.
+pub const INTENT_AUTHORITY_SEED: &[u8] = b"intent_authority";
+
+/// A transfer intent signed by `sender`. The signed message is the borsh
+/// serialization of this struct (see [`TransferIntent::message`]).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TransferIntent {
+    /// The NTT manager program the intent is for. Prevents the same intent from
+    /// being replayed against other deployments.
+    pub ntt_manager: Pubkey,
+    /// The owner of the token account the tokens are transferred from.
+    pub sender: Pubkey,
+    /// The amount to transfer (excluding the relayer fee).
+    pub amount: u64,
+    pub recipient_chain: ChainId,
+    pub recipient_address: [u8; 32],
+    pub should_queue: bool,
+    /// Must match the sender's [`IntentNonce::next_nonce`].
+    pub nonce: u64,
+    /// Unix timestamp after which the intent can no longer be executed.
+    pub expiry: i64,
+    /// The maximum fee (in the bridged token) the relayer may charge, on top of
+    /// `amount`.
+    pub max_relayer_fee: u64,
+}
+
+impl TransferIntent {
+    pub fn message(&self) -> Result<Vec<u8>> {
+        Ok(self.try_to_vec()?)
+    }
+}
+
+/// Tracks the next valid intent nonce of a sender. Intents have to be executed
+/// in nonce order, and each nonce can only be used once.
+#[account]
+#[derive(InitSpace)]
+pub struct IntentNonce {
+    pub bump: u8,
+    pub next_nonce: u64,
+}
+
+impl IntentNonce {
+    pub const SEED_PREFIX: &'static [u8] = b"intent_nonce";
+}
+
+/// Start of the (first) offsets in the ed25519 precompile instruction data,
+/// after the signature count and padding bytes.
+const ED25519_OFFSETS_START: usize = 2;
+
+/// Verifies that the instruction preceding the current one is an ed25519
+/// precompile instruction checking a single signature by `signer` over
+/// `message`. The precompile itself fails the transaction if the signature is
+/// invalid.
+pub fn verify_intent_signature(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = instructions_sysvar::load_current_index_checked(instructions)?;
+    let ed25519_index = current_index
+        .checked_sub(1)
+        .ok_or(NTTError::InvalidIntentSignature)?;
+    let ix =
+        instructions_sysvar::load_instruction_at_checked(usize::from(ed25519_index), instructions)?;
+
+    if ix.program_id != ed25519_program::ID {
+        return Err(NTTError::InvalidIntentSignature.into());
+    }
+
+    // only a single signature is supported
+    let data = &ix.data;
+    if data.first() != Some(&1) {
+        return Err(NTTError::InvalidIntentSignature.into());
+    }
+
+    let offset = |i: usize| read_u16(data, ED25519_OFFSETS_START + 2 * i);
+    let signature_instruction_index = offset(1)?;
+    let public_key_offset = usize::from(offset(2)?);
+    let public_key_instruction_index = offset(3)?;
+    let message_data_offset = usize::from(offset(4)?);
+    let message_data_size = usize::from(offset(5)?);
+    let message_instruction_index = offset(6)?;
+
+    // all the data must come from the precompile instruction itself
+    if [
+        signature_instruction_index,
+        public_key_instruction_index,
+        message_instruction_index,
+    ]
+    .iter()
+    .any(|i| *i != u16::MAX)
+    {
+        return Err(NTTError::InvalidIntentSignature.into());
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32);
+    let signed_message = data.get(message_data_offset..message_data_offset + message_data_size);
+
+    if public_key != Some(signer.as_ref()) || signed_message != Some(message) {
+        return Err(NTTError::InvalidIntentSignature.into());
+    }
+
+    Ok(())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    let bytes = data
+        .get(offset..offset + 2)
+        .ok_or(NTTError::InvalidIntentSignature)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
@@ -0,0 +1,233 @@
+//! Versioned records appended to the end of existing account layouts.
+//!
+//! Accounts created before such a record was added simply end before it, so
+//! the record is read as "unavailable" instead of failing to deserialize the
+//! whole account. A record is prefixed by its layout version, starting at 1
+//! (an account zero-filled by a realloc also reads as unavailable), so that
+//! it can be extended again later without another migration.
+
+use anchor_lang::prelude::*;
+use std::io;
+
+/// Reads the layout version of a trailing record, or 0 if the account ends
+/// before it.
+pub fn read_layout_version<R: io::Read>(reader: &mut R) -> io::Result<u8> {
+    let mut version = [0u8; 1];
+    match reader.read(&mut version)? {
+        0 => Ok(0),
+        _ => Ok(version[0]),
+    }
+}
+
+/// Reads the next `size` bytes, or the rest of the account (and returns
+/// `None`) if it ends before then.
+pub fn read_record<R: io::Read>(reader: &mut R, size: usize) -> io::Result<Option<Vec<u8>>> {
+    let mut record = Vec::with_capacity(size);
+    reader.take(size as u64).read_to_end(&mut record)?;
+    Ok((record.len() == size).then_some(record))
+}
+
+pub fn unknown_layout_version(version: u8) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unknown layout version {version}"),
+    )
+}
+
+/// Implements [`AnchorSerialize`], [`AnchorDeserialize`] and [`Space`] for an
+/// enum with an `Unavailable` variant and one variant per layout version.
+///
+/// The `after_slack` form is for the first record after the slack space at
+/// the end of an account whose serialized size can shrink (e.g. because of an
+/// `Option` field). Anchor doesn't zero the bytes past the end of the
+/// serialized data, so on accounts that were never grown to fit the record
+/// the slack may hold stale data instead of zeros. Such a record is read as
+/// unavailable if the account ends before it would, so its layouts must have
+/// a fixed size.
+macro_rules! versioned_layout {
+    ($name:ident { $($version:literal => $variant:ident($ty:ty)),+ $(,)? }) => {
+        $crate::layout::versioned_layout!(@serialize $name { $($version => $variant($ty)),+ });
+
+        impl AnchorDeserialize for $name {
+            fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                match $crate::layout::read_layout_version(reader)? {
+                    0 => Ok($name::Unavailable),
+                    $($version => Ok($name::$variant(
+                        <$ty as AnchorDeserialize>::deserialize_reader(reader)?,
+                    )),)+
+                    version => Err($crate::layout::unknown_layout_version(version)),
+                }
+            }
+        }
+    };
+    ($name:ident after_slack { $($version:literal => $variant:ident($ty:ty)),+ $(,)? }) => {
+        $crate::layout::versioned_layout!(@serialize $name { $($version => $variant($ty)),+ });
+
+        impl AnchorDeserialize for $name {
+            fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                let version = $crate::layout::read_layout_version(reader)?;
+                let size = match version {
+                    0 => return Ok($name::Unavailable),
+                    $($version => <$ty as anchor_lang::Space>::INIT_SPACE,)+
+                    _ => <$name as anchor_lang::Space>::INIT_SPACE - 1,
+                };
+                let Some(record) = $crate::layout::read_record(reader, size)? else {
+                    return Ok($name::Unavailable);
+                };
+                match version {
+                    $($version => Ok($name::$variant(
+                        <$ty as AnchorDeserialize>::deserialize(&mut &record[..])?,
+                    )),)+
+                    version => Err($crate::layout::unknown_layout_version(version)),
+                }
+            }
+        }
+    };
+    (@serialize $name:ident { $($version:literal => $variant:ident($ty:ty)),+ }) => {
+        impl AnchorSerialize for $name {
+            fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                match self {
+                    $name::Unavailable => Ok(()),
+                    $($name::$variant(record) => {
+                        AnchorSerialize::serialize(&($version as u8), writer)?;
+                        AnchorSerialize::serialize(record, writer)
+                    })+
+                }
+            }
+        }
+
+        impl anchor_lang::Space for $name {
+            const INIT_SPACE: usize = {
+                let mut space = 0;
+                $(
+                    if <$ty as anchor_lang::Space>::INIT_SPACE > space {
+                        space = <$ty as anchor_lang::Space>::INIT_SPACE;
+                    }
+                )+
+                1 + space
+            };
+        }
+    };
+}
+
+pub(crate) use versioned_layout;
+
+/// Grows `account` to `space` bytes (if it's smaller), topping up its rent
+/// from `payer`. The new bytes are zeroed, so trailing versioned records read
+/// as unavailable until they're written.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if account.data_len() >= space {
+        return Ok(());
+    }
+
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    account.realloc(space, true)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{DustSettings, DustSettingsV1, FeeSettings, FeeSettingsV1};
+    use crate::pending_token_authority::{
+        PendingTokenAuthority, PendingTokenAuthorityExpiry, PendingTokenAuthorityExpiryV1,
+    };
+
+    use super::*;
+
+    fn pending_token_authority(expiry: PendingTokenAuthorityExpiry) -> PendingTokenAuthority {
+        PendingTokenAuthority {
+            bump: 255,
+            pending_authority: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            expiry,
+        }
+    }
+
+    #[test]
+    fn test_current_layout() {
+        let pending = pending_token_authority(PendingTokenAuthorityExpiry::V1(
+            PendingTokenAuthorityExpiryV1 { expiry: 100 },
+        ));
+        let data = pending.try_to_vec().unwrap();
+        assert_eq!(data.len(), PendingTokenAuthority::INIT_SPACE);
+        assert_eq!(
+            PendingTokenAuthority::deserialize(&mut &data[..]).unwrap(),
+            pending
+        );
+    }
+
+    #[test]
+    fn test_legacy_layout() {
+        // accounts created before the expiry was added end right before it
+        let legacy = (255u8, Pubkey::new_unique(), Pubkey::new_unique())
+            .try_to_vec()
+            .unwrap();
+        assert_eq!(
+            legacy.len(),
+            PendingTokenAuthority::INIT_SPACE - PendingTokenAuthorityExpiry::INIT_SPACE
+        );
+        let pending = PendingTokenAuthority::deserialize(&mut &legacy[..]).unwrap();
+        assert_eq!(pending.expiry, PendingTokenAuthorityExpiry::Unavailable);
+        assert!(pending.is_expired());
+
+        // same for accounts that were grown (and zero-filled) since
+        let mut padded = legacy.clone();
+        padded.resize(PendingTokenAuthority::INIT_SPACE, 0);
+        assert_eq!(
+            PendingTokenAuthority::deserialize(&mut &padded[..]).unwrap(),
+            pending
+        );
+
+        padded[legacy.len()] = 2;
+        assert!(PendingTokenAuthority::deserialize(&mut &padded[..]).is_err());
+    }
+
+    #[test]
+    fn test_record_after_slack() {
+        let fees = FeeSettings::V1(FeeSettingsV1 {
+            fee_bps: 10,
+            fee_recipient: Pubkey::new_unique(),
+        });
+        let dust = DustSettings::V1(DustSettingsV1 { strict: true });
+        let data = (fees.clone(), dust.clone()).try_to_vec().unwrap();
+        assert_eq!(
+            <(FeeSettings, DustSettings)>::deserialize(&mut &data[..]).unwrap(),
+            (fees, dust)
+        );
+
+        // legacy accounts end in stale bytes that are too short to hold the
+        // record, whatever version they start with
+        for version in [1, 2, 0xff] {
+            let mut stale = Pubkey::new_unique().to_bytes()[..24].to_vec();
+            stale[0] = version;
+            assert_eq!(
+                <(FeeSettings, DustSettings)>::deserialize(&mut &stale[..]).unwrap(),
+                (FeeSettings::Unavailable, DustSettings::Unavailable)
+            );
+        }
+
+        let mut unknown = data.clone();
+        unknown[0] = 2;
+        assert!(<(FeeSettings, DustSettings)>::deserialize(&mut &unknown[..]).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
 
 // TODO: is there a more elegant way of checking that these 3 features are mutually exclusive?
 
@@ -11,11 +12,17 @@ compile_error!("Cannot enable both mainnet and tilt-devnet features at the same
 #[cfg(all(feature = "solana-devnet", feature = "tilt-devnet"))]
 compile_error!("Cannot enable both solana-devnet and tilt-devnet features at the same time");
 
+pub mod audit_log;
 pub mod bitmap;
 pub mod clock;
 pub mod config;
+pub mod council;
+pub mod directory;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod intent;
+pub mod layout;
 pub mod messages;
 pub mod peer;
 pub mod pending_token_authority;
@@ -65,7 +72,12 @@ pub const TOKEN_AUTHORITY_SEED: &[u8] = b"token_authority";
 /// user, atomically).
 pub const SESSION_AUTHORITY_SEED: &[u8] = b"session_authority";
 
-pub const VERSION: &str = "3.0.0";
+/// How long (in seconds) a pending owner (set by `transfer_ownership`) or a
+/// pending token authority (set by `set_token_authority`) can be claimed for.
+/// After this, the owner has to cancel (or revert) the transfer and start over.
+pub const PENDING_AUTHORITY_VALIDITY: i64 = 7 * 24 * 60 * 60;
+
+pub const VERSION: &str = "4.0.0";
 
 #[program]
 pub mod example_native_token_transfers {
@@ -80,10 +92,93 @@ pub mod example_native_token_transfers {
         instructions::initialize_lut(ctx, recent_slot)
     }
 
+    pub fn initialize_audit_log(ctx: Context<InitializeAuditLog>) -> Result<()> {
+        instructions::initialize_audit_log(ctx)
+    }
+
+    pub fn initialize_directory(ctx: Context<InitializeDirectory>) -> Result<()> {
+        instructions::initialize_directory(ctx)
+    }
+
+    pub fn sync_directory_peer(ctx: Context<SyncDirectoryPeer>, chain_id: ChainId) -> Result<()> {
+        instructions::sync_directory_peer(ctx, chain_id)
+    }
+
+    pub fn sync_directory_transceiver(ctx: Context<SyncDirectoryTransceiver>) -> Result<()> {
+        instructions::sync_directory_transceiver(ctx)
+    }
+
+    pub fn initialize_outbound_queue(ctx: Context<InitializeOutboundQueue>) -> Result<()> {
+        instructions::initialize_outbound_queue(ctx)
+    }
+
+    pub fn initialize_inbound_queue(
+        ctx: Context<InitializeInboundQueue>,
+        chain_id: ChainId,
+    ) -> Result<()> {
+        instructions::initialize_inbound_queue(ctx, chain_id)
+    }
+
+    pub fn dequeue_released_outbound(ctx: Context<DequeueReleasedOutbound>) -> Result<()> {
+        instructions::dequeue_released_outbound(ctx)
+    }
+
+    pub fn dequeue_released_inbound(ctx: Context<DequeueReleasedInbound>) -> Result<()> {
+        instructions::dequeue_released_inbound(ctx)
+    }
+
+    pub fn approve_large_inbound(
+        ctx: Context<ApproveLargeInbound>,
+        chain_id: ChainId,
+    ) -> Result<()> {
+        instructions::approve_large_inbound(ctx, chain_id)
+    }
+
+    pub fn approve_large_inbound_by_owner(ctx: Context<ApproveLargeInboundByOwner>) -> Result<()> {
+        instructions::approve_large_inbound_by_owner(ctx)
+    }
+
+    pub fn record_transceiver_admin_action(
+        ctx: Context<RecordTransceiverAdminAction>,
+        args: RecordTransceiverAdminActionArgs,
+    ) -> Result<()> {
+        instructions::record_transceiver_admin_action(ctx, args)
+    }
+
     pub fn version(_ctx: Context<Version>) -> Result<String> {
         Ok(VERSION.to_string())
     }
 
+    pub fn query_outbound_capacity(
+        ctx: Context<QueryOutboundCapacity>,
+    ) -> Result<RateLimitCapacity> {
+        instructions::query_outbound_capacity(ctx)
+    }
+
+    pub fn query_inbound_capacity(
+        ctx: Context<QueryInboundCapacity>,
+        chain_id: ChainId,
+    ) -> Result<RateLimitCapacity> {
+        instructions::query_inbound_capacity(ctx, chain_id)
+    }
+
+    pub fn query_outbox_item_status(
+        ctx: Context<QueryOutboxItemStatus>,
+    ) -> Result<OutboxItemStatus> {
+        instructions::query_outbox_item_status(ctx)
+    }
+
+    pub fn query_inbox_item_status(ctx: Context<QueryInboxItemStatus>) -> Result<InboxItemStatus> {
+        instructions::query_inbox_item_status(ctx)
+    }
+
+    pub fn simulate_transfer(
+        ctx: Context<SimulateTransfer>,
+        args: TransferArgs,
+    ) -> Result<TransferSimulation> {
+        instructions::simulate_transfer(ctx, args)
+    }
+
     pub fn transfer_burn<'info>(
         ctx: Context<'_, '_, '_, 'info, TransferBurn<'info>>,
         args: TransferArgs,
@@ -98,6 +193,62 @@ pub mod example_native_token_transfers {
         instructions::transfer_lock(ctx, args)
     }
 
+    pub fn transfer_burn_direct<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBurnDirect<'info>>,
+        args: TransferArgs,
+    ) -> Result<()> {
+        instructions::transfer_burn_direct(ctx, args)
+    }
+
+    pub fn transfer_lock_direct<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferLockDirect<'info>>,
+        args: TransferArgs,
+    ) -> Result<()> {
+        instructions::transfer_lock_direct(ctx, args)
+    }
+
+    pub fn transfer_burn_sequenced<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBurnSequenced<'info>>,
+        args: TransferArgs,
+    ) -> Result<()> {
+        instructions::transfer_burn_sequenced(ctx, args)
+    }
+
+    pub fn transfer_lock_sequenced<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferLockSequenced<'info>>,
+        args: TransferArgs,
+    ) -> Result<()> {
+        instructions::transfer_lock_sequenced(ctx, args)
+    }
+
+    pub fn transfer_burn_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBurnBatch<'info>>,
+        args: TransferBatchArgs,
+    ) -> Result<()> {
+        instructions::transfer_burn_batch(ctx, args)
+    }
+
+    pub fn transfer_lock_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferLockBatch<'info>>,
+        args: TransferBatchArgs,
+    ) -> Result<()> {
+        instructions::transfer_lock_batch(ctx, args)
+    }
+
+    pub fn transfer_burn_with_intent<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBurnWithIntent<'info>>,
+        args: TransferWithIntentArgs,
+    ) -> Result<()> {
+        instructions::transfer_burn_with_intent(ctx, args)
+    }
+
+    pub fn transfer_lock_with_intent<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferLockWithIntent<'info>>,
+        args: TransferWithIntentArgs,
+    ) -> Result<()> {
+        instructions::transfer_lock_with_intent(ctx, args)
+    }
+
     pub fn redeem(ctx: Context<Redeem>, args: RedeemArgs) -> Result<()> {
         instructions::redeem(ctx, args)
     }
@@ -116,6 +267,44 @@ pub mod example_native_token_transfers {
         instructions::release_inbound_unlock(ctx, args)
     }
 
+    pub fn deliver_gas_dropoff(ctx: Context<DeliverGasDropoff>) -> Result<()> {
+        instructions::deliver_gas_dropoff(ctx)
+    }
+
+    pub fn accept_inbound_to_token_account(
+        ctx: Context<AcceptInboundToTokenAccount>,
+    ) -> Result<()> {
+        instructions::accept_inbound_to_token_account(ctx)
+    }
+
+    pub fn report_failed_inbound_release(ctx: Context<ReportFailedInboundRelease>) -> Result<()> {
+        instructions::report_failed_inbound_release(ctx)
+    }
+
+    pub fn request_inbound_escrow(ctx: Context<RequestInboundEscrow>) -> Result<()> {
+        instructions::request_inbound_escrow(ctx)
+    }
+
+    pub fn redirect_escrowed_inbound(ctx: Context<RedirectEscrowedInbound>) -> Result<()> {
+        instructions::redirect_escrowed_inbound(ctx)
+    }
+
+    pub fn set_escrow_recovery(ctx: Context<SetEscrowRecovery>) -> Result<()> {
+        instructions::set_escrow_recovery(ctx)
+    }
+
+    pub fn release_escrowed_inbound_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseEscrowedInboundMint<'info>>,
+    ) -> Result<()> {
+        instructions::release_escrowed_inbound_mint(ctx)
+    }
+
+    pub fn release_escrowed_inbound_unlock<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseEscrowedInboundUnlock<'info>>,
+    ) -> Result<()> {
+        instructions::release_escrowed_inbound_unlock(ctx)
+    }
+
     pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
         instructions::transfer_ownership(ctx)
     }
@@ -128,6 +317,10 @@ pub mod example_native_token_transfers {
         instructions::claim_ownership(ctx)
     }
 
+    pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+        instructions::cancel_ownership_transfer(ctx)
+    }
+
     pub fn accept_token_authority(ctx: Context<AcceptTokenAuthority>) -> Result<()> {
         instructions::accept_token_authority(ctx)
     }
@@ -152,6 +345,12 @@ pub mod example_native_token_transfers {
         instructions::revert_token_authority(ctx)
     }
 
+    pub fn close_expired_pending_token_authority(
+        ctx: Context<CloseExpiredPendingTokenAuthority>,
+    ) -> Result<()> {
+        instructions::close_expired_pending_token_authority(ctx)
+    }
+
     pub fn claim_token_authority(ctx: Context<ClaimTokenAuthority>) -> Result<()> {
         instructions::claim_token_authority(ctx)
     }
@@ -170,6 +369,10 @@ pub mod example_native_token_transfers {
         instructions::set_peer(ctx, args)
     }
 
+    pub fn remove_peer(ctx: Context<RemovePeer>, chain_id: ChainId) -> Result<()> {
+        instructions::remove_peer(ctx, chain_id)
+    }
+
     pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
         instructions::register_transceiver(ctx)
     }
@@ -192,6 +395,71 @@ pub mod example_native_token_transfers {
         instructions::set_inbound_limit(ctx, args)
     }
 
+    pub fn set_outbound_strict_mode(
+        ctx: Context<SetOutboundStrictMode>,
+        strict: bool,
+    ) -> Result<()> {
+        instructions::set_outbound_strict_mode(ctx, strict)
+    }
+
+    pub fn set_strict_dust_mode(ctx: Context<SetStrictDustMode>, strict: bool) -> Result<()> {
+        instructions::set_strict_dust_mode(ctx, strict)
+    }
+
+    pub fn set_inbound_strict_mode(
+        ctx: Context<SetInboundStrictMode>,
+        args: SetInboundStrictModeArgs,
+    ) -> Result<()> {
+        instructions::set_inbound_strict_mode(ctx, args)
+    }
+
+    pub fn set_rate_limit_exemption(
+        ctx: Context<SetRateLimitExemption>,
+        args: SetRateLimitExemptionArgs,
+    ) -> Result<()> {
+        instructions::set_rate_limit_exemption(ctx, args)
+    }
+
+    pub fn remove_rate_limit_exemption(
+        ctx: Context<RemoveRateLimitExemption>,
+        sender: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_rate_limit_exemption(ctx, sender)
+    }
+
+    pub fn set_backflow(ctx: Context<SetBackflow>, args: SetBackflowArgs) -> Result<()> {
+        instructions::set_backflow(ctx, args)
+    }
+
+    pub fn set_peer_address_format(
+        ctx: Context<SetPeerAddressFormat>,
+        args: SetPeerAddressFormatArgs,
+    ) -> Result<()> {
+        instructions::set_peer_address_format(ctx, args)
+    }
+
+    pub fn set_large_transfer_policy(
+        ctx: Context<SetLargeTransferPolicy>,
+        args: SetLargeTransferPolicyArgs,
+    ) -> Result<()> {
+        instructions::set_large_transfer_policy(ctx, args)
+    }
+
+    pub fn set_fee(ctx: Context<SetFee>, args: SetFeeArgs) -> Result<()> {
+        instructions::set_fee(ctx, args)
+    }
+
+    pub fn set_peer_fee(ctx: Context<SetPeerFee>, args: SetPeerFeeArgs) -> Result<()> {
+        instructions::set_peer_fee(ctx, args)
+    }
+
+    pub fn set_amount_limits(
+        ctx: Context<SetAmountLimits>,
+        args: SetAmountLimitsArgs,
+    ) -> Result<()> {
+        instructions::set_amount_limits(ctx, args)
+    }
+
     pub fn mark_outbox_item_as_released(ctx: Context<MarkOutboxItemAsReleased>) -> Result<bool> {
         instructions::mark_outbox_item_as_released(ctx)
     }
@@ -200,6 +468,43 @@ pub mod example_native_token_transfers {
         instructions::set_threshold(ctx, threshold)
     }
 
+    // council
+
+    pub fn initialize_council(
+        ctx: Context<InitializeCouncil>,
+        args: SetCouncilMembersArgs,
+    ) -> Result<()> {
+        instructions::initialize_council(ctx, args)
+    }
+
+    pub fn set_council_members(
+        ctx: Context<SetCouncilMembers>,
+        args: SetCouncilMembersArgs,
+    ) -> Result<()> {
+        instructions::set_council_members(ctx, args)
+    }
+
+    pub fn propose_council_instruction(
+        ctx: Context<ProposeCouncilInstruction>,
+        council_instruction: crate::council::CouncilInstruction,
+    ) -> Result<()> {
+        instructions::propose_council_instruction(ctx, council_instruction)
+    }
+
+    pub fn approve_council_proposal(ctx: Context<ApproveCouncilProposal>) -> Result<()> {
+        instructions::approve_council_proposal(ctx)
+    }
+
+    pub fn execute_council_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteCouncilProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_council_proposal(ctx)
+    }
+
+    pub fn close_council_proposal(ctx: Context<CloseCouncilProposal>) -> Result<()> {
+        instructions::close_council_proposal(ctx)
+    }
+
     // standalone transceiver stuff
 
     pub fn set_wormhole_peer(
@@ -213,6 +518,20 @@ pub mod example_native_token_transfers {
         transceivers::wormhole::instructions::receive_message(ctx)
     }
 
+    pub fn receive_and_release_wormhole_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReceiveAndReleaseMint<'info>>,
+        args: ReleaseInboundArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::receive_and_release_wormhole_mint(ctx, args)
+    }
+
+    pub fn receive_and_release_wormhole_unlock<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReceiveAndReleaseUnlock<'info>>,
+        args: ReleaseInboundArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::receive_and_release_wormhole_unlock(ctx, args)
+    }
+
     pub fn release_wormhole_outbound(
         ctx: Context<ReleaseOutbound>,
         args: ReleaseOutboundArgs,
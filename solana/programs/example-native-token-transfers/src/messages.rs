@@ -1,15 +1,22 @@
 use anchor_lang::{prelude::*, system_program, Discriminator};
 use ntt_messages::{
     chain_id::ChainId,
+    gas_dropoff::GasDropoff,
     transceiver::{TransceiverMessageData, TransceiverMessageDataBytes},
 };
 use std::{collections::HashMap, marker::PhantomData};
 
+use crate::layout::versioned_layout;
+
 #[account]
 #[derive(InitSpace)]
 pub struct ValidatedTransceiverMessage<A: AnchorDeserialize + AnchorSerialize + Space + Clone> {
     pub from_chain: ChainId,
     pub message: TransceiverMessageData<A>,
+    /// NOTE: this field was appended after the initial release, and must
+    /// remain the last one, so that messages validated before then (or by
+    /// transceivers that don't record it) can still be read.
+    pub gas_dropoff: RequestedGasDropoff,
 }
 
 impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceiverMessage<A> {
@@ -54,6 +61,42 @@ impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceive
     }
 }
 
+/// The native gas dropoff requested in the transceiver payload of a message
+/// (see [`ntt_messages::gas_dropoff::GasDropoff`]).
+///
+/// NOTE: this was added after the initial release, so it's stored as a
+/// trailing versioned record (see [`crate::layout`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RequestedGasDropoff {
+    Unavailable,
+    V1(RequestedGasDropoffV1),
+}
+
+versioned_layout!(RequestedGasDropoff {
+    1 => V1(RequestedGasDropoffV1),
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct RequestedGasDropoffV1 {
+    pub lamports: u64,
+}
+
+impl RequestedGasDropoff {
+    pub fn from_transceiver_payload(transceiver_payload: &[u8]) -> Self {
+        RequestedGasDropoff::V1(RequestedGasDropoffV1 {
+            lamports: GasDropoff::from_transceiver_payload(transceiver_payload)
+                .map_or(0, |gas_dropoff| gas_dropoff.amount),
+        })
+    }
+
+    pub fn lamports(&self) -> u64 {
+        match self {
+            RequestedGasDropoff::Unavailable => 0,
+            RequestedGasDropoff::V1(RequestedGasDropoffV1 { lamports }) => *lamports,
+        }
+    }
+}
+
 // This is a hack to get around the fact that the IDL generator doesn't support
 // PhantomData. The generator uses the following functions, so we just mix them onto PhantomData.
 //
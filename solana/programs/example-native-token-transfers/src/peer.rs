@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{error::NTTError, layout::versioned_layout};
+
 #[account]
 #[derive(InitSpace)]
 /// A peer on another chain. Stored in a PDA seeded by the chain id.
@@ -7,8 +9,410 @@ pub struct NttManagerPeer {
     pub bump: u8,
     pub address: [u8; 32],
     pub token_decimals: u8,
+    /// NOTE: this field was appended after the initial release, so that
+    /// peers created before then can still be read. Fields appended after it
+    /// must be trailing versioned records too.
+    pub settings: PeerSettings,
+    /// NOTE: this field was appended after [`NttManagerPeer::settings`].
+    pub large_transfers: LargeTransferSettings,
+    /// NOTE: this field was appended after
+    /// [`NttManagerPeer::large_transfers`].
+    pub fee: PeerFeeSettings,
+    /// NOTE: this field was appended after [`NttManagerPeer::fee`], and must
+    /// remain the last one.
+    pub amount_limits: AmountLimitSettings,
 }
 
 impl NttManagerPeer {
     pub const SEED_PREFIX: &'static [u8] = b"peer";
+
+    /// Writes all the trailing records explicitly, with their current values.
+    ///
+    /// Unavailable records serialize to nothing, so a record written after an
+    /// unavailable one would be read in its place, and so would the stale
+    /// bytes left behind when a record shrinks. Every instruction that sets a
+    /// record calls this first, after growing the peer to
+    /// [`NttManagerPeer::INIT_SPACE`].
+    pub fn materialize_records(&mut self) {
+        self.settings = PeerSettings::V1(self.settings.current());
+        self.large_transfers = LargeTransferSettings::V1(self.large_transfers.current());
+        self.fee = PeerFeeSettings::V1(self.fee.current());
+        self.amount_limits = AmountLimitSettings::V1(self.amount_limits.current());
+    }
+
+    pub fn validate_recipient(&self, recipient_address: &[u8; 32]) -> Result<()> {
+        if self
+            .settings
+            .current()
+            .address_format
+            .is_valid(recipient_address)
+        {
+            Ok(())
+        } else {
+            Err(NTTError::InvalidRecipientAddressFormat.into())
+        }
+    }
+}
+
+/// The address format of a peer, set by [`crate::instructions::set_peer`].
+/// [`PeerSettings::Unavailable`] (for peers that haven't been configured since
+/// the settings were added) means the defaults.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PeerSettings {
+    Unavailable,
+    V1(PeerSettingsV1),
+}
+
+versioned_layout!(PeerSettings {
+    1 => V1(PeerSettingsV1),
+});
+
+impl PeerSettings {
+    pub fn current(&self) -> PeerSettingsV1 {
+        match self {
+            PeerSettings::Unavailable => PeerSettingsV1::default(),
+            PeerSettings::V1(settings) => settings.clone(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct PeerSettingsV1 {
+    /// The format of recipient addresses on the peer chain. Transfers to
+    /// recipients that don't match it are rejected.
+    pub address_format: AddressFormat,
+}
+
+/// The large transfer policy of a peer, set by
+/// [`crate::instructions::set_large_transfer_policy`].
+/// [`LargeTransferSettings::Unavailable`] means no policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LargeTransferSettings {
+    Unavailable,
+    V1(LargeTransferPolicy),
+}
+
+versioned_layout!(LargeTransferSettings {
+    1 => V1(LargeTransferPolicy),
+});
+
+impl LargeTransferSettings {
+    pub fn current(&self) -> LargeTransferPolicy {
+        match self {
+            LargeTransferSettings::Unavailable => LargeTransferPolicy::default(),
+            LargeTransferSettings::V1(policy) => policy.clone(),
+        }
+    }
+}
+
+/// The protocol fee override of a peer, set by
+/// [`crate::instructions::set_peer_fee`].
+/// [`PeerFeeSettings::Unavailable`] means the global fee applies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PeerFeeSettings {
+    Unavailable,
+    V1(PeerFeeSettingsV1),
+}
+
+versioned_layout!(PeerFeeSettings {
+    1 => V1(PeerFeeSettingsV1),
+});
+
+impl PeerFeeSettings {
+    pub fn current(&self) -> PeerFeeSettingsV1 {
+        match self {
+            PeerFeeSettings::Unavailable => PeerFeeSettingsV1::default(),
+            PeerFeeSettings::V1(settings) => settings.clone(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct PeerFeeSettingsV1 {
+    /// The protocol fee on transfers to the peer, in basis points, if it
+    /// overrides the global one (see [`crate::config::FeeSettingsV1`]).
+    pub fee_bps: Option<u16>,
+}
+
+/// The amount limits of a peer, set by
+/// [`crate::instructions::set_amount_limits`].
+/// [`AmountLimitSettings::Unavailable`] means no limits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AmountLimitSettings {
+    Unavailable,
+    V1(AmountLimits),
+}
+
+versioned_layout!(AmountLimitSettings {
+    1 => V1(AmountLimits),
+});
+
+impl AmountLimitSettings {
+    pub fn current(&self) -> AmountLimits {
+        match self {
+            AmountLimitSettings::Unavailable => AmountLimits::default(),
+            AmountLimitSettings::V1(limits) => limits.clone(),
+        }
+    }
+}
+
+/// Bounds on the amount of transfers to and from a peer, after the protocol
+/// fee is deducted and dust is removed (in the mint's decimals). Transfers
+/// outside of them are rejected, instead of being queued. Both are disabled
+/// by default.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct AmountLimits {
+    pub min_amount: Option<u64>,
+    pub max_amount: Option<u64>,
+    /// Whether inbound transfers are checked too. Since the tokens have
+    /// already left the peer chain by then, rejected inbound transfers can't
+    /// be redeemed until the limits are relaxed.
+    pub enforce_inbound: bool,
+}
+
+impl AmountLimits {
+    pub fn is_valid(&self) -> bool {
+        match (self.min_amount, self.max_amount) {
+            (Some(min_amount), Some(max_amount)) => min_amount <= max_amount,
+            _ => true,
+        }
+    }
+
+    pub fn check(&self, amount: u64) -> Result<()> {
+        if self
+            .min_amount
+            .is_some_and(|min_amount| amount < min_amount)
+        {
+            return Err(NTTError::TransferBelowMinimum.into());
+        }
+        if self
+            .max_amount
+            .is_some_and(|max_amount| amount > max_amount)
+        {
+            return Err(NTTError::TransferAboveMaximum.into());
+        }
+        Ok(())
+    }
+
+    pub fn check_inbound(&self, amount: u64) -> Result<()> {
+        if self.enforce_inbound {
+            self.check(amount)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Extra checks on large transfers to and from a peer, on top of the rate
+/// limits. Both are disabled by default.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct LargeTransferPolicy {
+    /// Outbound transfers of more than this amount (after removing dust) can
+    /// only be released [`LargeTransferPolicy::outbound_delay`] seconds after
+    /// they're sent, even if the rate limit has capacity for them.
+    pub outbound_threshold: Option<u64>,
+    pub outbound_delay: i64,
+    /// Inbound transfers of more than this amount can only be released once
+    /// [`LargeTransferPolicy::guardian`] has approved them (see
+    /// [`crate::instructions::approve_large_inbound`]).
+    pub inbound_threshold: Option<u64>,
+    pub guardian: Pubkey,
+}
+
+impl LargeTransferPolicy {
+    pub fn is_valid(&self) -> bool {
+        self.outbound_delay >= 0
+            && (self.inbound_threshold.is_none() || self.guardian != Pubkey::default())
+    }
+
+    /// Returns the earliest release timestamp of an outbound transfer of
+    /// `amount` sent at `now`, if it's subject to the security delay.
+    pub fn outbound_release_timestamp(&self, amount: u64, now: i64) -> Option<i64> {
+        match self.outbound_threshold {
+            Some(threshold) if amount > threshold => Some(now.saturating_add(self.outbound_delay)),
+            _ => None,
+        }
+    }
+
+    /// Whether an inbound transfer of `amount` needs the guardian's approval.
+    pub fn requires_guardian(&self, amount: u64) -> bool {
+        matches!(self.inbound_threshold, Some(threshold) if amount > threshold)
+    }
+}
+
+/// How addresses of a chain are encoded in the 32-byte universal address
+/// format.
+///
+/// Peers configured before the format was added read as
+/// [`AddressFormat::Bytes32`], which accepts any non-zero address.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum AddressFormat {
+    /// Native 32-byte addresses (e.g. Solana, Sui, Aptos). Any non-zero
+    /// address is accepted.
+    #[default]
+    Bytes32,
+    /// 20-byte addresses (e.g. EVM chains), left-padded with zeros. The first
+    /// 12 bytes must be zero, and the address itself must be non-zero.
+    Evm,
+}
+
+impl AddressFormat {
+    pub fn is_valid(&self, address: &[u8; 32]) -> bool {
+        if address.iter().all(|b| *b == 0) {
+            return false;
+        }
+        match self {
+            AddressFormat::Bytes32 => true,
+            AddressFormat::Evm => address[..12].iter().all(|b| *b == 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_format() {
+        let mut evm = [0u8; 32];
+        evm[31] = 1;
+
+        assert!(!AddressFormat::Bytes32.is_valid(&[0u8; 32]));
+        assert!(!AddressFormat::Evm.is_valid(&[0u8; 32]));
+
+        assert!(AddressFormat::Bytes32.is_valid(&[1u8; 32]));
+        assert!(!AddressFormat::Evm.is_valid(&[1u8; 32]));
+
+        assert!(AddressFormat::Bytes32.is_valid(&evm));
+        assert!(AddressFormat::Evm.is_valid(&evm));
+
+        // not left-padded
+        let mut evm = [0u8; 32];
+        evm[0] = 1;
+        assert!(!AddressFormat::Evm.is_valid(&evm));
+    }
+
+    #[test]
+    fn test_legacy_peer() {
+        // peers created before the settings were added end right before them
+        let data = (255u8, [1u8; 32], 8u8).try_to_vec().unwrap();
+        assert_eq!(
+            data.len(),
+            NttManagerPeer::INIT_SPACE
+                - PeerSettings::INIT_SPACE
+                - LargeTransferSettings::INIT_SPACE
+                - PeerFeeSettings::INIT_SPACE
+                - AmountLimitSettings::INIT_SPACE
+        );
+        let legacy = NttManagerPeer::deserialize(&mut &data[..]).unwrap();
+        assert_eq!(legacy.settings, PeerSettings::Unavailable);
+        // ...and accept any non-zero recipient
+        assert!(legacy.validate_recipient(&[1; 32]).is_ok());
+        assert!(legacy.validate_recipient(&[0; 32]).is_err());
+    }
+
+    #[test]
+    fn test_settings_records() {
+        // peers configured before the later records were added end with
+        // zeros, which read as unavailable
+        let mut data = (
+            255u8,
+            [1u8; 32],
+            8u8,
+            PeerSettings::V1(PeerSettingsV1 {
+                address_format: AddressFormat::Evm,
+            }),
+        )
+            .try_to_vec()
+            .unwrap();
+        data.resize(NttManagerPeer::INIT_SPACE, 0);
+        let mut peer = NttManagerPeer::deserialize(&mut &data[..]).unwrap();
+        assert_eq!(peer.settings.current().address_format, AddressFormat::Evm);
+        assert_eq!(peer.large_transfers, LargeTransferSettings::Unavailable);
+        assert_eq!(peer.fee, PeerFeeSettings::Unavailable);
+        assert_eq!(peer.amount_limits, AmountLimitSettings::Unavailable);
+
+        // a record can only be set once the ones before it are, so that it
+        // isn't read in their place
+        let limits = AmountLimits {
+            max_amount: Some(1000),
+            ..Default::default()
+        };
+        let mut unmaterialized = peer.clone();
+        unmaterialized.amount_limits = AmountLimitSettings::V1(limits.clone());
+        let data = unmaterialized.try_to_vec().unwrap();
+        assert_ne!(
+            NttManagerPeer::deserialize(&mut &data[..])
+                .ok()
+                .map(|peer| peer.amount_limits),
+            Some(AmountLimitSettings::V1(limits.clone()))
+        );
+
+        peer.materialize_records();
+        peer.amount_limits = AmountLimitSettings::V1(limits.clone());
+        let data = peer.try_to_vec().unwrap();
+        assert!(data.len() <= NttManagerPeer::INIT_SPACE);
+        let peer = NttManagerPeer::deserialize(&mut &data[..]).unwrap();
+        assert_eq!(
+            peer.large_transfers.current(),
+            LargeTransferPolicy::default()
+        );
+        assert_eq!(peer.fee.current().fee_bps, None);
+        assert_eq!(peer.amount_limits.current(), limits);
+    }
+
+    #[test]
+    fn test_amount_limits() {
+        let limits = AmountLimits {
+            min_amount: Some(100),
+            max_amount: Some(1000),
+            enforce_inbound: false,
+        };
+        assert!(limits.is_valid());
+        assert!(limits.check(100).is_ok());
+        assert!(limits.check(1000).is_ok());
+        assert!(limits.check(99).is_err());
+        assert!(limits.check(1001).is_err());
+        // inbound transfers are only checked on request
+        assert!(limits.check_inbound(1001).is_ok());
+        assert!(AmountLimits {
+            enforce_inbound: true,
+            ..limits.clone()
+        }
+        .check_inbound(1001)
+        .is_err());
+
+        assert!(!AmountLimits {
+            min_amount: Some(1001),
+            ..limits
+        }
+        .is_valid());
+        assert!(AmountLimits::default().check(0).is_ok());
+    }
+
+    #[test]
+    fn test_large_transfer_policy() {
+        let mut policy = LargeTransferPolicy::default();
+        assert!(policy.is_valid());
+        assert_eq!(policy.outbound_release_timestamp(u64::MAX, 10), None);
+        assert!(!policy.requires_guardian(u64::MAX));
+
+        policy.outbound_threshold = Some(1000);
+        policy.outbound_delay = 7200;
+        assert_eq!(policy.outbound_release_timestamp(1000, 10), None);
+        assert_eq!(policy.outbound_release_timestamp(1001, 10), Some(7210));
+
+        policy.inbound_threshold = Some(1000);
+        // the guardian must be set
+        assert!(!policy.is_valid());
+        policy.guardian = Pubkey::new_unique();
+        assert!(policy.is_valid());
+        assert!(!policy.requires_guardian(1000));
+        assert!(policy.requires_guardian(1001));
+
+        policy.outbound_delay = -1;
+        assert!(!policy.is_valid());
+    }
 }
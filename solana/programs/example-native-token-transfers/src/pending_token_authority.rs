@@ -1,13 +1,49 @@
 use anchor_lang::prelude::*;
 
+use crate::layout::versioned_layout;
+
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Debug, PartialEq, Eq)]
 pub struct PendingTokenAuthority {
     pub bump: u8,
     pub pending_authority: Pubkey,
     pub rent_payer: Pubkey,
+    /// See [`PendingTokenAuthorityExpiry`].
+    pub expiry: PendingTokenAuthorityExpiry,
 }
 
 impl PendingTokenAuthority {
     pub const SEED_PREFIX: &'static [u8] = b"pending_token_authority";
+
+    /// Pending token authorities created before the expiry was introduced
+    /// never expired, so they're treated as expired now. They can still be
+    /// reverted by the owner (or closed by anyone), refunding the rent payer.
+    pub fn is_expired(&self) -> bool {
+        match &self.expiry {
+            PendingTokenAuthorityExpiry::Unavailable => true,
+            PendingTokenAuthorityExpiry::V1(PendingTokenAuthorityExpiryV1 { expiry }) => {
+                crate::clock::current_timestamp() >= *expiry
+            }
+        }
+    }
+}
+
+/// Timestamp after which [`PendingTokenAuthority::pending_authority`] can no
+/// longer claim the token authority. See [`crate::PENDING_AUTHORITY_VALIDITY`].
+///
+/// NOTE: this was added after the initial release, so it's stored as a
+/// trailing versioned record (see [`crate::layout`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PendingTokenAuthorityExpiry {
+    Unavailable,
+    V1(PendingTokenAuthorityExpiryV1),
+}
+
+versioned_layout!(PendingTokenAuthorityExpiry {
+    1 => V1(PendingTokenAuthorityExpiryV1),
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct PendingTokenAuthorityExpiryV1 {
+    pub expiry: i64,
 }
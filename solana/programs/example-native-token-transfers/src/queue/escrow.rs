@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::error::NTTError;
+
+/// Tracks an inbound transfer whose recipient can't receive the tokens (e.g.
+/// because their token account is frozen). Seeded by the
+/// [`super::inbox::InboxItem`] it belongs to.
+///
+/// Once the inbox item is [`super::inbox::ReleaseStatus::Escrowed`] (after
+/// [`InboxEscrow::MAX_FAILED_ATTEMPTS`] failed attempts, or at the recipient's
+/// request), it can no longer be released to the recipient's associated token
+/// account. Instead, the tokens are released to `destination`, which is either
+/// chosen by the recipient (effective immediately), or by the owner among the
+/// recipient's token accounts (effective after
+/// [`InboxEscrow::RECOVERY_TIMELOCK`]). The recipient can override the owner's
+/// choice until then.
+#[account]
+#[derive(InitSpace, Debug, PartialEq, Eq)]
+pub struct InboxEscrow {
+    pub bump: u8,
+    pub inbox_item: Pubkey,
+    pub failed_attempts: u8,
+    pub last_failed_attempt: i64,
+    /// The token account the escrowed tokens are released to.
+    pub destination: Option<Pubkey>,
+    /// Unix timestamp from which the tokens can be released to `destination`.
+    pub destination_effective_at: i64,
+}
+
+impl InboxEscrow {
+    pub const SEED_PREFIX: &'static [u8] = b"inbox_escrow";
+
+    /// The number of failed release attempts after which the inbox item is
+    /// escrowed.
+    pub const MAX_FAILED_ATTEMPTS: u8 = 3;
+
+    /// The minimum time between two failed release attempts, so the attempts
+    /// can't all be made in a single transaction.
+    pub const FAILED_ATTEMPT_INTERVAL: i64 = 60 * 60;
+
+    /// How long a recovery destination set by the owner takes to become
+    /// effective.
+    pub const RECOVERY_TIMELOCK: i64 = 7 * 24 * 60 * 60;
+
+    /// Records a failed release attempt. Returns true once enough attempts have
+    /// been recorded for the inbox item to be escrowed.
+    pub fn record_failed_attempt(&mut self, now: i64) -> Result<bool> {
+        if self.failed_attempts > 0
+            && now < self.last_failed_attempt + Self::FAILED_ATTEMPT_INTERVAL
+        {
+            return Err(NTTError::FailedAttemptTooSoon.into());
+        }
+        self.failed_attempts = self.failed_attempts.saturating_add(1);
+        self.last_failed_attempt = now;
+        Ok(self.failed_attempts >= Self::MAX_FAILED_ATTEMPTS)
+    }
+
+    /// Sets the destination chosen by the recipient, effective immediately.
+    pub fn redirect(&mut self, destination: Pubkey, now: i64) {
+        self.destination = Some(destination);
+        self.destination_effective_at = now;
+    }
+
+    /// Sets the recovery destination chosen by the owner, effective after the
+    /// timelock.
+    pub fn recover(&mut self, destination: Pubkey, now: i64) {
+        self.destination = Some(destination);
+        self.destination_effective_at = now + Self::RECOVERY_TIMELOCK;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_failed_attempt() {
+        let mut escrow = InboxEscrow {
+            bump: 0,
+            inbox_item: Pubkey::default(),
+            failed_attempts: 0,
+            last_failed_attempt: 0,
+            destination: None,
+            destination_effective_at: 0,
+        };
+
+        let interval = InboxEscrow::FAILED_ATTEMPT_INTERVAL;
+        assert!(!escrow.record_failed_attempt(100).unwrap());
+        assert!(escrow.record_failed_attempt(100 + interval - 1).is_err());
+        assert!(!escrow.record_failed_attempt(100 + interval).unwrap());
+        assert!(escrow.record_failed_attempt(100 + 2 * interval).unwrap());
+    }
+}
@@ -1,10 +1,14 @@
 use std::ops::{Deref, DerefMut};
 
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp};
+use ntt_messages::chain_id::ChainId;
 
-use crate::{bitmap::Bitmap, clock::current_timestamp, error::NTTError};
+use crate::{bitmap::Bitmap, clock::current_timestamp, error::NTTError, layout::versioned_layout};
 
-use super::rate_limit::RateLimitState;
+use super::{
+    index::QueueMembership,
+    rate_limit::{BackflowSettings, RateLimitResult, RateLimitSettings, RateLimitState},
+};
 
 #[account]
 #[derive(InitSpace)]
@@ -18,17 +22,48 @@ pub struct InboxItem {
     pub recipient_address: Pubkey,
     pub votes: Bitmap,
     pub release_status: ReleaseStatus,
+    /// NOTE: this field was appended after the initial release, so that inbox
+    /// items created before then can still be read. Fields appended after it
+    /// must be trailing versioned records too.
+    pub gas_dropoff: InboxGasDropoff,
+    /// NOTE: this field was appended after [`InboxItem::gas_dropoff`]. It's
+    /// only ever set on items that have the previous records.
+    pub queue: QueueMembership,
+    /// NOTE: this field was appended after [`InboxItem::queue`]. Like it, it's
+    /// only ever set on items that have the previous records.
+    pub provenance: InboxProvenance,
+    /// NOTE: this field was appended after [`InboxItem::provenance`]. Like it,
+    /// it's only ever set on items that have the previous records.
+    pub tranches: InboxTranches,
+    /// NOTE: this field was appended after [`InboxItem::tranches`], and must
+    /// remain the last one. Like it, it's only ever set on items that have the
+    /// previous records.
+    pub token_account_recipient: InboxTokenAccountRecipient,
 }
 
 /// The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As
 /// such, this must be used as a state machine that moves forward in a linear manner. A state
 /// should never "move backward" to a previous state (e.g. should never move from `Released` to
 /// `ReleaseAfter`).
+///
+/// `Escrowed` is an alternative path from `ReleaseAfter` to `Released`, for
+/// transfers that can't be delivered to the recipient. See
+/// [`super::escrow::InboxEscrow`].
+///
+/// `AwaitingGuardian` comes before `ReleaseAfter` for large transfers that need
+/// the approval of the peer's guardian (see
+/// [`crate::peer::LargeTransferPolicy`]). It holds the release timestamp set
+/// by the rate limit.
+///
+/// NOTE: new variants must only ever be appended, as the discriminant is
+/// stored on-chain.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
 pub enum ReleaseStatus {
     NotApproved,
     ReleaseAfter(i64),
     Released,
+    Escrowed,
+    AwaitingGuardian(i64),
 }
 
 impl InboxItem {
@@ -38,7 +73,12 @@ impl InboxItem {
     ///
     /// * If the inbox item status is [`ReleaseStatus::ReleaseAfter`], this function returns true if the current timestamp
     /// is newer than the one stored in the release status. If the timestamp is in the future, returns false.
-    /// * If the inbox item status is [`ReleaseStatus::NotApproved`], this function returns false.
+    /// * If the inbox item status is [`ReleaseStatus::NotApproved`] or
+    /// [`ReleaseStatus::AwaitingGuardian`], this function returns false.
+    /// * If the transfer is released in tranches, this function returns false:
+    /// see [`InboxItem::try_release_tranche`] instead.
+    /// * If the inbox item status is [`ReleaseStatus::Escrowed`], this function returns
+    /// [`NTTError::TransferEscrowed`].
     ///
     /// # Errors
     ///
@@ -46,10 +86,18 @@ impl InboxItem {
     /// This is important to prevent a single transfer from being redeemed multiple times, which would
     /// result in minting arbitrary amounts of the token.
     pub fn try_release(&mut self) -> Result<bool> {
+        if self.tranches.is_enabled() {
+            // never released in full
+            return match self.release_status {
+                ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
+                _ => Ok(false),
+            };
+        }
+
         let now = current_timestamp();
 
         match self.release_status {
-            ReleaseStatus::NotApproved => Ok(false),
+            ReleaseStatus::NotApproved | ReleaseStatus::AwaitingGuardian(_) => Ok(false),
             ReleaseStatus::ReleaseAfter(release_timestamp) => {
                 if release_timestamp > now {
                     return Ok(false);
@@ -58,9 +106,82 @@ impl InboxItem {
                 Ok(true)
             }
             ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
+            ReleaseStatus::Escrowed => Err(NTTError::TransferEscrowed.into()),
         }
     }
 
+    /// Checks that the transfer could be released now, i.e. that it's approved
+    /// and its release timestamp has passed.
+    pub fn check_releasable(&self) -> Result<()> {
+        match self.release_status {
+            ReleaseStatus::NotApproved => Err(NTTError::TransferNotApproved.into()),
+            ReleaseStatus::ReleaseAfter(release_timestamp) => {
+                if release_timestamp > current_timestamp() {
+                    return Err(NTTError::CantReleaseYet.into());
+                }
+                Ok(())
+            }
+            ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
+            ReleaseStatus::Escrowed => Err(NTTError::TransferEscrowed.into()),
+            ReleaseStatus::AwaitingGuardian(_) => Err(NTTError::GuardianApprovalRequired.into()),
+        }
+    }
+
+    /// Releases the next tranche of a transfer that's released in tranches:
+    /// as much of the remaining amount as `capacity` allows. Once the whole
+    /// amount is released, the status becomes [`ReleaseStatus::Released`].
+    ///
+    /// Returns the amount of the tranche, which is 0 if the transfer isn't
+    /// releasable yet (or there's no capacity).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`InboxItem::try_release`].
+    pub fn try_release_tranche(&mut self, capacity: u64) -> Result<u64> {
+        if let Err(err) = self.check_releasable() {
+            return match self.release_status {
+                ReleaseStatus::Released | ReleaseStatus::Escrowed => Err(err),
+                _ => Ok(0),
+            };
+        }
+        let InboxTranches::V1(InboxTranchesV1 {
+            enabled: true,
+            released_amount,
+        }) = &mut self.tranches
+        else {
+            return Err(NTTError::TransferNotReleasedInTranches.into());
+        };
+
+        let tranche = (self.amount - *released_amount).min(capacity);
+        *released_amount += tranche;
+        if *released_amount == self.amount {
+            self.release_status = ReleaseStatus::Released;
+        }
+        Ok(tranche)
+    }
+
+    /// Moves a releasable transfer into escrow. From then on, it can only be
+    /// released with [`InboxItem::release_escrowed`].
+    ///
+    /// Transfers released in tranches can't be escrowed, as the escrow
+    /// releases the whole amount at once.
+    pub fn escrow(&mut self) -> Result<()> {
+        if self.tranches.is_enabled() {
+            return Err(NTTError::TransferReleasedInTranches.into());
+        }
+        self.check_releasable()?;
+        self.release_status = ReleaseStatus::Escrowed;
+        Ok(())
+    }
+
+    pub fn release_escrowed(&mut self) -> Result<()> {
+        if self.release_status != ReleaseStatus::Escrowed {
+            return Err(NTTError::TransferNotEscrowed.into());
+        }
+        self.release_status = ReleaseStatus::Released;
+        Ok(())
+    }
+
     pub fn release_after(&mut self, release_timestamp: i64) -> Result<()> {
         if self.release_status != ReleaseStatus::NotApproved {
             return Err(NTTError::TransferCannotBeRedeemed.into());
@@ -68,6 +189,255 @@ impl InboxItem {
         self.release_status = ReleaseStatus::ReleaseAfter(release_timestamp);
         Ok(())
     }
+
+    /// Like [`InboxItem::release_after`], but the transfer also needs the
+    /// guardian's approval (see [`InboxItem::approve_by_guardian`]).
+    pub fn await_guardian(&mut self, release_timestamp: i64) -> Result<()> {
+        if self.release_status != ReleaseStatus::NotApproved {
+            return Err(NTTError::TransferCannotBeRedeemed.into());
+        };
+        self.release_status = ReleaseStatus::AwaitingGuardian(release_timestamp);
+        Ok(())
+    }
+
+    pub fn approve_by_guardian(&mut self) -> Result<()> {
+        let ReleaseStatus::AwaitingGuardian(release_timestamp) = self.release_status else {
+            return Err(NTTError::NotAwaitingGuardian.into());
+        };
+        self.release_status = ReleaseStatus::ReleaseAfter(release_timestamp);
+        Ok(())
+    }
+}
+
+/// The native gas dropoff requested by the sender of an inbound transfer, and
+/// how much of it was delivered to the recipient on release (for
+/// reconciliation with the relayer's compensation on the source chain).
+///
+/// NOTE: this was added after the initial release, so it's stored as a
+/// trailing versioned record (see [`crate::layout`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InboxGasDropoff {
+    Unavailable,
+    V1(InboxGasDropoffV1),
+}
+
+versioned_layout!(InboxGasDropoff {
+    1 => V1(InboxGasDropoffV1),
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct InboxGasDropoffV1 {
+    pub requested: u64,
+    pub delivered: u64,
+}
+
+impl InboxGasDropoff {
+    pub fn new(requested: u64) -> Self {
+        InboxGasDropoff::V1(InboxGasDropoffV1 {
+            requested,
+            delivered: 0,
+        })
+    }
+
+    /// The lamports still to be dropped off to the recipient.
+    pub fn undelivered(&self) -> u64 {
+        match self {
+            InboxGasDropoff::Unavailable => 0,
+            InboxGasDropoff::V1(InboxGasDropoffV1 {
+                requested,
+                delivered,
+            }) => requested.saturating_sub(*delivered),
+        }
+    }
+
+    pub fn record_delivery(&mut self, lamports: u64) {
+        if let InboxGasDropoff::V1(InboxGasDropoffV1 { delivered, .. }) = self {
+            *delivered = delivered.saturating_add(lamports);
+        }
+    }
+}
+
+/// Where an inbound transfer came from, and when it was received and
+/// approved, so that it can be traced without the original message.
+///
+/// NOTE: this was added after the initial release, so it's stored as a
+/// trailing versioned record (see [`crate::layout`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InboxProvenance {
+    Unavailable,
+    V1(InboxProvenanceV1),
+}
+
+versioned_layout!(InboxProvenance {
+    1 => V1(InboxProvenanceV1),
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct InboxProvenanceV1 {
+    pub source_chain: ChainId,
+    pub source_ntt_manager: [u8; 32],
+    /// The sender on the source chain.
+    pub sender: [u8; 32],
+    /// The id of the NTT manager message (for transfers from Solana, the
+    /// address of the outbox item).
+    pub message_id: [u8; 32],
+    /// When the first transceiver voted for the transfer.
+    pub created_at: i64,
+    /// When the transfer reached the threshold.
+    pub approved_at: Option<i64>,
+}
+
+impl InboxProvenance {
+    pub fn new(
+        source_chain: ChainId,
+        source_ntt_manager: [u8; 32],
+        sender: [u8; 32],
+        message_id: [u8; 32],
+    ) -> Self {
+        InboxProvenance::V1(InboxProvenanceV1 {
+            source_chain,
+            source_ntt_manager,
+            sender,
+            message_id,
+            created_at: current_timestamp(),
+            approved_at: None,
+        })
+    }
+
+    pub fn source_chain(&self) -> Option<ChainId> {
+        match self {
+            InboxProvenance::Unavailable => None,
+            InboxProvenance::V1(provenance) => Some(provenance.source_chain),
+        }
+    }
+
+    pub fn record_approval(&mut self, timestamp: i64) {
+        if let InboxProvenance::V1(InboxProvenanceV1 { approved_at, .. }) = self {
+            *approved_at = Some(timestamp);
+        }
+    }
+}
+
+/// Progress of a transfer larger than the inbound rate limit, which is
+/// released in tranches as the rate limit allows (see
+/// [`InboxItem::try_release_tranche`]), rather than all at once.
+///
+/// NOTE: this was added after the initial release, so it's stored as a
+/// trailing versioned record (see [`crate::layout`]). It's set on all new
+/// inbox items, so that their accounts have room for it, and only
+/// [`InboxTranches::enable`]d when the transfer is approved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InboxTranches {
+    Unavailable,
+    V1(InboxTranchesV1),
+}
+
+versioned_layout!(InboxTranches {
+    1 => V1(InboxTranchesV1),
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct InboxTranchesV1 {
+    /// Whether the transfer is released in tranches.
+    pub enabled: bool,
+    /// The amount released so far.
+    pub released_amount: u64,
+}
+
+impl InboxTranches {
+    /// The tranches of a new item, which isn't released in tranches (yet).
+    pub fn new() -> Self {
+        InboxTranches::V1(InboxTranchesV1 {
+            enabled: false,
+            released_amount: 0,
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        matches!(
+            self,
+            InboxTranches::V1(InboxTranchesV1 { enabled: true, .. })
+        )
+    }
+
+    /// Marks the transfer as released in tranches. Returns false for inbox
+    /// items created before the tranches were added, which can't be.
+    pub fn enable(&mut self) -> bool {
+        match self {
+            InboxTranches::Unavailable => false,
+            InboxTranches::V1(tranches) => {
+                tranches.enabled = true;
+                true
+            }
+        }
+    }
+}
+
+impl Default for InboxTranches {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether the recipient address of an inbound transfer, which is then a
+/// token account of the mint rather than a wallet, accepted to receive the
+/// transfer itself (see
+/// [`crate::instructions::accept_inbound_to_token_account`]), and who owned
+/// it at the time.
+///
+/// NOTE: this was added after the initial release, so it's stored as a
+/// trailing versioned record (see [`crate::layout`]). It's set on all new
+/// inbox items, so that their accounts have room for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InboxTokenAccountRecipient {
+    Unavailable,
+    V1(InboxTokenAccountRecipientV1),
+}
+
+versioned_layout!(InboxTokenAccountRecipient {
+    1 => V1(InboxTokenAccountRecipientV1),
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct InboxTokenAccountRecipientV1 {
+    /// The owner of the token account, if it accepted the transfer.
+    pub owner: Option<Pubkey>,
+}
+
+impl InboxTokenAccountRecipient {
+    /// The record of a new item, which is released to the associated token
+    /// account of the recipient address.
+    pub fn new() -> Self {
+        InboxTokenAccountRecipient::V1(InboxTokenAccountRecipientV1 { owner: None })
+    }
+
+    /// The owner of the token account the transfer is released to, if the
+    /// recipient address is that token account.
+    pub fn owner(&self) -> Option<Pubkey> {
+        match self {
+            InboxTokenAccountRecipient::Unavailable => None,
+            InboxTokenAccountRecipient::V1(recipient) => recipient.owner,
+        }
+    }
+
+    /// Records that the recipient address is a token account owned by
+    /// `owner`. Returns false for inbox items created before this record was
+    /// added, which can't be.
+    pub fn accept(&mut self, owner: Pubkey) -> bool {
+        match self {
+            InboxTokenAccountRecipient::Unavailable => false,
+            InboxTokenAccountRecipient::V1(recipient) => {
+                recipient.owner = Some(owner);
+                true
+            }
+        }
+    }
+}
+
+impl Default for InboxTokenAccountRecipient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Inbound rate limit per chain.
@@ -77,10 +447,35 @@ impl InboxItem {
 pub struct InboxRateLimit {
     pub bump: u8,
     pub rate_limit: RateLimitState,
+    /// NOTE: this field was appended after the initial release, so that rate
+    /// limits created before then can still be read.
+    pub settings: RateLimitSettings,
+    /// NOTE: this field was appended after `settings`, and must remain the
+    /// last one. It's only ever set on accounts that have the settings.
+    pub backflow: BackflowSettings,
 }
 
 impl InboxRateLimit {
     pub const SEED_PREFIX: &'static [u8] = b"inbox_rate_limit";
+
+    /// [`RateLimitState::consume_or_delay`], in the mode set in the settings.
+    pub fn consume_or_delay(&mut self, amount: u64) -> RateLimitResult {
+        let strict = self.settings.current().strict;
+        self.rate_limit.consume_or_delay(amount, strict)
+    }
+
+    /// Refills the capacity with the backflow of a transfer of `amount` sent
+    /// to the chain.
+    pub fn refill_outbound_backflow(&mut self, now: UnixTimestamp, amount: u64) {
+        let backflow = self.backflow.current().outbound.apply(amount);
+        self.rate_limit.refill(now, backflow);
+    }
+
+    /// The backflow of a transfer of `amount` received from the chain, which
+    /// refills the outbound rate limit.
+    pub fn inbound_backflow(&self, amount: u64) -> u64 {
+        self.backflow.current().inbound.apply(amount)
+    }
 }
 
 impl Deref for InboxRateLimit {
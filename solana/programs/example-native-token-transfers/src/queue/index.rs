@@ -0,0 +1,259 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::NTTError, layout::versioned_layout};
+
+/// Maximum number of transfers listed in a [`QueueIndex`]. Once it's full,
+/// transfers queued in it are still counted in its totals, but not listed,
+/// so that filling the index can't block queued transfers.
+pub const QUEUE_INDEX_CAPACITY: u16 = 64;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct QueueEntry {
+    /// The [`super::outbox::OutboxItem`] or [`super::inbox::InboxItem`].
+    pub item: Pubkey,
+    /// The amount of the transfer (in the mint's decimals).
+    pub amount: u64,
+    pub release_timestamp: i64,
+}
+
+/// Transfers delayed by a rate limit, and not released yet.
+///
+/// There is one index for outbound transfers (seeded by
+/// [`QueueIndex::OUTBOUND_SEED_PREFIX`]), and one per chain for inbound
+/// transfers (seeded by [`QueueIndex::INBOUND_SEED_PREFIX`] and the chain id).
+/// Transfers are added when the rate limit delays them, and removed when
+/// they're released.
+///
+/// The instructions that queue transfers (transfer, redeem and
+/// receive-and-release) take the index as a required account, even before
+/// it's created (see [`QueueIndex::load`]). Once it is, every
+/// transfer they queue is tracked. Inbound releases must pass the index the
+/// inbox item is queued in. Outbound releases (which transceivers perform)
+/// take it as an optional account: transfers released without it
+/// stay in the index until they're removed with
+/// [`crate::instructions::dequeue_released_outbound`]. Transfers released
+/// before the inbound index was required are removed with
+/// [`crate::instructions::dequeue_released_inbound`].
+#[account]
+#[derive(InitSpace)]
+pub struct QueueIndex {
+    pub bump: u8,
+    /// Total amount of the queued transfers.
+    pub queued_amount: u64,
+    /// Number of queued transfers.
+    pub queued_count: u64,
+    /// The queued transfers, as long as there is room for them (see
+    /// [`QUEUE_INDEX_CAPACITY`]).
+    #[max_len(QUEUE_INDEX_CAPACITY)]
+    pub entries: Vec<QueueEntry>,
+}
+
+impl QueueIndex {
+    pub const OUTBOUND_SEED_PREFIX: &'static [u8] = b"outbound_queue";
+    pub const INBOUND_SEED_PREFIX: &'static [u8] = b"inbound_queue";
+
+    /// Loads the index passed as `info`, or returns `None` if it hasn't been
+    /// created yet.
+    ///
+    /// NOTE: the caller must check that `info` is the index's address. The
+    /// instructions that queue transfers take it as a required account, so
+    /// that once the index is created, transfers can't be queued without being
+    /// tracked.
+    pub fn load<'info>(info: &AccountInfo<'info>) -> Result<Option<Account<'info, QueueIndex>>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        Account::try_from(info).map(Some)
+    }
+
+    /// Adds a delayed transfer to the index passed as `info` (see
+    /// [`QueueIndex::load`]), and returns the membership to be recorded on its
+    /// item. Transfers aren't tracked until the index is created.
+    pub fn enqueue_into(
+        info: &AccountInfo,
+        item: Pubkey,
+        amount: u64,
+        release_timestamp: i64,
+    ) -> Result<QueueMembership> {
+        let Some(mut index) = Self::load(info)? else {
+            return Ok(QueueMembership::new());
+        };
+        let membership = index.enqueue(info.key(), item, amount, release_timestamp);
+        index.exit(&crate::ID)?;
+        Ok(membership)
+    }
+
+    /// Adds a delayed transfer to the queue, and returns the membership to be
+    /// recorded on its item. `index` is the address of this account. The
+    /// transfer is only listed if the index isn't full.
+    pub fn enqueue(
+        &mut self,
+        index: Pubkey,
+        item: Pubkey,
+        amount: u64,
+        release_timestamp: i64,
+    ) -> QueueMembership {
+        self.queued_amount = self.queued_amount.saturating_add(amount);
+        self.queued_count = self.queued_count.saturating_add(1);
+        if self.entries.len() < usize::from(QUEUE_INDEX_CAPACITY) {
+            self.entries.push(QueueEntry {
+                item,
+                amount,
+                release_timestamp,
+            });
+        }
+        QueueMembership::V1(QueueMembershipV1 {
+            queued: true,
+            index,
+            amount,
+        })
+    }
+
+    /// Removes a released transfer from the queue, if it's in it. `index` is
+    /// the address of this account, which must be the one the transfer was
+    /// queued in.
+    pub fn dequeue(
+        &mut self,
+        index: Pubkey,
+        item: Pubkey,
+        membership: &mut QueueMembership,
+    ) -> Result<()> {
+        let QueueMembership::V1(membership) = membership else {
+            return Ok(());
+        };
+        if !membership.queued {
+            return Ok(());
+        }
+        if membership.index != index {
+            return Err(NTTError::InvalidQueueIndex.into());
+        }
+        self.queued_amount = self.queued_amount.saturating_sub(membership.amount);
+        self.queued_count = self.queued_count.saturating_sub(1);
+        if let Some(position) = self.entries.iter().position(|entry| entry.item == item) {
+            self.entries.swap_remove(position);
+        }
+        membership.queued = false;
+        Ok(())
+    }
+}
+
+/// Whether a transfer is counted in its [`QueueIndex`].
+///
+/// NOTE: this was added after the initial release, so it's stored as a
+/// trailing versioned record (see [`crate::layout`]). Items created before
+/// then are never queued in an index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QueueMembership {
+    Unavailable,
+    V1(QueueMembershipV1),
+}
+
+versioned_layout!(QueueMembership {
+    1 => V1(QueueMembershipV1),
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct QueueMembershipV1 {
+    pub queued: bool,
+    /// The [`QueueIndex`] the transfer is (or was) queued in.
+    pub index: Pubkey,
+    /// The amount counted in the index's totals.
+    pub amount: u64,
+}
+
+impl QueueMembership {
+    /// The membership of a new item, which isn't queued (yet).
+    pub fn new() -> Self {
+        QueueMembership::V1(QueueMembershipV1 {
+            queued: false,
+            index: Pubkey::default(),
+            amount: 0,
+        })
+    }
+
+    pub fn is_queued(&self) -> bool {
+        matches!(
+            self,
+            QueueMembership::V1(QueueMembershipV1 { queued: true, .. })
+        )
+    }
+}
+
+impl Default for QueueMembership {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_totals() {
+        let key = Pubkey::new_unique();
+        let mut index = QueueIndex {
+            bump: 0,
+            queued_amount: 0,
+            queued_count: 0,
+            entries: vec![],
+        };
+
+        let items: Vec<Pubkey> = (0..QUEUE_INDEX_CAPACITY)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        let mut memberships: Vec<QueueMembership> = items
+            .iter()
+            .map(|item| index.enqueue(key, *item, 10, 100))
+            .collect();
+
+        assert_eq!(index.entries.len(), usize::from(QUEUE_INDEX_CAPACITY));
+        assert_eq!(index.queued_count, u64::from(QUEUE_INDEX_CAPACITY));
+        assert_eq!(index.queued_amount, 10 * u64::from(QUEUE_INDEX_CAPACITY));
+
+        // once full, transfers are still counted, but not listed
+        let unlisted = Pubkey::new_unique();
+        let mut unlisted_membership = index.enqueue(key, unlisted, 10, 100);
+        assert!(unlisted_membership.is_queued());
+        assert_eq!(index.entries.len(), usize::from(QUEUE_INDEX_CAPACITY));
+        assert_eq!(index.queued_count, u64::from(QUEUE_INDEX_CAPACITY) + 1);
+        index
+            .dequeue(key, unlisted, &mut unlisted_membership)
+            .unwrap();
+        assert_eq!(index.queued_count, u64::from(QUEUE_INDEX_CAPACITY));
+        assert_eq!(index.queued_amount, 10 * u64::from(QUEUE_INDEX_CAPACITY));
+
+        // only the index the transfer was queued in can dequeue it
+        assert!(index
+            .dequeue(Pubkey::new_unique(), items[0], &mut memberships[0])
+            .is_err());
+
+        index.dequeue(key, items[0], &mut memberships[0]).unwrap();
+        assert!(!memberships[0].is_queued());
+        assert_eq!(index.entries.len(), usize::from(QUEUE_INDEX_CAPACITY) - 1);
+        assert_eq!(index.queued_count, u64::from(QUEUE_INDEX_CAPACITY) - 1);
+
+        // which makes room for another one
+        let item = Pubkey::new_unique();
+        let mut membership = index.enqueue(key, item, 10, 100);
+        assert_eq!(index.entries.last().unwrap().item, item);
+        index.dequeue(key, item, &mut membership).unwrap();
+
+        // dequeuing twice is a no-op
+        index.dequeue(key, items[0], &mut memberships[0]).unwrap();
+        assert_eq!(index.queued_count, u64::from(QUEUE_INDEX_CAPACITY) - 1);
+        assert_eq!(
+            index.queued_amount,
+            10 * (u64::from(QUEUE_INDEX_CAPACITY) - 1)
+        );
+
+        // as is dequeuing items that were never queued
+        index
+            .dequeue(key, Pubkey::new_unique(), &mut QueueMembership::Unavailable)
+            .unwrap();
+        index
+            .dequeue(key, Pubkey::new_unique(), &mut QueueMembership::new())
+            .unwrap();
+        assert_eq!(index.queued_count, u64::from(QUEUE_INDEX_CAPACITY) - 1);
+    }
+}
@@ -1,3 +1,5 @@
+pub mod escrow;
 pub mod inbox;
+pub mod index;
 pub mod outbox;
 pub mod rate_limit;
@@ -3,24 +3,44 @@ use std::ops::{Deref, DerefMut};
 use anchor_lang::prelude::*;
 use ntt_messages::{chain_id::ChainId, trimmed_amount::TrimmedAmount};
 
-use crate::{bitmap::*, clock::current_timestamp, error::NTTError};
+use crate::{bitmap::*, clock::current_timestamp, error::NTTError, layout::versioned_layout};
 
-use super::rate_limit::RateLimitState;
+use super::{
+    index::QueueMembership,
+    rate_limit::{RateLimitResult, RateLimitSettings, RateLimitState},
+};
 
 #[account]
 #[derive(InitSpace, Debug, PartialEq, Eq)]
 // TODO: generalise this to arbitrary outbound messages (via a generic parameter in place of amount and recipient info)
 pub struct OutboxItem {
     pub amount: TrimmedAmount,
+    /// The account that authorised the transfer. This is the owner of the
+    /// source token account for [`crate::instructions::transfer_burn`] and
+    /// [`crate::instructions::transfer_lock`], and the signing authority (which
+    /// may be a program PDA) for the `transfer_*_direct` and
+    /// `transfer_*_sequenced` variants.
     pub sender: Pubkey,
     pub recipient_chain: ChainId,
     pub recipient_ntt_manager: [u8; 32],
     pub recipient_address: [u8; 32],
     pub release_timestamp: i64,
     pub released: Bitmap,
+    /// NOTE: this field was appended after the initial release, so that
+    /// outbox items created before then can still be read. Fields appended
+    /// after it must be trailing versioned records too.
+    pub queue: QueueMembership,
+    /// NOTE: this field was appended after [`OutboxItem::queue`], and must
+    /// remain the last one.
+    pub provenance: OutboxProvenance,
 }
 
 impl OutboxItem {
+    /// Outbox items created by the `transfer_*_sequenced` variants are PDAs
+    /// seeded by this prefix, the sender, and the sender's sequence number
+    /// (see [`SenderSequence`]). Other outbox items are keypair accounts.
+    pub const SEED_PREFIX: &'static [u8] = b"outbox_item";
+
     /// Attempt to release the transfer.
     /// Returns true if the transfer was released, false if it was not yet time to release it.
     pub fn try_release(&mut self, transceiver_index: u8) -> Result<bool> {
@@ -40,16 +60,103 @@ impl OutboxItem {
     }
 }
 
+/// Details of an outbound transfer that aren't part of the message, so that
+/// it can be traced and reconciled.
+///
+/// NOTE: this was added after the initial release, so it's stored as a
+/// trailing versioned record (see [`crate::layout`]). Outbox items are written
+/// once, in full, so new details don't need a new version of this record:
+/// they go in their own trailing record after it instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OutboxProvenance {
+    Unavailable,
+    V1(OutboxProvenanceV1),
+}
+
+versioned_layout!(OutboxProvenance {
+    1 => V1(OutboxProvenanceV1),
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct OutboxProvenanceV1 {
+    /// The amount requested by the sender, before the protocol fee was
+    /// deducted and dust was removed (in the mint's decimals).
+    pub requested_amount: u64,
+    pub created_at: i64,
+    /// The account that paid for the outbox item.
+    pub payer: Pubkey,
+    /// Whether the transfer drew from the sender's [`RateLimitExemption`]
+    /// instead of the outbound rate limit.
+    pub rate_limit_exempt: bool,
+    /// The protocol fee paid to the fee recipient (in the mint's decimals).
+    pub fee: u64,
+}
+
+impl OutboxProvenance {
+    pub fn new(requested_amount: u64, payer: Pubkey, rate_limit_exempt: bool, fee: u64) -> Self {
+        OutboxProvenance::V1(OutboxProvenanceV1 {
+            requested_amount,
+            created_at: current_timestamp(),
+            payer,
+            rate_limit_exempt,
+            fee,
+        })
+    }
+}
+
+/// The next sequence number of a sender, used to derive the address of its next
+/// PDA outbox item. Since the message id is the outbox item's address, this
+/// makes message ids predictable, and lets clients enumerate a sender's
+/// transfers.
+#[account]
+#[derive(InitSpace, Debug, PartialEq, Eq)]
+pub struct SenderSequence {
+    pub bump: u8,
+    pub next_sequence: u64,
+}
+
+impl SenderSequence {
+    pub const SEED_PREFIX: &'static [u8] = b"sender_sequence";
+}
+
 #[account]
 #[derive(InitSpace, PartialEq, Eq, Debug)]
 pub struct OutboxRateLimit {
     pub rate_limit: RateLimitState,
+    /// NOTE: this field was appended after the initial release, and must
+    /// remain the last one, so that rate limits created before then can still
+    /// be read.
+    pub settings: RateLimitSettings,
+}
+
+/// An owner-managed exemption of a sender from the outbound rate limit.
+/// Transfers by the sender that pass this account draw from its dedicated
+/// rate limit instead, so that large institutional transfers don't queue up
+/// behind (or crowd out) everyone else's.
+/// SECURITY: must check the sender (since there are multiple PDAs, namely one
+/// for each sender.)
+#[account]
+#[derive(InitSpace, Debug, PartialEq, Eq)]
+pub struct RateLimitExemption {
+    pub bump: u8,
+    pub sender: Pubkey,
+    pub rate_limit: RateLimitState,
+}
+
+impl RateLimitExemption {
+    pub const SEED_PREFIX: &'static [u8] = b"rate_limit_exemption";
 }
 
 /// Global rate limit for all outbound transfers to all chains.
 /// NOTE: only one of this account can exist, so we don't need to check the PDA.
 impl OutboxRateLimit {
     pub const SEED_PREFIX: &'static [u8] = b"outbox_rate_limit";
+
+    /// [`RateLimitState::consume_or_delay`], in the mode set in the settings.
+    pub fn consume_or_delay(&mut self, amount: u64) -> RateLimitResult {
+        let strict = self.settings.current().strict;
+        self.rate_limit.consume_or_delay(amount, strict)
+    }
 }
 
 impl Deref for OutboxRateLimit {
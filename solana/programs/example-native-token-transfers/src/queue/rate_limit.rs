@@ -1,6 +1,6 @@
 use anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp};
 
-use crate::clock::current_timestamp;
+use crate::{clock::current_timestamp, layout::versioned_layout};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq, Debug)]
 pub struct RateLimitState {
@@ -13,10 +13,117 @@ pub struct RateLimitState {
     pub capacity_at_last_tx: u64,
     /// The timestamp of the last transaction that counted towards the current
     /// capacity. Transactions that exceeded the capacity do not count, they are
-    /// just delayed.
+    /// just delayed (unless the rate limit is strict, see
+    /// [`RateLimitSettingsV1::strict`]).
+    ///
+    /// In strict mode, this can be in the future: the capacity up to then is
+    /// reserved by queued transfers.
     pub last_tx_timestamp: i64,
 }
 
+/// Optional settings of a rate limit account, set by
+/// [`crate::instructions::set_outbound_strict_mode`] and
+/// [`crate::instructions::set_inbound_strict_mode`].
+/// [`RateLimitSettings::Unavailable`] (for accounts that haven't been
+/// configured since the settings were added) means the defaults.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RateLimitSettings {
+    Unavailable,
+    V1(RateLimitSettingsV1),
+}
+
+versioned_layout!(RateLimitSettings {
+    1 => V1(RateLimitSettingsV1),
+});
+
+impl RateLimitSettings {
+    pub fn current(&self) -> RateLimitSettingsV1 {
+        match self {
+            RateLimitSettings::Unavailable => RateLimitSettingsV1::default(),
+            RateLimitSettings::V1(settings) => settings.clone(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct RateLimitSettingsV1 {
+    /// Whether queued transfers reserve capacity. By default, a transfer that
+    /// exceeds the capacity is queued without consuming any, so a backlog of
+    /// queued transfers can be released all at once, on top of the transfers
+    /// that keep coming in. In strict mode, a queued transfer drains the
+    /// capacity it needs as of its release timestamp, and later transfers
+    /// queue up behind it.
+    pub strict: bool,
+}
+
+/// How much of a transfer flows back into the rate limit of the opposite
+/// direction ("backflow"): sending a transfer to a chain refills that chain's
+/// inbound rate limit, and receiving one refills the outbound rate limit.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum Backflow {
+    Disabled,
+    #[default]
+    Full,
+    /// A percentage of the amount, at most 100.
+    Percentage(u8),
+}
+
+impl Backflow {
+    pub fn is_valid(&self) -> bool {
+        !matches!(self, Backflow::Percentage(percentage) if *percentage > 100)
+    }
+
+    /// The amount that flows back for a transfer of `amount`.
+    // SECURITY: Integer division is OK here. The backflow is rounded down.
+    // SECURITY: Truncation is allowed here. The result is capped by `amount`,
+    // which is a u64.
+    #[allow(clippy::integer_division)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn apply(&self, amount: u64) -> u64 {
+        match self {
+            Backflow::Disabled => 0,
+            Backflow::Full => amount,
+            Backflow::Percentage(percentage) => {
+                let backflow = u128::from(amount) * u128::from(*percentage) / 100;
+                backflow.min(u128::from(amount)) as u64
+            }
+        }
+    }
+}
+
+/// The backflow settings of a peer, stored on its inbound rate limit account,
+/// and set by [`crate::instructions::set_backflow`].
+/// [`BackflowSettings::Unavailable`] means full backflow in both directions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BackflowSettings {
+    Unavailable,
+    V1(BackflowSettingsV1),
+}
+
+versioned_layout!(BackflowSettings {
+    1 => V1(BackflowSettingsV1),
+});
+
+impl BackflowSettings {
+    pub fn current(&self) -> BackflowSettingsV1 {
+        match self {
+            BackflowSettings::Unavailable => BackflowSettingsV1::default(),
+            BackflowSettings::V1(settings) => settings.clone(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct BackflowSettingsV1 {
+    /// Backflow of transfers sent to the peer, into its inbound rate limit.
+    pub outbound: Backflow,
+    /// Backflow of transfers received from the peer, into the outbound rate
+    /// limit.
+    pub inbound: Backflow,
+}
+
 /// The result of attempting to consume from a rate limiter.
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum RateLimitResult {
@@ -56,7 +163,10 @@ impl RateLimitState {
     #[allow(clippy::cast_sign_loss)]
     #[allow(clippy::cast_possible_truncation)]
     pub fn capacity_at(&self, now: UnixTimestamp) -> u64 {
-        assert!(self.last_tx_timestamp <= now);
+        // reserved by queued transfers (strict mode only)
+        if now < self.last_tx_timestamp {
+            return 0;
+        }
 
         let limit = u128::from(self.limit);
 
@@ -86,33 +196,81 @@ impl RateLimitState {
         calculated_capacity.min(limit) as u64
     }
 
+    /// Returns how long it takes for the capacity to grow by `amount`
+    /// (ignoring the limit, which the capacity never exceeds).
+    /// This is the inverse of the computation in [`Self::capacity_at`], rounded
+    /// up so that the capacity computed there (which rounds down) has grown by
+    /// at least `amount` after the returned duration.
+    fn time_to_refill(&self, amount: u64) -> i64 {
+        if self.limit == 0 {
+            // never refills
+            return Self::RATE_LIMIT_DURATION;
+        }
+        let time_needed = (u128::from(amount) * Self::RATE_LIMIT_DURATION as u128)
+            .div_ceil(u128::from(self.limit));
+        i64::try_from(time_needed).unwrap_or(i64::MAX)
+    }
+
     /// Computes the timestamp at which the given amount can be consumed.
     /// If it fits within the current capacity, the current timestamp is
     /// returned, and the remaining capacity is reduced.
-    /// Otherwise, the timestamp at which the capacity will be available is
-    /// returned.
-    pub fn consume_or_delay(&mut self, amount: u64) -> RateLimitResult {
+    /// Otherwise, the timestamp at which the missing capacity will have
+    /// refilled is returned. Amounts exceeding the limit are released once
+    /// the capacity has refilled by the full amount.
+    ///
+    /// If `strict` is set, a delayed amount is consumed too, as of the
+    /// returned timestamp: the capacity is reserved until then (see
+    /// [`RateLimitSettingsV1::strict`]).
+    // SECURITY: Integer division is OK here. The leftover capacity is rounded
+    // down, as in `capacity_at`.
+    // SECURITY: Truncation is allowed here. The leftover capacity is capped
+    // by the limit, which is a u64.
+    #[allow(clippy::integer_division)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn consume_or_delay(&mut self, amount: u64, strict: bool) -> RateLimitResult {
         let now = current_timestamp();
         let capacity = self.capacity_at(now);
         if capacity >= amount {
             self.capacity_at_last_tx = capacity - amount;
             self.last_tx_timestamp = now;
-            RateLimitResult::Consumed(now)
-        } else {
-            RateLimitResult::Delayed(now + Self::RATE_LIMIT_DURATION)
+            return RateLimitResult::Consumed(now);
         }
+
+        // capacity reserved by earlier queued transfers is only available
+        // after they're released
+        let start = now.max(self.last_tx_timestamp);
+        let capacity = self.capacity_at(start);
+
+        let missing = amount - capacity;
+        let time_needed = self.time_to_refill(missing);
+        let release_timestamp = start.saturating_add(time_needed);
+        if !strict {
+            return RateLimitResult::Delayed(release_timestamp);
+        }
+
+        // whatever the rounding added on top of the missing amount is left
+        let refilled = u128::from(time_needed.unsigned_abs()) * u128::from(self.limit)
+            / (Self::RATE_LIMIT_DURATION as u128);
+        let leftover = refilled.saturating_sub(u128::from(missing));
+        self.capacity_at_last_tx = leftover.min(u128::from(self.limit)) as u64;
+        self.last_tx_timestamp = release_timestamp;
+        RateLimitResult::Delayed(release_timestamp)
     }
 
     /// Refills the capacity by the given amount.
     /// This is used to replenish the capacity via backflows.
+    /// Capacity reserved by queued transfers (in strict mode) stays reserved:
+    /// the refill becomes available once they're released.
     pub fn refill(&mut self, now: UnixTimestamp, amount: u64) {
-        self.capacity_at_last_tx = self.capacity_at(now).saturating_add(amount).min(self.limit);
-        self.last_tx_timestamp = now;
+        let at = now.max(self.last_tx_timestamp);
+        self.capacity_at_last_tx = self.capacity_at(at).saturating_add(amount).min(self.limit);
+        self.last_tx_timestamp = at;
     }
 
     pub fn set_limit(&mut self, limit: u64) {
         let old_limit = self.limit;
-        let now = current_timestamp();
+        // don't release capacity reserved by queued transfers (strict mode)
+        let now = current_timestamp().max(self.last_tx_timestamp);
         let current_capacity = self.capacity_at(now);
 
         self.limit = limit;
@@ -149,7 +307,7 @@ mod tests {
         };
 
         // consume 30k. should be immediate
-        let immediately = rate_limit_state.consume_or_delay(30_000);
+        let immediately = rate_limit_state.consume_or_delay(30_000, false);
 
         assert_eq!(immediately, RateLimitResult::Consumed(now));
         assert_eq!(rate_limit_state.capacity(), 70_000);
@@ -162,11 +320,19 @@ mod tests {
 
         assert_eq!(rate_limit_state.capacity(), 70_000 + 25_000);
 
-        // now consume 150k. should be delayed
-        let tomorrow = rate_limit_state.consume_or_delay(150_000);
+        // now consume 150k. should be delayed until the missing 55k have
+        // refilled
+        let delayed = rate_limit_state.consume_or_delay(150_000, false);
         assert_eq!(
-            tomorrow,
-            RateLimitResult::Delayed(now + RateLimitState::RATE_LIMIT_DURATION)
+            delayed,
+            RateLimitResult::Delayed(now + RateLimitState::RATE_LIMIT_DURATION * 55 / 100)
+        );
+
+        // 99k only needs the missing 4k
+        let delayed = rate_limit_state.consume_or_delay(99_000, false);
+        assert_eq!(
+            delayed,
+            RateLimitResult::Delayed(now + RateLimitState::RATE_LIMIT_DURATION * 4 / 100)
         );
 
         // the limit is not changed, since the tx was delayed
@@ -190,5 +356,87 @@ mod tests {
         // now refill 50k
         rate_limit_state.refill(now, 50_000);
         assert_eq!(rate_limit_state.capacity(), 100_000);
+
+        // NOTE: the rest is in the same test, since the clock is shared
+        // between tests
+        let now = current_timestamp();
+        let day = RateLimitState::RATE_LIMIT_DURATION;
+
+        // release timestamps are rounded up, so that the capacity is
+        // sufficient by then
+        let mut rate_limit_state = RateLimitState {
+            limit: 7,
+            capacity_at_last_tx: 0,
+            last_tx_timestamp: now,
+        };
+
+        // 1 unit refills every 86400 / 7 = 12342.86 seconds
+        let RateLimitResult::Delayed(release_timestamp) =
+            rate_limit_state.consume_or_delay(1, false)
+        else {
+            panic!("expected delay");
+        };
+        assert_eq!(release_timestamp, now + 12_343);
+        assert_eq!(rate_limit_state.capacity_at(release_timestamp - 1), 0);
+        assert_eq!(rate_limit_state.capacity_at(release_timestamp), 1);
+
+        // strict mode
+        let mut rate_limit_state = RateLimitState {
+            limit: 100_000,
+            capacity_at_last_tx: 50_000,
+            last_tx_timestamp: now,
+        };
+
+        // 60k needs the missing 10k, and reserves the whole capacity until then
+        let first = rate_limit_state.consume_or_delay(60_000, true);
+        assert_eq!(first, RateLimitResult::Delayed(now + day / 10));
+        assert_eq!(rate_limit_state.capacity(), 0);
+        assert_eq!(rate_limit_state.capacity_at(now + day / 10), 0);
+
+        // the next transfer queues up behind it, even if it would fit in
+        // the capacity before the first one is released
+        let second = rate_limit_state.consume_or_delay(20_000, true);
+        assert_eq!(second, RateLimitResult::Delayed(now + day / 10 + day / 5));
+
+        // backflows become available after the queued transfers
+        rate_limit_state.refill(now, 10_000);
+        assert_eq!(rate_limit_state.capacity(), 0);
+        assert_eq!(
+            rate_limit_state.capacity_at(now + day / 10 + day / 5),
+            10_000
+        );
+
+        // changing the limit doesn't release the reserved capacity
+        rate_limit_state.set_limit(200_000);
+        assert_eq!(rate_limit_state.capacity(), 0);
+        assert_eq!(
+            rate_limit_state.capacity_at(now + day / 10 + day / 5),
+            110_000
+        );
+
+        // amounts over the limit are released once the capacity has refilled
+        // by the full amount
+        let mut rate_limit_state = RateLimitState::new(100_000);
+        rate_limit_state.last_tx_timestamp = now;
+        let large = rate_limit_state.consume_or_delay(250_000, true);
+        assert_eq!(large, RateLimitResult::Delayed(now + day * 3 / 2));
+        assert_eq!(rate_limit_state.capacity_at(now + day * 3 / 2), 0);
+        assert_eq!(rate_limit_state.capacity_at(now + day * 2), 50_000);
+    }
+
+    #[test]
+    fn test_backflow() {
+        assert_eq!(Backflow::Disabled.apply(1_000), 0);
+        assert_eq!(Backflow::Full.apply(1_000), 1_000);
+        assert_eq!(Backflow::Percentage(25).apply(1_000), 250);
+        assert_eq!(Backflow::Percentage(33).apply(1_001), 330);
+        assert_eq!(Backflow::Percentage(100).apply(u64::MAX), u64::MAX);
+
+        assert!(Backflow::Percentage(100).is_valid());
+        assert!(!Backflow::Percentage(101).is_valid());
+        assert_eq!(
+            BackflowSettings::Unavailable.current().outbound,
+            Backflow::Full
+        );
     }
 }
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 use ntt_messages::chain_id::ChainId;
 
-use crate::{config::Config, transceivers::accounts::peer::TransceiverPeer};
+use crate::{
+    audit_log::{AuditAction, AuditLog},
+    config::Config,
+    transceivers::accounts::peer::TransceiverPeer,
+};
 
 #[derive(Accounts)]
 #[instruction(args: SetTransceiverPeerArgs)]
@@ -26,6 +30,15 @@ pub struct SetTransceiverPeer<'info> {
     pub peer: Account<'info, TransceiverPeer>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the audit log (see
+    /// [`AuditLog::record_into`])
+    pub audit_log: UncheckedAccount<'info>,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -43,5 +56,15 @@ pub fn set_transceiver_peer(
         address: args.address,
     });
 
+    // NOTE: the peer account is created here (and can't be overwritten), so
+    // there is no previous state to record
+    AuditLog::record_into(
+        &ctx.accounts.audit_log,
+        AuditAction::SetWormholePeer,
+        ctx.accounts.owner.key(),
+        ctx.accounts.peer.key(),
+        AuditLog::digest(&[0u8; 32])?,
+        AuditLog::digest(&args.address)?,
+    )?;
     Ok(())
 }
@@ -0,0 +1,339 @@
+//! Completes an inbound transfer in a single instruction.
+//!
+//! The general flow takes three instructions (and usually several
+//! transactions): [`super::receive_message`] verifies the VAA into a
+//! [`crate::messages::ValidatedTransceiverMessage`], [`crate::instructions::redeem`]
+//! records the vote and consumes the rate limit, and
+//! [`crate::instructions::release_inbound_mint`] or
+//! [`crate::instructions::release_inbound_unlock`] deliver the tokens.
+//!
+//! When the threshold is 1, the vote of this program's Wormhole transceiver is
+//! all it takes, so the instructions below do all of it at once, reading the
+//! message straight from the VAA without the intermediate account.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface};
+use ntt_messages::{
+    chain_id::ChainId, mode::Mode, ntt::NativeTokenTransfer, transceiver::TransceiverMessage,
+    transceivers::wormhole::WormholeTransceiver,
+};
+use spl_token_2022::onchain;
+use wormhole_anchor_sdk::wormhole::PostedVaa;
+
+use crate::{
+    config::*,
+    error::NTTError,
+    instructions::{
+        approve_inbox_item, mint_to_custody_from_multisig_token_authority,
+        mint_to_custody_from_token_authority, new_inbox_item, prepare_recipient,
+        ReleaseInboundArgs,
+    },
+    messages::RequestedGasDropoff,
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        index::QueueIndex,
+        outbox::OutboxRateLimit,
+    },
+    registered_transceiver::*,
+    spl_multisig::SplMultisig,
+    transceivers::accounts::peer::TransceiverPeer,
+    transfer::Payload,
+};
+
+#[derive(Accounts)]
+pub struct ReceiveAndRelease<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = config.threshold == 1 @ NTTError::ThresholdTooHigh,
+    )]
+    pub config: NotPausedConfig<'info>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, vaa.emitter_chain().to_be_bytes().as_ref()],
+        constraint = peer.address == vaa.message().source_ntt_manager @ NTTError::InvalidNttManagerPeer,
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [TransceiverPeer::SEED_PREFIX, vaa.emitter_chain().to_be_bytes().as_ref()],
+        constraint = transceiver_peer.address == *vaa.emitter_address() @ NTTError::InvalidTransceiverPeer,
+        bump = transceiver_peer.bump,
+    )]
+    pub transceiver_peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        // check that the message is targeted to this chain
+        constraint = vaa.message().ntt_manager_payload.payload.to_chain == config.chain_id @ NTTError::InvalidChainId,
+        // check that we're the intended recipient
+        constraint = vaa.message().recipient_ntt_manager == crate::ID.to_bytes() @ NTTError::InvalidRecipientNttManager,
+        // NOTE: we don't replay protect VAAs. Instead, the inbox item can only
+        // be created once.
+    )]
+    pub vaa: Account<
+        'info,
+        PostedVaa<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>>,
+    >,
+
+    #[account(
+        constraint = transceiver.transceiver_address == crate::ID,
+        constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
+    )]
+    pub transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + InboxItem::INIT_SPACE,
+        seeds = [
+            InboxItem::SEED_PREFIX,
+            vaa.message().ntt_manager_payload.keccak256(ChainId { id: vaa.emitter_chain() }).as_ref(),
+        ],
+        bump,
+    )]
+    /// NOTE: same address as in [`crate::instructions::Redeem`], so a transfer
+    /// can't be redeemed through both.
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, vaa.emitter_chain().to_be_bytes().as_ref()],
+        bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(mut)]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(mut)]
+    /// CHECK: validated in [`crate::instructions::prepare_recipient`]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        address = Pubkey::new_from_array(vaa.message().ntt_manager_payload.payload.to),
+    )]
+    /// CHECK: the address constraint ensures this is the recipient address of
+    /// the transfer
+    pub recipient_address: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        mut,
+        address = config.custody
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [QueueIndex::INBOUND_SEED_PREFIX, vaa.emitter_chain().to_be_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures this is the inbound queue index of
+    /// the source chain, which is loaded with [`QueueIndex::load`] if it has
+    /// been created
+    pub inbound_queue: UncheckedAccount<'info>,
+}
+
+impl<'info> ReceiveAndRelease<'info> {
+    /// Creates the inbox item with this transceiver's vote, which meets the
+    /// threshold, and releases it if the rate limit allows. Returns whether the
+    /// tokens should be delivered now.
+    fn redeem(&mut self, inbox_item_bump: u8, revert_when_not_ready: bool) -> Result<bool> {
+        let message = self.vaa.message();
+        let gas_dropoff =
+            RequestedGasDropoff::from_transceiver_payload(&message.transceiver_payload);
+        self.inbox_item.set_inner(new_inbox_item(
+            inbox_item_bump,
+            ChainId {
+                id: self.vaa.emitter_chain(),
+            },
+            message.source_ntt_manager,
+            &message.ntt_manager_payload,
+            self.mint.decimals,
+            gas_dropoff.lamports(),
+        )?);
+        self.inbox_item.votes.set(self.transceiver.id, true)?;
+
+        approve_inbox_item(
+            self.inbox_item.key(),
+            &mut self.inbox_item,
+            &self.peer,
+            &mut self.inbox_rate_limit,
+            &mut self.outbox_rate_limit,
+            &self.inbound_queue,
+        )?;
+
+        if !self.inbox_item.try_release()? {
+            if revert_when_not_ready {
+                return Err(match self.inbox_item.release_status {
+                    ReleaseStatus::AwaitingGuardian(_) => NTTError::GuardianApprovalRequired,
+                    _ => NTTError::CantReleaseYet,
+                }
+                .into());
+            }
+            // the transfer is queued, and can be released later with
+            // [`crate::instructions::release_inbound_mint`] or
+            // [`crate::instructions::release_inbound_unlock`]
+            return Ok(false);
+        }
+
+        prepare_recipient(
+            &self.payer,
+            &self.recipient,
+            &self.recipient_address,
+            self.inbox_item.token_account_recipient.owner(),
+            &self.mint,
+            &self.token_program,
+            &self.associated_token_program,
+            &self.system_program,
+        )?;
+        Ok(true)
+    }
+}
+
+// Burn/mint
+
+#[derive(Accounts)]
+pub struct ReceiveAndReleaseMint<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+    )]
+    common: ReceiveAndRelease<'info>,
+
+    #[account(
+        constraint = multisig_token_authority.m == 1
+            && multisig_token_authority.signers.contains(&common.token_authority.key())
+            @ NTTError::InvalidMultisig,
+    )]
+    pub multisig_token_authority: Option<InterfaceAccount<'info, SplMultisig>>,
+}
+
+/// Receives an inbound transfer from a Wormhole VAA, redeems it and mints the
+/// tokens to the recipient, in one go. Only available when the threshold is 1.
+/// If the inbound rate limit delays the transfer, it's queued instead (or the
+/// transaction reverts, when `revert_when_not_ready` is true), and can be
+/// released later with [`crate::instructions::release_inbound_mint`].
+/// SECURITY: Signer checks are disabled here because anyone is permitted to
+/// relay a VAA.
+pub fn receive_and_release_wormhole_mint<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReceiveAndReleaseMint<'info>>,
+    args: ReleaseInboundArgs,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .common
+        .redeem(ctx.bumps.common.inbox_item, args.revert_when_not_ready)?
+    {
+        return Ok(());
+    }
+
+    let common = &ctx.accounts.common;
+    let amount = common.inbox_item.amount;
+    let token_authority_sig: &[&[&[u8]]] = &[&[
+        crate::TOKEN_AUTHORITY_SEED,
+        &[ctx.bumps.common.token_authority],
+    ]];
+
+    // mint to custody first, then transfer, as in
+    // [`crate::instructions::release_inbound_mint`]
+    match &ctx.accounts.multisig_token_authority {
+        Some(multisig_token_authority) => mint_to_custody_from_multisig_token_authority(
+            common.token_program.to_account_info(),
+            common.mint.to_account_info(),
+            common.custody.to_account_info(),
+            multisig_token_authority.to_account_info(),
+            common.token_authority.to_account_info(),
+            token_authority_sig,
+            amount,
+        )?,
+        None => mint_to_custody_from_token_authority(
+            common.token_program.to_account_info(),
+            common.mint.to_account_info(),
+            common.custody.to_account_info(),
+            common.token_authority.to_account_info(),
+            token_authority_sig,
+            amount,
+        )?,
+    };
+
+    onchain::invoke_transfer_checked(
+        &common.token_program.key(),
+        common.custody.to_account_info(),
+        common.mint.to_account_info(),
+        common.recipient.to_account_info(),
+        common.token_authority.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        common.mint.decimals,
+        token_authority_sig,
+    )?;
+    Ok(())
+}
+
+// Lock/unlock
+
+#[derive(Accounts)]
+pub struct ReceiveAndReleaseUnlock<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    common: ReceiveAndRelease<'info>,
+}
+
+/// Receives an inbound transfer from a Wormhole VAA, redeems it and unlocks the
+/// tokens to the recipient, in one go. Only available when the threshold is 1.
+/// If the inbound rate limit delays the transfer, it's queued instead (or the
+/// transaction reverts, when `revert_when_not_ready` is true), and can be
+/// released later with [`crate::instructions::release_inbound_unlock`].
+/// SECURITY: Signer checks are disabled here because anyone is permitted to
+/// relay a VAA.
+pub fn receive_and_release_wormhole_unlock<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReceiveAndReleaseUnlock<'info>>,
+    args: ReleaseInboundArgs,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .common
+        .redeem(ctx.bumps.common.inbox_item, args.revert_when_not_ready)?
+    {
+        return Ok(());
+    }
+
+    let common = &ctx.accounts.common;
+    onchain::invoke_transfer_checked(
+        &common.token_program.key(),
+        common.custody.to_account_info(),
+        common.mint.to_account_info(),
+        common.recipient.to_account_info(),
+        common.token_authority.to_account_info(),
+        ctx.remaining_accounts,
+        common.inbox_item.amount,
+        common.mint.decimals,
+        &[&[
+            crate::TOKEN_AUTHORITY_SEED,
+            &[ctx.bumps.common.token_authority],
+        ]],
+    )?;
+    Ok(())
+}
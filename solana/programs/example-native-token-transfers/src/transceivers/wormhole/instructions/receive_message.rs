@@ -9,8 +9,11 @@ use ntt_messages::{
 use wormhole_anchor_sdk::wormhole::PostedVaa;
 
 use crate::{
-    config::*, error::NTTError, messages::ValidatedTransceiverMessage,
-    transceivers::accounts::peer::TransceiverPeer, transfer::Payload,
+    config::*,
+    error::NTTError,
+    messages::{RequestedGasDropoff, ValidatedTransceiverMessage},
+    transceivers::accounts::peer::TransceiverPeer,
+    transfer::Payload,
 };
 
 #[derive(Accounts)]
@@ -64,12 +67,16 @@ pub struct ReceiveMessage<'info> {
 
 pub fn receive_message(ctx: Context<ReceiveMessage>) -> Result<()> {
     let message = ctx.accounts.vaa.message().message_data.clone();
+    let gas_dropoff = RequestedGasDropoff::from_transceiver_payload(
+        &ctx.accounts.vaa.message().transceiver_payload,
+    );
     let chain_id = ctx.accounts.vaa.emitter_chain();
     ctx.accounts
         .transceiver_message
         .set_inner(ValidatedTransceiverMessage {
             from_chain: ChainId { id: chain_id },
             message,
+            gas_dropoff,
         });
 
     Ok(())
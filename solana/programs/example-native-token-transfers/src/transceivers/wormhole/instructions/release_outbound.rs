@@ -6,8 +6,12 @@ use ntt_messages::{
 };
 
 use crate::{
-    config::*, error::NTTError, queue::outbox::OutboxItem, registered_transceiver::*,
-    transceivers::wormhole::accounts::*, transfer::Payload,
+    config::*,
+    error::NTTError,
+    queue::{index::QueueIndex, outbox::OutboxItem},
+    registered_transceiver::*,
+    transceivers::wormhole::accounts::*,
+    transfer::Payload,
 };
 
 #[derive(Accounts)]
@@ -46,6 +50,13 @@ pub struct ReleaseOutbound<'info> {
     pub emitter: UncheckedAccount<'info>,
 
     pub wormhole: WormholeAccounts<'info>,
+
+    #[account(
+        mut,
+        seeds = [QueueIndex::OUTBOUND_SEED_PREFIX],
+        bump = outbound_queue.bump,
+    )]
+    pub outbound_queue: Option<Account<'info, QueueIndex>>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -65,6 +76,12 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
         }
     }
 
+    if let Some(outbound_queue) = &mut accs.outbound_queue {
+        let index = outbound_queue.key();
+        let outbox_item = accs.outbox_item.key();
+        outbound_queue.dequeue(index, outbox_item, &mut accs.outbox_item.queue)?;
+    }
+
     assert!(accs.outbox_item.released.get(accs.transceiver.id)?);
     let message: TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>> =
         TransceiverMessage::new(
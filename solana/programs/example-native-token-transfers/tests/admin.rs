@@ -1,21 +1,56 @@
 #![cfg(feature = "test-sbf")]
 #![feature(type_changing_struct_update)]
 
-use anchor_lang::{prelude::Pubkey, system_program::System, Id};
+use anchor_lang::{
+    error::ErrorCode,
+    prelude::{Clock, Pubkey, Rent},
+    system_program::System,
+    AccountSerialize, AnchorSerialize, Discriminator, Id, Space,
+};
 use example_native_token_transfers::{
-    config::Config, error::NTTError, registered_transceiver::RegisteredTransceiver,
+    audit_log::{AuditAction, AuditLog},
+    config::{Config, DustSettings, DustSettingsV1, FeeSettings, FeeSettingsV1},
+    directory::{Directory, DirectoryPeer, DirectoryTransceiver},
+    error::NTTError,
+    instructions::{SetPeerAddressFormatArgs, SetPeerArgs},
+    peer::{
+        AddressFormat, AmountLimitSettings, LargeTransferSettings, NttManagerPeer, PeerFeeSettings,
+        PeerSettings, PeerSettingsV1,
+    },
+    pending_token_authority::PendingTokenAuthority,
+    queue::{
+        inbox::InboxRateLimit,
+        rate_limit::{BackflowSettings, RateLimitSettings},
+    },
+    registered_transceiver::RegisteredTransceiver,
+    PENDING_AUTHORITY_VALIDITY,
 };
-use ntt_messages::mode::Mode;
+use ntt_messages::{chain_id::ChainId, mode::Mode};
 use solana_program_test::*;
-use solana_sdk::{instruction::InstructionError, signer::Signer, transaction::TransactionError};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, InstructionError},
+    signature::Keypair,
+    signer::Signer,
+    transaction::TransactionError,
+};
 
 use crate::{
-    common::{query::GetAccountDataAnchor, setup::setup, submit::Submittable},
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, ANOTHER_CHAIN, ANOTHER_MANAGER, INBOUND_LIMIT, OTHER_CHAIN, OTHER_MANAGER},
+        submit::Submittable,
+    },
     sdk::accounts::{good_ntt, NTTAccounts},
     sdk::instructions::admin::{
-        deregister_transceiver, register_transceiver, set_threshold, DeregisterTransceiver,
-        RegisterTransceiver, SetThreshold,
+        cancel_ownership_transfer, claim_ownership, close_expired_pending_token_authority,
+        deregister_transceiver, register_transceiver, remove_peer, set_paused, set_peer,
+        set_peer_address_format, set_threshold, transfer_ownership, CancelOwnershipTransfer,
+        ClaimOwnership, CloseExpiredPendingTokenAuthority, DeregisterTransceiver,
+        RegisterTransceiver, RemovePeer, SetPaused, SetPeer, SetPeerAddressFormat, SetThreshold,
+        TransferOwnership,
     },
+    sdk::instructions::directory::{sync_directory_peer, sync_directory_transceiver},
 };
 
 pub mod common;
@@ -212,3 +247,564 @@ async fn test_threshold_too_high() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_audit_log() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let audit_log_before: AuditLog = ctx.get_account_data_anchor(good_ntt.audit_log()).await;
+
+    set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let audit_log: AuditLog = ctx.get_account_data_anchor(good_ntt.audit_log()).await;
+    assert_eq!(audit_log.total_entries, audit_log_before.total_entries + 1);
+
+    let entry = audit_log.entries[usize::from(audit_log_before.head)];
+    assert_eq!(entry.action, AuditAction::SetPaused);
+    assert_eq!(entry.signer, test_data.program_owner.pubkey());
+    assert_eq!(entry.subject, good_ntt.config());
+    assert_eq!(entry.before, AuditLog::digest(&false).unwrap());
+    assert_eq!(entry.after, AuditLog::digest(&true).unwrap());
+}
+
+#[tokio::test]
+async fn test_audit_log_is_required() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // omitting the audit log would let admin actions go unrecorded
+    let mut ix = set_paused(
+        &good_ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    );
+    assert_eq!(ix.accounts.pop().unwrap().pubkey, good_ntt.audit_log());
+
+    let err = ix
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotEnoughKeys.into())
+        )
+    );
+
+    let config: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(!config.paused);
+}
+
+#[tokio::test]
+async fn test_pending_owner_expiry() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let new_owner = Keypair::new();
+
+    transfer_ownership(
+        &good_ntt,
+        TransferOwnership {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            new_owner: new_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config_account.pending_owner, Some(new_owner.pubkey()));
+
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += PENDING_AUTHORITY_VALIDITY;
+    ctx.set_sysvar(&clock);
+
+    let err = claim_ownership(
+        &good_ntt,
+        ClaimOwnership {
+            new_owner: new_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&new_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PendingOwnerExpired.into())
+        )
+    );
+
+    cancel_ownership_transfer(
+        &good_ntt,
+        CancelOwnershipTransfer {
+            owner: test_data.program_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config_account.owner, test_data.program_owner.pubkey());
+    assert_eq!(config_account.pending_owner, None);
+
+    // make sure the same instruction isn't deduplicated as an already
+    // processed transaction
+    let slot = ctx.banks_client.get_root_slot().await.unwrap();
+    ctx.warp_to_slot(slot + 1).unwrap();
+
+    let err = cancel_ownership_transfer(
+        &good_ntt,
+        CancelOwnershipTransfer {
+            owner: test_data.program_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::NoPendingOwner.into())
+        )
+    );
+}
+
+/// Replaces the config with one created before the pending owner expiry and
+/// the trailing records were added, after an ownership transfer. Such configs
+/// end in the stale bytes left by the pending owner when it was cleared (the
+/// end of the custody address).
+async fn set_legacy_config(ctx: &mut ProgramTestContext) {
+    let mut config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    let legacy_len =
+        8 + Config::INIT_SPACE - 8 - FeeSettings::INIT_SPACE - DustSettings::INIT_SPACE;
+
+    config_account.pending_owner = Some(Pubkey::new_unique());
+    let mut data = vec![];
+    config_account.try_serialize(&mut data).unwrap();
+    data.truncate(legacy_len);
+
+    config_account.pending_owner = None;
+    let mut cleared = vec![];
+    config_account.try_serialize(&mut cleared).unwrap();
+    data[..legacy_len - 32].copy_from_slice(&cleared[..legacy_len - 32]);
+    assert_eq!(&data[legacy_len - 32..], config_account.custody.as_ref());
+
+    let mut config = ctx
+        .banks_client
+        .get_account(good_ntt.config())
+        .await
+        .unwrap()
+        .unwrap();
+    config.data = data;
+    config.lamports = Rent::default().minimum_balance(legacy_len);
+    ctx.set_account(&good_ntt.config(), &config.into());
+}
+
+#[tokio::test]
+async fn test_transfer_ownership_grows_legacy_config() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_legacy_config(&mut ctx).await;
+    let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config_account.fees, FeeSettings::Unavailable);
+    assert_eq!(config_account.dust, DustSettings::Unavailable);
+
+    let new_owner = Keypair::new();
+    let ix = transfer_ownership(
+        &good_ntt,
+        TransferOwnership {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            new_owner: new_owner.pubkey(),
+        },
+    );
+
+    // growing the config requires the payer and the system program, which
+    // are optional (the program id stands in for them when they're not passed)
+    let mut legacy_ix = ix.clone();
+    let len = legacy_ix.accounts.len();
+    for meta in &mut legacy_ix.accounts[len - 2..] {
+        *meta = AccountMeta::new_readonly(good_ntt.program(), false);
+    }
+    let err = legacy_ix
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::AccountNotEnoughKeys.into())
+        )
+    );
+
+    ix.submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let config = ctx
+        .banks_client
+        .get_account(good_ntt.config())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(config.data.len(), 8 + Config::INIT_SPACE);
+    assert!(config.lamports >= Rent::default().minimum_balance(config.data.len()));
+
+    // clearing the pending owner leaves its bytes at the end of the config,
+    // after the (now written) trailing records
+    claim_ownership(
+        &good_ntt,
+        ClaimOwnership {
+            new_owner: new_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&new_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config_account.owner, new_owner.pubkey());
+    assert_eq!(
+        config_account.fees,
+        FeeSettings::V1(FeeSettingsV1::default())
+    );
+    assert_eq!(
+        config_account.dust,
+        DustSettings::V1(DustSettingsV1::default())
+    );
+}
+
+#[tokio::test]
+async fn test_close_legacy_pending_token_authority() {
+    let (mut ctx, _test_data) = setup(Mode::Burning).await;
+
+    // pending token authorities created before the expiry was added end right
+    // before it, and are treated as expired
+    let rent_payer = Keypair::new().pubkey();
+    let (_, bump) =
+        Pubkey::find_program_address(&[PendingTokenAuthority::SEED_PREFIX], &good_ntt.program());
+    let mut data = PendingTokenAuthority::DISCRIMINATOR.to_vec();
+    data.extend(
+        (bump, Keypair::new().pubkey(), rent_payer)
+            .try_to_vec()
+            .unwrap(),
+    );
+    let lamports = Rent::default().minimum_balance(data.len());
+    ctx.set_account(
+        &good_ntt.pending_token_authority(),
+        &Account {
+            lamports,
+            data,
+            owner: good_ntt.program(),
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    close_expired_pending_token_authority(
+        &good_ntt,
+        CloseExpiredPendingTokenAuthority { rent_payer },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt.pending_token_authority())
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        ctx.banks_client.get_balance(rent_payer).await.unwrap(),
+        lamports
+    );
+}
+
+/// Truncates the peer of `OTHER_CHAIN` to its size before the settings were
+/// added.
+async fn set_legacy_peer(ctx: &mut ProgramTestContext) {
+    let peer = good_ntt.peer(OTHER_CHAIN);
+    let mut account = ctx.banks_client.get_account(peer).await.unwrap().unwrap();
+    let legacy_len = 8 + NttManagerPeer::INIT_SPACE
+        - PeerSettings::INIT_SPACE
+        - LargeTransferSettings::INIT_SPACE
+        - PeerFeeSettings::INIT_SPACE
+        - AmountLimitSettings::INIT_SPACE;
+    account.data.truncate(legacy_len);
+    account.lamports = Rent::default().minimum_balance(legacy_len);
+    ctx.set_account(&peer, &account.into());
+}
+
+#[tokio::test]
+async fn test_set_peer_grows_legacy_peer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // peers created before the settings were added end right before them
+    set_legacy_peer(&mut ctx).await;
+    let peer = good_ntt.peer(OTHER_CHAIN);
+
+    // they're still usable, with the default settings
+    let legacy: NttManagerPeer = ctx.get_account_data_anchor(peer).await;
+    assert_eq!(legacy.settings, PeerSettings::Unavailable);
+
+    // set_peer grows them to fit the settings
+    set_peer(
+        &good_ntt,
+        SetPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            address: OTHER_MANAGER,
+            limit: INBOUND_LIMIT,
+            token_decimals: 7,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let account = ctx.banks_client.get_account(peer).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + NttManagerPeer::INIT_SPACE);
+    assert!(account.lamports >= Rent::default().minimum_balance(account.data.len()));
+
+    let grown: NttManagerPeer = ctx.get_account_data_anchor(peer).await;
+    assert_eq!(
+        grown.settings,
+        PeerSettings::V1(PeerSettingsV1 {
+            address_format: AddressFormat::Bytes32,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_set_peer_address_format() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_legacy_peer(&mut ctx).await;
+    let peer = good_ntt.peer(OTHER_CHAIN);
+
+    // legacy peers are grown to fit the settings
+    set_peer_address_format(
+        &good_ntt,
+        SetPeerAddressFormat {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerAddressFormatArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            address_format: AddressFormat::Evm,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let account = ctx.banks_client.get_account(peer).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + NttManagerPeer::INIT_SPACE);
+
+    let grown: NttManagerPeer = ctx.get_account_data_anchor(peer).await;
+    assert_eq!(
+        grown.settings,
+        PeerSettings::V1(PeerSettingsV1 {
+            address_format: AddressFormat::Evm,
+        })
+    );
+    assert_eq!(grown.address, OTHER_MANAGER);
+
+    // set_peer keeps the format
+    set_peer(
+        &good_ntt,
+        SetPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            address: OTHER_MANAGER,
+            limit: INBOUND_LIMIT * 2,
+            token_decimals: 7,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let peer: NttManagerPeer = ctx.get_account_data_anchor(peer).await;
+    assert_eq!(peer.settings.current().address_format, AddressFormat::Evm);
+}
+
+#[tokio::test]
+async fn test_set_peer_grows_legacy_inbox_rate_limit() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    // inbox rate limits created before the settings were added end right
+    // before them
+    let inbox_rate_limit = good_ntt.inbox_rate_limit(OTHER_CHAIN);
+    let mut account = ctx
+        .banks_client
+        .get_account(inbox_rate_limit)
+        .await
+        .unwrap()
+        .unwrap();
+    let legacy_len = 8 + InboxRateLimit::INIT_SPACE
+        - RateLimitSettings::INIT_SPACE
+        - BackflowSettings::INIT_SPACE;
+    account.data.truncate(legacy_len);
+    account.lamports = Rent::default().minimum_balance(legacy_len);
+    ctx.set_account(&inbox_rate_limit, &account.into());
+
+    set_peer(
+        &good_ntt,
+        SetPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            address: OTHER_MANAGER,
+            limit: INBOUND_LIMIT * 2,
+            token_decimals: 7,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let account = ctx
+        .banks_client
+        .get_account(inbox_rate_limit)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), 8 + InboxRateLimit::INIT_SPACE);
+
+    let grown: InboxRateLimit = ctx.get_account_data_anchor(inbox_rate_limit).await;
+    assert_eq!(grown.rate_limit.limit, INBOUND_LIMIT * 2);
+    assert_eq!(grown.settings, RateLimitSettings::Unavailable);
+}
+
+#[tokio::test]
+async fn test_directory() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let other_peer = DirectoryPeer {
+        chain_id: ChainId { id: OTHER_CHAIN },
+        address: OTHER_MANAGER,
+        token_decimals: 7,
+    };
+    let another_peer = DirectoryPeer {
+        chain_id: ChainId { id: ANOTHER_CHAIN },
+        address: ANOTHER_MANAGER,
+        token_decimals: 7,
+    };
+    let transceiver = DirectoryTransceiver {
+        id: 0,
+        transceiver_address: example_native_token_transfers::ID,
+        enabled: true,
+    };
+
+    // set up by the admin instructions
+    let directory: Directory = ctx.get_account_data_anchor(good_ntt.directory()).await;
+    assert_eq!(directory.peers, vec![other_peer, another_peer]);
+    assert_eq!(directory.transceivers, vec![transceiver]);
+
+    remove_peer(
+        &good_ntt,
+        RemovePeer {
+            owner: test_data.program_owner.pubkey(),
+        },
+        ChainId { id: ANOTHER_CHAIN },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert!(ctx
+        .banks_client
+        .get_account(good_ntt.peer(ANOTHER_CHAIN))
+        .await
+        .unwrap()
+        .is_none());
+
+    let audit_log: AuditLog = ctx.get_account_data_anchor(good_ntt.audit_log()).await;
+    let entry = audit_log.entries[usize::from(audit_log.head) - 1];
+    assert_eq!(entry.action, AuditAction::RemovePeer);
+    assert_eq!(entry.subject, good_ntt.peer(ANOTHER_CHAIN));
+
+    deregister_transceiver(
+        &good_ntt,
+        DeregisterTransceiver {
+            owner: test_data.program_owner.pubkey(),
+            transceiver: example_native_token_transfers::ID,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let directory: Directory = ctx.get_account_data_anchor(good_ntt.directory()).await;
+    assert_eq!(directory.peers, vec![other_peer]);
+    assert_eq!(
+        directory.transceivers,
+        vec![DirectoryTransceiver {
+            enabled: false,
+            ..transceiver
+        }]
+    );
+
+    // entries that went stale (e.g. when the directory wasn't passed) can be
+    // synced by anyone
+    let mut account = ctx
+        .banks_client
+        .get_account(good_ntt.directory())
+        .await
+        .unwrap()
+        .unwrap();
+    let stale = Directory {
+        bump: directory.bump,
+        peers: vec![another_peer],
+        transceivers: vec![],
+    };
+    let mut data = Directory::DISCRIMINATOR.to_vec();
+    stale.serialize(&mut data).unwrap();
+    account.data[..data.len()].copy_from_slice(&data);
+    ctx.set_account(&good_ntt.directory(), &account.into());
+
+    sync_directory_peer(&good_ntt, ChainId { id: OTHER_CHAIN })
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    sync_directory_peer(&good_ntt, ChainId { id: ANOTHER_CHAIN })
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    sync_directory_transceiver(&good_ntt, &example_native_token_transfers::ID)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let synced: Directory = ctx.get_account_data_anchor(good_ntt.directory()).await;
+    assert_eq!(synced.peers, directory.peers);
+    assert_eq!(synced.transceivers, directory.transceivers);
+}
@@ -55,6 +55,8 @@ fn init_transfer_accs_args(
         from: test_data.user_token_account,
         from_authority: test_data.user.pubkey(),
         outbox_item,
+        rate_limit_exempt: false,
+        fee_recipient: None,
     };
 
     let args = TransferArgs {
@@ -81,6 +83,7 @@ fn init_redeem_accs(
         inbox_item: good_ntt.inbox_item(chain_id, ntt_manager_message),
         inbox_rate_limit: good_ntt.inbox_rate_limit(chain_id),
         mint: test_data.mint,
+        inbound_queue: good_ntt.inbound_queue(chain_id),
     }
 }
 
@@ -21,7 +21,11 @@ use crate::sdk::{
     accounts::{good_ntt, Governance, NTTAccounts},
     instructions::{
         admin::{register_transceiver, set_peer, RegisterTransceiver, SetPeer},
-        initialize::{initialize_with_token_program_id, Initialize},
+        directory::{initialize_directory, InitializeDirectory},
+        initialize::{
+            initialize_audit_log, initialize_with_token_program_id, Initialize, InitializeAuditLog,
+        },
+        queue_index::{initialize_inbound_queue, initialize_outbound_queue, InitializeQueue},
     },
     transceivers::wormhole::instructions::admin::{set_transceiver_peer, SetTransceiverPeer},
 };
@@ -199,6 +203,49 @@ pub async fn setup_ntt_with_token_program_id(
     .await
     .unwrap();
 
+    initialize_audit_log(
+        &good_ntt,
+        InitializeAuditLog {
+            payer: ctx.payer.pubkey(),
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    initialize_directory(
+        &good_ntt,
+        InitializeDirectory {
+            payer: ctx.payer.pubkey(),
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    initialize_outbound_queue(
+        &good_ntt,
+        InitializeQueue {
+            payer: ctx.payer.pubkey(),
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    for chain_id in [OTHER_CHAIN, ANOTHER_CHAIN] {
+        initialize_inbound_queue(
+            &good_ntt,
+            InitializeQueue {
+                payer: ctx.payer.pubkey(),
+            },
+            ChainId { id: chain_id },
+        )
+        .submit(ctx)
+        .await
+        .unwrap();
+    }
+
     register_transceiver(
         &good_ntt,
         RegisterTransceiver {
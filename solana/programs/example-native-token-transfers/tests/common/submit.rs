@@ -1,3 +1,5 @@
+use anchor_lang::{AnchorDeserialize, Event};
+use base64::Engine;
 use solana_program_test::{BanksClientError, ProgramTestContext};
 use solana_sdk::{
     instruction::Instruction, signature::Keypair, signer::Signer, signers::Signers,
@@ -49,3 +51,77 @@ impl Submittable for Transaction {
         ctx.banks_client.process_transaction(self).await
     }
 }
+
+/// Simulates `ix` and returns the compute units it consumes. The simulation
+/// must succeed.
+pub async fn compute_units(ix: Instruction, ctx: &mut ProgramTestContext) -> u64 {
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    transaction.sign(&[&ctx.payer], blockhash);
+
+    let simulation = ctx
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulation.result.unwrap().unwrap();
+    simulation.simulation_details.unwrap().units_consumed
+}
+
+/// Simulates `ix` and deserializes the data it returns. The simulation must
+/// succeed.
+pub async fn return_data<T: AnchorDeserialize>(ix: Instruction, ctx: &mut ProgramTestContext) -> T {
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    transaction.sign(&[&ctx.payer], blockhash);
+
+    let simulation = ctx
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulation.result.unwrap().unwrap();
+    // the runtime strips trailing zeroes from the return data (and drops it
+    // entirely if it's all zeroes)
+    let mut data = simulation
+        .simulation_details
+        .unwrap()
+        .return_data
+        .map(|return_data| return_data.data)
+        .unwrap_or_default();
+    data.resize(data.len() + 1024, 0);
+    T::deserialize(&mut &data[..]).unwrap()
+}
+
+/// Simulates `ix` and returns the events of type `E` it emits. The simulation
+/// must succeed.
+pub async fn emitted_events<E: Event, T: Signers + ?Sized>(
+    ix: Instruction,
+    signers: &T,
+    ctx: &mut ProgramTestContext,
+) -> Vec<E> {
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    transaction.partial_sign(&[&ctx.payer], blockhash);
+    transaction.partial_sign(signers, blockhash);
+
+    let simulation = ctx
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulation.result.unwrap().unwrap();
+    simulation
+        .simulation_details
+        .unwrap()
+        .logs
+        .iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|data| base64::engine::general_purpose::STANDARD.decode(data).ok())
+        .filter(|data| data.starts_with(&E::DISCRIMINATOR))
+        .map(|data| E::deserialize(&mut &data[E::DISCRIMINATOR.len()..]).unwrap())
+        .collect()
+}
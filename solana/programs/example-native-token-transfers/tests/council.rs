@@ -0,0 +1,375 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::{prelude::*, InstructionData};
+use example_native_token_transfers::{
+    config::Config,
+    council::{Council, COUNCIL_PAYER},
+    error::NTTError,
+    instructions::SetCouncilMembersArgs,
+};
+use ntt_messages::mode::Mode;
+use solana_program::{
+    bpf_loader_upgradeable::UpgradeableLoaderState,
+    instruction::{Instruction, InstructionError},
+};
+use solana_program_test::*;
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer, system_instruction,
+    transaction::TransactionError,
+};
+use wormhole_solana_utils::cpi::bpf_loader_upgradeable;
+
+use crate::{
+    common::{query::GetAccountDataAnchor, setup::setup, submit::Submittable},
+    sdk::{
+        accounts::{good_ntt, NTTAccounts},
+        instructions::{
+            admin::{set_paused, SetPaused},
+            council::{
+                approve_council_proposal, close_council_proposal, execute_council_proposal,
+                initialize_council, propose_council_instruction, ApproveCouncilProposal,
+                CloseCouncilProposal, ExecuteCouncilProposal, InitializeCouncil,
+                ProposeCouncilInstruction,
+            },
+        },
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+async fn propose(
+    ctx: &mut ProgramTestContext,
+    proposer: &Keypair,
+    instruction: Instruction,
+) -> u64 {
+    let council: Council = ctx.get_account_data_anchor(good_ntt.council()).await;
+    let proposal_id = council.next_proposal_id;
+
+    propose_council_instruction(
+        &good_ntt,
+        ProposeCouncilInstruction {
+            proposer: proposer.pubkey(),
+            proposal_id,
+        },
+        instruction,
+    )
+    .submit_with_signers(&[proposer], ctx)
+    .await
+    .unwrap();
+
+    proposal_id
+}
+
+async fn approve(ctx: &mut ProgramTestContext, member: &Keypair, proposal_id: u64) {
+    approve_council_proposal(
+        &good_ntt,
+        ApproveCouncilProposal {
+            member: member.pubkey(),
+            proposal_id,
+        },
+    )
+    .submit_with_signers(&[member], ctx)
+    .await
+    .unwrap();
+}
+
+async fn execute(
+    ctx: &mut ProgramTestContext,
+    proposal_id: u64,
+    instruction: &Instruction,
+) -> core::result::Result<(), BanksClientError> {
+    execute_council_proposal(
+        &good_ntt,
+        ExecuteCouncilProposal { proposal_id },
+        instruction,
+    )
+    .submit(ctx)
+    .await
+}
+
+#[tokio::test]
+async fn test_council() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let council = good_ntt.council();
+
+    // members pay the rent for their proposals
+    for member in &members {
+        system_instruction::transfer(&ctx.payer.pubkey(), &member.pubkey(), LAMPORTS_PER_SOL)
+            .submit(&mut ctx)
+            .await
+            .unwrap();
+    }
+
+    initialize_council(
+        &good_ntt,
+        InitializeCouncil {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetCouncilMembersArgs {
+            members: members.iter().map(|m| m.pubkey()).collect(),
+            threshold: 2,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // step 1. transfer ownership to the council
+    Instruction {
+        program_id: good_ntt.program(),
+        accounts: example_native_token_transfers::accounts::TransferOwnership {
+            config: good_ntt.config(),
+            owner: test_data.program_owner.pubkey(),
+            new_owner: council,
+            upgrade_lock: good_ntt.upgrade_lock(),
+            program_data: good_ntt.program_data(),
+            bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+            audit_log: good_ntt.audit_log(),
+            payer: None,
+            system_program: None,
+        }
+        .to_account_metas(None),
+        data: example_native_token_transfers::instruction::TransferOwnership.data(),
+    }
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // step 2. the council claims ownership
+    let claim_ownership = Instruction {
+        program_id: good_ntt.program(),
+        accounts: example_native_token_transfers::accounts::ClaimOwnership {
+            new_owner: council,
+            config: good_ntt.config(),
+            upgrade_lock: good_ntt.upgrade_lock(),
+            program_data: good_ntt.program_data(),
+            bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+            audit_log: good_ntt.audit_log(),
+        }
+        .to_account_metas(None),
+        data: example_native_token_transfers::instruction::ClaimOwnership {}.data(),
+    };
+
+    let proposal_id = propose(&mut ctx, &members[0], claim_ownership.clone()).await;
+
+    // a single approval is not enough
+    let err = execute(&mut ctx, proposal_id, &claim_ownership)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::CouncilThresholdNotMet.into())
+        )
+    );
+
+    approve(&mut ctx, &members[2], proposal_id).await;
+    execute(&mut ctx, proposal_id, &claim_ownership)
+        .await
+        .unwrap();
+
+    let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert_eq!(config_account.owner, council);
+    assert_eq!(config_account.pending_owner, None);
+
+    // the upgrade authority followed the ownership
+    let program_data = ctx
+        .banks_client
+        .get_account(good_ntt.program_data())
+        .await
+        .unwrap()
+        .unwrap();
+    match bincode::deserialize::<UpgradeableLoaderState>(&program_data.data).unwrap() {
+        UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address,
+            ..
+        } => assert_eq!(upgrade_authority_address, Some(council)),
+        state => panic!("unexpected program data state: {:?}", state),
+    }
+
+    // the proposal can't be executed again (in a new slot, so that the
+    // transaction isn't deduplicated)
+    let slot = ctx.banks_client.get_root_slot().await.unwrap();
+    ctx.warp_to_slot(slot + 1).unwrap();
+    let err = execute(&mut ctx, proposal_id, &claim_ownership)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::CouncilProposalAlreadyExecuted.into())
+        )
+    );
+
+    // step 3. the council pauses the program
+    let pause = set_paused(&good_ntt, SetPaused { owner: council }, true);
+    let proposal_id = propose(&mut ctx, &members[1], pause.clone()).await;
+    approve(&mut ctx, &members[0], proposal_id).await;
+    execute(&mut ctx, proposal_id, &pause).await.unwrap();
+
+    let config_account: Config = ctx.get_account_data_anchor(good_ntt.config()).await;
+    assert!(config_account.paused);
+}
+
+#[tokio::test]
+async fn test_council_non_member() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let member = Keypair::new();
+    initialize_council(
+        &good_ntt,
+        InitializeCouncil {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetCouncilMembersArgs {
+            members: vec![member.pubkey()],
+            threshold: 1,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the payer is not a member of the council
+    let err = propose_council_instruction(
+        &good_ntt,
+        ProposeCouncilInstruction {
+            proposer: ctx.payer.pubkey(),
+            proposal_id: 0,
+        },
+        set_paused(
+            &good_ntt,
+            SetPaused {
+                owner: good_ntt.council(),
+            },
+            true,
+        ),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::NotCouncilMember.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_council_payer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let member = Keypair::new();
+    system_instruction::transfer(&ctx.payer.pubkey(), &member.pubkey(), LAMPORTS_PER_SOL)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    initialize_council(
+        &good_ntt,
+        InitializeCouncil {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetCouncilMembersArgs {
+            members: vec![member.pubkey()],
+            threshold: 1,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    system_instruction::transfer(
+        &ctx.payer.pubkey(),
+        &good_ntt.council_payer(),
+        LAMPORTS_PER_SOL,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // the proposal spends lamports from the placeholder payer
+    let recipient = Keypair::new().pubkey();
+    let spend = system_instruction::transfer(&COUNCIL_PAYER, &recipient, LAMPORTS_PER_SOL / 2);
+    let proposal_id = propose(&mut ctx, &member, spend.clone()).await;
+
+    execute(&mut ctx, proposal_id, &spend).await.unwrap();
+
+    // the lamports came from the council payer PDA, not from the executor
+    assert_eq!(
+        ctx.banks_client.get_balance(recipient).await.unwrap(),
+        LAMPORTS_PER_SOL / 2
+    );
+    assert_eq!(
+        ctx.banks_client
+            .get_balance(good_ntt.council_payer())
+            .await
+            .unwrap(),
+        LAMPORTS_PER_SOL / 2
+    );
+}
+
+#[tokio::test]
+async fn test_council_proposal_self_reference() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let member = Keypair::new();
+    system_instruction::transfer(&ctx.payer.pubkey(), &member.pubkey(), LAMPORTS_PER_SOL)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    initialize_council(
+        &good_ntt,
+        InitializeCouncil {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetCouncilMembersArgs {
+            members: vec![member.pubkey()],
+            threshold: 1,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // a proposal closing itself would fail when the executed flag is written
+    // back, so it's rejected upfront
+    let close_itself = close_council_proposal(
+        &good_ntt,
+        CloseCouncilProposal {
+            proposer: member.pubkey(),
+            proposal_id: 0,
+        },
+    );
+    let err = propose_council_instruction(
+        &good_ntt,
+        ProposeCouncilInstruction {
+            proposer: member.pubkey(),
+            proposal_id: 0,
+        },
+        close_itself,
+    )
+    .submit_with_signers(&[&member], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::CouncilProposalSelfReference.into())
+        )
+    );
+}
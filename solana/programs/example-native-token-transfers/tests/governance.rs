@@ -85,6 +85,9 @@ async fn transfer_ownership_to_gov_program(
         upgrade_lock: good_ntt.upgrade_lock(),
         program_data: good_ntt.program_data(),
         bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+        audit_log: good_ntt.audit_log(),
+        payer: None,
+        system_program: None,
     };
 
     Instruction {
@@ -104,6 +107,7 @@ async fn transfer_ownership_to_gov_program(
         upgrade_lock: good_ntt.upgrade_lock(),
         program_data: good_ntt.program_data(),
         bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+        audit_log: good_ntt.audit_log(),
     };
 
     let inner_ix: Instruction = Instruction {
@@ -171,6 +175,9 @@ async fn test_governance_one_step_transfer() {
         upgrade_lock: good_ntt.upgrade_lock(),
         program_data: good_ntt.program_data(),
         bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+        audit_log: good_ntt.audit_log(),
+        payer: None,
+        system_program: None,
     };
 
     Instruction {
@@ -1,10 +1,17 @@
 use anchor_lang::prelude::Pubkey;
 use example_native_token_transfers::{
+    audit_log::AuditLog,
     config::Config,
+    council::{Council, CouncilProposal, COUNCIL_PAYER_SEED},
+    directory::Directory,
     instructions::TransferArgs,
+    intent::{IntentNonce, INTENT_AUTHORITY_SEED},
+    pending_token_authority::PendingTokenAuthority,
     queue::{
+        escrow::InboxEscrow,
         inbox::{InboxItem, InboxRateLimit},
-        outbox::OutboxRateLimit,
+        index::QueueIndex,
+        outbox::{OutboxItem, OutboxRateLimit, RateLimitExemption, SenderSequence},
     },
     registered_transceiver::RegisteredTransceiver,
     transfer::Payload,
@@ -120,6 +127,50 @@ pub trait NTTAccounts {
         session_authority
     }
 
+    fn sender_sequence(&self, sender: &Pubkey) -> Pubkey {
+        let (sender_sequence, _) = Pubkey::find_program_address(
+            &[SenderSequence::SEED_PREFIX, sender.as_ref()],
+            &self.program(),
+        );
+        sender_sequence
+    }
+
+    fn rate_limit_exemption(&self, sender: &Pubkey) -> Pubkey {
+        let (exemption, _) = Pubkey::find_program_address(
+            &[RateLimitExemption::SEED_PREFIX, sender.as_ref()],
+            &self.program(),
+        );
+        exemption
+    }
+
+    fn sequenced_outbox_item(&self, sender: &Pubkey, sequence: u64) -> Pubkey {
+        let (outbox_item, _) = Pubkey::find_program_address(
+            &[
+                OutboxItem::SEED_PREFIX,
+                sender.as_ref(),
+                &sequence.to_be_bytes(),
+            ],
+            &self.program(),
+        );
+        outbox_item
+    }
+
+    fn intent_authority(&self, sender: &Pubkey) -> Pubkey {
+        let (intent_authority, _) = Pubkey::find_program_address(
+            &[INTENT_AUTHORITY_SEED, sender.as_ref()],
+            &self.program(),
+        );
+        intent_authority
+    }
+
+    fn intent_nonce(&self, sender: &Pubkey) -> Pubkey {
+        let (intent_nonce, _) = Pubkey::find_program_address(
+            &[IntentNonce::SEED_PREFIX, sender.as_ref()],
+            &self.program(),
+        );
+        intent_nonce
+    }
+
     fn inbox_item(
         &self,
         chain: u16,
@@ -136,6 +187,59 @@ pub trait NTTAccounts {
         inbox_item
     }
 
+    fn inbox_escrow(&self, inbox_item: &Pubkey) -> Pubkey {
+        let (inbox_escrow, _) = Pubkey::find_program_address(
+            &[InboxEscrow::SEED_PREFIX, inbox_item.as_ref()],
+            &self.program(),
+        );
+        inbox_escrow
+    }
+
+    fn audit_log(&self) -> Pubkey {
+        let (audit_log, _) =
+            Pubkey::find_program_address(&[AuditLog::SEED_PREFIX], &self.program());
+        audit_log
+    }
+
+    fn directory(&self) -> Pubkey {
+        let (directory, _) =
+            Pubkey::find_program_address(&[Directory::SEED_PREFIX], &self.program());
+        directory
+    }
+
+    fn outbound_queue(&self) -> Pubkey {
+        let (outbound_queue, _) =
+            Pubkey::find_program_address(&[QueueIndex::OUTBOUND_SEED_PREFIX], &self.program());
+        outbound_queue
+    }
+
+    fn inbound_queue(&self, chain: u16) -> Pubkey {
+        let (inbound_queue, _) = Pubkey::find_program_address(
+            &[QueueIndex::INBOUND_SEED_PREFIX, &chain.to_be_bytes()],
+            &self.program(),
+        );
+        inbound_queue
+    }
+
+    fn council(&self) -> Pubkey {
+        let (council, _) = Pubkey::find_program_address(&[Council::SEED_PREFIX], &self.program());
+        council
+    }
+
+    fn council_payer(&self) -> Pubkey {
+        let (council_payer, _) =
+            Pubkey::find_program_address(&[COUNCIL_PAYER_SEED], &self.program());
+        council_payer
+    }
+
+    fn council_proposal(&self, id: u64) -> Pubkey {
+        let (proposal, _) = Pubkey::find_program_address(
+            &[CouncilProposal::SEED_PREFIX, &id.to_be_bytes()],
+            &self.program(),
+        );
+        proposal
+    }
+
     fn token_authority(&self) -> Pubkey {
         let (token_authority, _) =
             Pubkey::find_program_address(&[TOKEN_AUTHORITY_SEED], &self.program());
@@ -213,6 +317,12 @@ pub trait NTTAccounts {
         let (addr, _) = Pubkey::find_program_address(&[b"upgrade_lock"], &self.program());
         addr
     }
+
+    fn pending_token_authority(&self) -> Pubkey {
+        let (addr, _) =
+            Pubkey::find_program_address(&[PendingTokenAuthority::SEED_PREFIX], &self.program());
+        addr
+    }
 }
 
 /// This implements the account derivations correctly. For negative tests, other
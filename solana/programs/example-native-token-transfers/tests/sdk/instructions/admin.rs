@@ -1,6 +1,12 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
-use example_native_token_transfers::instructions::{SetOutboundLimitArgs, SetPeerArgs};
+use example_native_token_transfers::instructions::{
+    SetAmountLimitsArgs, SetBackflowArgs, SetFeeArgs, SetInboundStrictModeArgs,
+    SetLargeTransferPolicyArgs, SetOutboundLimitArgs, SetPeerAddressFormatArgs, SetPeerArgs,
+    SetPeerFeeArgs, SetRateLimitExemptionArgs,
+};
+use ntt_messages::chain_id::ChainId;
 use solana_sdk::instruction::Instruction;
+use wormhole_solana_utils::cpi::bpf_loader_upgradeable;
 
 use crate::sdk::accounts::NTT;
 
@@ -19,6 +25,8 @@ pub fn set_peer(ntt: &NTT, accounts: SetPeer, args: SetPeerArgs) -> Instruction
         payer: accounts.payer,
         peer: ntt.peer(chain_id),
         inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        audit_log: ntt.audit_log(),
+        directory: Some(ntt.directory()),
         system_program: System::id(),
     };
 
@@ -29,6 +37,28 @@ pub fn set_peer(ntt: &NTT, accounts: SetPeer, args: SetPeerArgs) -> Instruction
     }
 }
 
+pub struct RemovePeer {
+    pub owner: Pubkey,
+}
+
+pub fn remove_peer(ntt: &NTT, accounts: RemovePeer, chain_id: ChainId) -> Instruction {
+    let data = example_native_token_transfers::instruction::RemovePeer { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::RemovePeer {
+        owner: accounts.owner,
+        config: ntt.config(),
+        peer: ntt.peer(chain_id.id),
+        audit_log: ntt.audit_log(),
+        directory: Some(ntt.directory()),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
 pub struct SetPaused {
     pub owner: Pubkey,
 }
@@ -39,6 +69,7 @@ pub fn set_paused(ntt: &NTT, accounts: SetPaused, pause: bool) -> Instruction {
     let accounts = example_native_token_transfers::accounts::SetPaused {
         owner: accounts.owner,
         config: ntt.config(),
+        audit_log: ntt.audit_log(),
     };
 
     Instruction {
@@ -63,6 +94,8 @@ pub fn register_transceiver(ntt: &NTT, accounts: RegisterTransceiver) -> Instruc
         payer: accounts.payer,
         transceiver: accounts.transceiver,
         registered_transceiver: ntt.registered_transceiver(&accounts.transceiver),
+        audit_log: ntt.audit_log(),
+        directory: Some(ntt.directory()),
         system_program: System::id(),
     };
 
@@ -85,6 +118,8 @@ pub fn deregister_transceiver(ntt: &NTT, accounts: DeregisterTransceiver) -> Ins
         config: ntt.config(),
         owner: accounts.owner,
         registered_transceiver: ntt.registered_transceiver(&accounts.transceiver),
+        audit_log: ntt.audit_log(),
+        directory: Some(ntt.directory()),
     };
 
     Instruction {
@@ -104,6 +139,7 @@ pub fn set_threshold(ntt: &NTT, accounts: SetThreshold, threshold: u8) -> Instru
     let accounts = example_native_token_transfers::accounts::SetThreshold {
         config: ntt.config(),
         owner: accounts.owner,
+        audit_log: ntt.audit_log(),
     };
 
     Instruction {
@@ -128,6 +164,385 @@ pub fn set_outbound_limit(
         config: ntt.config(),
         owner: accounts.owner,
         rate_limit: ntt.outbox_rate_limit(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetStrictMode {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn set_outbound_strict_mode(ntt: &NTT, accounts: SetStrictMode, strict: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetOutboundStrictMode { strict };
+
+    let accounts = example_native_token_transfers::accounts::SetOutboundStrictMode {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        rate_limit: ntt.outbox_rate_limit(),
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn set_strict_dust_mode(ntt: &NTT, accounts: SetStrictMode, strict: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetStrictDustMode { strict };
+
+    let accounts = example_native_token_transfers::accounts::SetStrictDustMode {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn set_inbound_strict_mode(
+    ntt: &NTT,
+    accounts: SetStrictMode,
+    args: SetInboundStrictModeArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetInboundStrictMode { args };
+
+    let accounts = example_native_token_transfers::accounts::SetInboundStrictMode {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        rate_limit: ntt.inbox_rate_limit(chain_id),
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetRateLimitExemption {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn set_rate_limit_exemption(
+    ntt: &NTT,
+    accounts: SetRateLimitExemption,
+    args: SetRateLimitExemptionArgs,
+) -> Instruction {
+    let exemption = ntt.rate_limit_exemption(&args.sender);
+    let data = example_native_token_transfers::instruction::SetRateLimitExemption { args };
+
+    let accounts = example_native_token_transfers::accounts::SetRateLimitExemption {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        exemption,
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RemoveRateLimitExemption {
+    pub owner: Pubkey,
+}
+
+pub fn remove_rate_limit_exemption(
+    ntt: &NTT,
+    accounts: RemoveRateLimitExemption,
+    sender: Pubkey,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::RemoveRateLimitExemption { sender };
+
+    let accounts = example_native_token_transfers::accounts::RemoveRateLimitExemption {
+        owner: accounts.owner,
+        config: ntt.config(),
+        exemption: ntt.rate_limit_exemption(&sender),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetBackflow {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn set_backflow(ntt: &NTT, accounts: SetBackflow, args: SetBackflowArgs) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetBackflow { args };
+
+    let accounts = example_native_token_transfers::accounts::SetBackflow {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        rate_limit: ntt.inbox_rate_limit(chain_id),
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetPeerAddressFormat {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn set_peer_address_format(
+    ntt: &NTT,
+    accounts: SetPeerAddressFormat,
+    args: SetPeerAddressFormatArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetPeerAddressFormat { args };
+
+    let accounts = example_native_token_transfers::accounts::SetPeerAddressFormat {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        peer: ntt.peer(chain_id),
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetLargeTransferPolicy {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn set_large_transfer_policy(
+    ntt: &NTT,
+    accounts: SetLargeTransferPolicy,
+    args: SetLargeTransferPolicyArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetLargeTransferPolicy { args };
+
+    let accounts = example_native_token_transfers::accounts::SetLargeTransferPolicy {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        peer: ntt.peer(chain_id),
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetAmountLimits {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn set_amount_limits(
+    ntt: &NTT,
+    accounts: SetAmountLimits,
+    args: SetAmountLimitsArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetAmountLimits { args };
+
+    let accounts = example_native_token_transfers::accounts::SetAmountLimits {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        peer: ntt.peer(chain_id),
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetFee {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub fee_recipient: Pubkey,
+}
+
+pub fn set_fee(ntt: &NTT, accounts: SetFee, args: SetFeeArgs) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetFee { args };
+
+    let accounts = example_native_token_transfers::accounts::SetFee {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        fee_recipient: accounts.fee_recipient,
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetPeerFee {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn set_peer_fee(ntt: &NTT, accounts: SetPeerFee, args: SetPeerFeeArgs) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetPeerFee { args };
+
+    let accounts = example_native_token_transfers::accounts::SetPeerFee {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        peer: ntt.peer(chain_id),
+        system_program: System::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct TransferOwnership {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+pub fn transfer_ownership(ntt: &NTT, accounts: TransferOwnership) -> Instruction {
+    let data = example_native_token_transfers::instruction::TransferOwnership {};
+
+    let accounts = example_native_token_transfers::accounts::TransferOwnership {
+        config: ntt.config(),
+        owner: accounts.owner,
+        new_owner: accounts.new_owner,
+        upgrade_lock: ntt.upgrade_lock(),
+        program_data: ntt.program_data(),
+        bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+        audit_log: ntt.audit_log(),
+        payer: Some(accounts.payer),
+        system_program: Some(System::id()),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ClaimOwnership {
+    pub new_owner: Pubkey,
+}
+
+pub fn claim_ownership(ntt: &NTT, accounts: ClaimOwnership) -> Instruction {
+    let data = example_native_token_transfers::instruction::ClaimOwnership {};
+
+    let accounts = example_native_token_transfers::accounts::ClaimOwnership {
+        config: ntt.config(),
+        upgrade_lock: ntt.upgrade_lock(),
+        new_owner: accounts.new_owner,
+        program_data: ntt.program_data(),
+        bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct CancelOwnershipTransfer {
+    pub owner: Pubkey,
+}
+
+pub fn cancel_ownership_transfer(ntt: &NTT, accounts: CancelOwnershipTransfer) -> Instruction {
+    let data = example_native_token_transfers::instruction::CancelOwnershipTransfer {};
+
+    let accounts = example_native_token_transfers::accounts::CancelOwnershipTransfer {
+        config: ntt.config(),
+        owner: accounts.owner,
+        upgrade_lock: ntt.upgrade_lock(),
+        program_data: ntt.program_data(),
+        bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct CloseExpiredPendingTokenAuthority {
+    pub rent_payer: Pubkey,
+}
+
+pub fn close_expired_pending_token_authority(
+    ntt: &NTT,
+    accounts: CloseExpiredPendingTokenAuthority,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::CloseExpiredPendingTokenAuthority {};
+
+    let accounts = example_native_token_transfers::accounts::CloseExpiredPendingTokenAuthority {
+        rent_payer: accounts.rent_payer,
+        pending_token_authority: ntt.pending_token_authority(),
     };
 
     Instruction {
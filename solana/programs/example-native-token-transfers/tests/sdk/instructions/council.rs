@@ -0,0 +1,173 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use example_native_token_transfers::{
+    council::{CouncilInstruction, COUNCIL_PAYER},
+    instructions::SetCouncilMembersArgs,
+};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+
+use crate::sdk::accounts::NTT;
+
+pub struct InitializeCouncil {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn initialize_council(
+    ntt: &NTT,
+    accounts: InitializeCouncil,
+    args: SetCouncilMembersArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::InitializeCouncil { args };
+
+    let accounts = example_native_token_transfers::accounts::InitializeCouncil {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        council: ntt.council(),
+        audit_log: ntt.audit_log(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetCouncilMembers {
+    pub owner: Pubkey,
+}
+
+pub fn set_council_members(
+    ntt: &NTT,
+    accounts: SetCouncilMembers,
+    args: SetCouncilMembersArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetCouncilMembers { args };
+
+    let accounts = example_native_token_transfers::accounts::SetCouncilMembers {
+        owner: accounts.owner,
+        config: ntt.config(),
+        council: ntt.council(),
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ProposeCouncilInstruction {
+    pub proposer: Pubkey,
+    /// The id the proposal will be created with (i.e. the council's current
+    /// `next_proposal_id`).
+    pub proposal_id: u64,
+}
+
+pub fn propose_council_instruction(
+    ntt: &NTT,
+    accounts: ProposeCouncilInstruction,
+    instruction: Instruction,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ProposeCouncilInstruction {
+        council_instruction: CouncilInstruction::from(instruction),
+    };
+
+    let accounts = example_native_token_transfers::accounts::ProposeCouncilInstruction {
+        proposer: accounts.proposer,
+        council: ntt.council(),
+        proposal: ntt.council_proposal(accounts.proposal_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ApproveCouncilProposal {
+    pub member: Pubkey,
+    pub proposal_id: u64,
+}
+
+pub fn approve_council_proposal(ntt: &NTT, accounts: ApproveCouncilProposal) -> Instruction {
+    let data = example_native_token_transfers::instruction::ApproveCouncilProposal {};
+
+    let accounts = example_native_token_transfers::accounts::ApproveCouncilProposal {
+        member: accounts.member,
+        council: ntt.council(),
+        proposal: ntt.council_proposal(accounts.proposal_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ExecuteCouncilProposal {
+    pub proposal_id: u64,
+}
+
+/// `instruction` is the proposed instruction. Its accounts (with the signer
+/// flags cleared, as the program signs for the council, and [`COUNCIL_PAYER`]
+/// replaced with the council payer PDA) and program id are passed as remaining
+/// accounts.
+pub fn execute_council_proposal(
+    ntt: &NTT,
+    accounts: ExecuteCouncilProposal,
+    instruction: &Instruction,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ExecuteCouncilProposal {};
+
+    let mut account_metas = example_native_token_transfers::accounts::ExecuteCouncilProposal {
+        council_payer: ntt.council_payer(),
+        council: ntt.council(),
+        proposal: ntt.council_proposal(accounts.proposal_id),
+    }
+    .to_account_metas(None);
+
+    account_metas.extend(instruction.accounts.iter().map(|acc| AccountMeta {
+        pubkey: if acc.pubkey == COUNCIL_PAYER {
+            ntt.council_payer()
+        } else {
+            acc.pubkey
+        },
+        is_signer: false,
+        is_writable: acc.is_writable,
+    }));
+    account_metas.push(AccountMeta::new_readonly(instruction.program_id, false));
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: account_metas,
+        data: data.data(),
+    }
+}
+
+pub struct CloseCouncilProposal {
+    pub proposer: Pubkey,
+    pub proposal_id: u64,
+}
+
+pub fn close_council_proposal(ntt: &NTT, accounts: CloseCouncilProposal) -> Instruction {
+    let data = example_native_token_transfers::instruction::CloseCouncilProposal {};
+
+    let accounts = example_native_token_transfers::accounts::CloseCouncilProposal {
+        proposer: accounts.proposer,
+        council: ntt.council(),
+        proposal: ntt.council_proposal(accounts.proposal_id),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use ntt_messages::chain_id::ChainId;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct InitializeDirectory {
+    pub payer: Pubkey,
+}
+
+pub fn initialize_directory(ntt: &NTT, accounts: InitializeDirectory) -> Instruction {
+    let data = example_native_token_transfers::instruction::InitializeDirectory {};
+
+    let accounts = example_native_token_transfers::accounts::InitializeDirectory {
+        payer: accounts.payer,
+        directory: ntt.directory(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn sync_directory_peer(ntt: &NTT, chain_id: ChainId) -> Instruction {
+    let data = example_native_token_transfers::instruction::SyncDirectoryPeer { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::SyncDirectoryPeer {
+        peer: ntt.peer(chain_id.id),
+        directory: ntt.directory(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn sync_directory_transceiver(ntt: &NTT, transceiver: &Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::SyncDirectoryTransceiver {};
+
+    let accounts = example_native_token_transfers::accounts::SyncDirectoryTransceiver {
+        config: ntt.config(),
+        registered_transceiver: ntt.registered_transceiver(transceiver),
+        directory: ntt.directory(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
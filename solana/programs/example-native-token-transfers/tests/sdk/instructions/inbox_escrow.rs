@@ -0,0 +1,122 @@
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::token::Token;
+use example_native_token_transfers::accounts::NotPausedConfig;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct ReportFailedInboundRelease {
+    pub payer: Pubkey,
+    pub inbox_item: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub recipient_address: Pubkey,
+}
+
+pub fn report_failed_inbound_release(
+    ntt: &NTT,
+    accounts: ReportFailedInboundRelease,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReportFailedInboundRelease {};
+    let accounts = example_native_token_transfers::accounts::ReportFailedInboundRelease {
+        payer: accounts.payer,
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        inbox_item: accounts.inbox_item,
+        escrow: ntt.inbox_escrow(&accounts.inbox_item),
+        recipient_address: accounts.recipient_address,
+        recipient: accounts.recipient,
+        mint: accounts.mint,
+        token_program: Token::id(),
+        system_program: System::id(),
+    };
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RequestInboundEscrow {
+    pub payer: Pubkey,
+    pub recipient: Pubkey,
+    pub inbox_item: Pubkey,
+}
+
+pub fn request_inbound_escrow(ntt: &NTT, accounts: RequestInboundEscrow) -> Instruction {
+    let data = example_native_token_transfers::instruction::RequestInboundEscrow {};
+    let accounts = example_native_token_transfers::accounts::RequestInboundEscrow {
+        payer: accounts.payer,
+        recipient: accounts.recipient,
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        inbox_item: accounts.inbox_item,
+        escrow: ntt.inbox_escrow(&accounts.inbox_item),
+        system_program: System::id(),
+    };
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RedirectEscrowedInbound {
+    pub recipient: Pubkey,
+    pub inbox_item: Pubkey,
+    pub destination: Pubkey,
+}
+
+pub fn redirect_escrowed_inbound(ntt: &NTT, accounts: RedirectEscrowedInbound) -> Instruction {
+    let data = example_native_token_transfers::instruction::RedirectEscrowedInbound {};
+    let accounts = example_native_token_transfers::accounts::RedirectEscrowedInbound {
+        recipient: accounts.recipient,
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        inbox_item: accounts.inbox_item,
+        escrow: ntt.inbox_escrow(&accounts.inbox_item),
+        destination: accounts.destination,
+    };
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ReleaseEscrowedInbound {
+    pub payer: Pubkey,
+    pub inbox_item: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+}
+
+pub fn release_escrowed_inbound_unlock(
+    ntt: &NTT,
+    release_escrowed_inbound: ReleaseEscrowedInbound,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReleaseEscrowedInboundUnlock {};
+    let accounts = example_native_token_transfers::accounts::ReleaseEscrowedInboundUnlock {
+        common: example_native_token_transfers::accounts::ReleaseEscrowedInbound {
+            payer: release_escrowed_inbound.payer,
+            config: NotPausedConfig {
+                config: ntt.config(),
+            },
+            inbox_item: release_escrowed_inbound.inbox_item,
+            escrow: ntt.inbox_escrow(&release_escrowed_inbound.inbox_item),
+            destination: release_escrowed_inbound.destination,
+            token_authority: ntt.token_authority(),
+            mint: release_escrowed_inbound.mint,
+            token_program: Token::id(),
+            custody: ntt.custody(&release_escrowed_inbound.mint),
+        },
+    };
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
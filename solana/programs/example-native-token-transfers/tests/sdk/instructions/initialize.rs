@@ -49,3 +49,23 @@ pub fn initialize_with_token_program_id(
         data: data.data(),
     }
 }
+
+pub struct InitializeAuditLog {
+    pub payer: Pubkey,
+}
+
+pub fn initialize_audit_log(ntt: &NTT, accounts: InitializeAuditLog) -> Instruction {
+    let data = example_native_token_transfers::instruction::InitializeAuditLog {};
+
+    let accounts = example_native_token_transfers::accounts::InitializeAuditLog {
+        payer: accounts.payer,
+        audit_log: ntt.audit_log(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use ntt_messages::chain_id::ChainId;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct ApproveLargeInbound {
+    pub guardian: Pubkey,
+    pub inbox_item: Pubkey,
+}
+
+pub fn approve_large_inbound(
+    ntt: &NTT,
+    accounts: ApproveLargeInbound,
+    chain_id: ChainId,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ApproveLargeInbound { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::ApproveLargeInbound {
+        guardian: accounts.guardian,
+        peer: ntt.peer(chain_id.id),
+        inbox_item: accounts.inbox_item,
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ApproveLargeInboundByOwner {
+    pub owner: Pubkey,
+    pub inbox_item: Pubkey,
+}
+
+pub fn approve_large_inbound_by_owner(
+    ntt: &NTT,
+    accounts: ApproveLargeInboundByOwner,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ApproveLargeInboundByOwner {};
+
+    let accounts = example_native_token_transfers::accounts::ApproveLargeInboundByOwner {
+        config: ntt.config(),
+        owner: accounts.owner,
+        inbox_item: accounts.inbox_item,
+        audit_log: ntt.audit_log(),
+    };
+
+    Instruction {
+        program_id: ntt.program(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -1,6 +1,12 @@
 pub mod admin;
+pub mod council;
+pub mod directory;
+pub mod inbox_escrow;
 pub mod initialize;
+pub mod large_transfer;
 pub mod post_vaa;
+pub mod query;
+pub mod queue_index;
 pub mod redeem;
 pub mod release_inbound;
 pub mod transfer;
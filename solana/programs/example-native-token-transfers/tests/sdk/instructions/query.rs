@@ -0,0 +1,78 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use example_native_token_transfers::{accounts::NotPausedConfig, instructions::TransferArgs};
+use ntt_messages::chain_id::ChainId;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub fn query_outbound_capacity(ntt: &NTT) -> Instruction {
+    let data = example_native_token_transfers::instruction::QueryOutboundCapacity {};
+
+    let accounts = example_native_token_transfers::accounts::QueryOutboundCapacity {
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn query_inbound_capacity(ntt: &NTT, chain_id: ChainId) -> Instruction {
+    let data = example_native_token_transfers::instruction::QueryInboundCapacity { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::QueryInboundCapacity {
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id.id),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn query_outbox_item_status(outbox_item: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::QueryOutboxItemStatus {};
+
+    let accounts = example_native_token_transfers::accounts::QueryOutboxItemStatus { outbox_item };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn query_inbox_item_status(inbox_item: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::QueryInboxItemStatus {};
+
+    let accounts = example_native_token_transfers::accounts::QueryInboxItemStatus { inbox_item };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn simulate_transfer(ntt: &NTT, mint: Pubkey, args: TransferArgs) -> Instruction {
+    let accounts = example_native_token_transfers::accounts::SimulateTransfer {
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        mint,
+        peer: ntt.peer(args.recipient_chain.id),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        rate_limit_exemption: None,
+    };
+
+    let data = example_native_token_transfers::instruction::SimulateTransfer { args };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
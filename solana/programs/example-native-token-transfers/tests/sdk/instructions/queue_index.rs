@@ -0,0 +1,75 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use ntt_messages::chain_id::ChainId;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct InitializeQueue {
+    pub payer: Pubkey,
+}
+
+pub fn initialize_outbound_queue(ntt: &NTT, accounts: InitializeQueue) -> Instruction {
+    let data = example_native_token_transfers::instruction::InitializeOutboundQueue {};
+
+    let accounts = example_native_token_transfers::accounts::InitializeOutboundQueue {
+        payer: accounts.payer,
+        outbound_queue: ntt.outbound_queue(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn initialize_inbound_queue(
+    ntt: &NTT,
+    accounts: InitializeQueue,
+    chain_id: ChainId,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::InitializeInboundQueue { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::InitializeInboundQueue {
+        payer: accounts.payer,
+        inbound_queue: ntt.inbound_queue(chain_id.id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn dequeue_released_outbound(ntt: &NTT, outbox_item: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::DequeueReleasedOutbound {};
+
+    let accounts = example_native_token_transfers::accounts::DequeueReleasedOutbound {
+        outbox_item,
+        outbound_queue: ntt.outbound_queue(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn dequeue_released_inbound(ntt: &NTT, inbox_item: Pubkey, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::DequeueReleasedInbound {};
+
+    let accounts = example_native_token_transfers::accounts::DequeueReleasedInbound {
+        inbox_item,
+        inbound_queue: ntt.inbound_queue(chain_id),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
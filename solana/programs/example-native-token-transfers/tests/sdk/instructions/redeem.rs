@@ -13,6 +13,7 @@ pub struct Redeem {
     pub mint: Pubkey,
     pub inbox_item: Pubkey,
     pub inbox_rate_limit: Pubkey,
+    pub inbound_queue: Pubkey,
 }
 
 pub fn redeem(ntt: &NTT, accs: Redeem, args: RedeemArgs) -> Instruction {
@@ -29,6 +30,7 @@ pub fn redeem(ntt: &NTT, accs: Redeem, args: RedeemArgs) -> Instruction {
         inbox_rate_limit: accs.inbox_rate_limit,
         outbox_rate_limit: ntt.outbox_rate_limit(),
         system_program: System::id(),
+        inbound_queue: accs.inbound_queue,
     };
 
     Instruction {
@@ -1,5 +1,5 @@
 use anchor_lang::{prelude::*, InstructionData};
-use anchor_spl::token::Token;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
 use example_native_token_transfers::{accounts::NotPausedConfig, instructions::ReleaseInboundArgs};
 use solana_sdk::instruction::Instruction;
 
@@ -10,6 +10,11 @@ pub struct ReleaseInbound {
     pub inbox_item: Pubkey,
     pub mint: Pubkey,
     pub recipient: Pubkey,
+    pub recipient_address: Pubkey,
+    pub inbound_queue: Option<Pubkey>,
+    /// The source chain, whose inbound rate limit is passed along with the
+    /// outbound one (for transfers released in tranches).
+    pub rate_limits: Option<u16>,
 }
 
 pub fn release_inbound_unlock(
@@ -26,10 +31,18 @@ pub fn release_inbound_unlock(
             },
             inbox_item: release_inbound.inbox_item,
             recipient: release_inbound.recipient,
+            recipient_address: release_inbound.recipient_address,
             token_authority: ntt.token_authority(),
             mint: release_inbound.mint,
             token_program: Token::id(),
             custody: ntt.custody(&release_inbound.mint),
+            associated_token_program: AssociatedToken::id(),
+            system_program: System::id(),
+            inbound_queue: release_inbound.inbound_queue,
+            inbox_rate_limit: release_inbound
+                .rate_limits
+                .map(|chain| ntt.inbox_rate_limit(chain)),
+            outbox_rate_limit: release_inbound.rate_limits.map(|_| ntt.outbox_rate_limit()),
         },
     };
     Instruction {
@@ -38,3 +51,50 @@ pub fn release_inbound_unlock(
         data: data.data(),
     }
 }
+
+pub struct AcceptInboundToTokenAccount {
+    pub inbox_item: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn accept_inbound_to_token_account(
+    ntt: &NTT,
+    accept: AcceptInboundToTokenAccount,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::AcceptInboundToTokenAccount {};
+    let accounts = example_native_token_transfers::accounts::AcceptInboundToTokenAccount {
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        inbox_item: accept.inbox_item,
+        recipient_token_account: accept.recipient_token_account,
+        owner: accept.owner,
+    };
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct DeliverGasDropoff {
+    pub payer: Pubkey,
+    pub inbox_item: Pubkey,
+    pub recipient_address: Pubkey,
+}
+
+pub fn deliver_gas_dropoff(accounts: DeliverGasDropoff) -> Instruction {
+    let data = example_native_token_transfers::instruction::DeliverGasDropoff {};
+    let accounts = example_native_token_transfers::accounts::DeliverGasDropoff {
+        payer: accounts.payer,
+        inbox_item: accounts.inbox_item,
+        recipient_address: accounts.recipient_address,
+        system_program: System::id(),
+    };
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -1,8 +1,17 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
 use anchor_spl::{token::Token, token_2022::spl_token_2022};
-use example_native_token_transfers::{accounts::NotPausedConfig, instructions::TransferArgs};
+use example_native_token_transfers::{
+    accounts::NotPausedConfig,
+    instructions::{TransferArgs, TransferBatchArgs, TransferWithIntentArgs},
+};
 use ntt_messages::mode::Mode;
-use solana_sdk::instruction::Instruction;
+use solana_program::sysvar::instructions as instructions_sysvar;
+use solana_sdk::{
+    ed25519_program,
+    instruction::{AccountMeta, Instruction},
+    signature::Keypair,
+    signer::Signer,
+};
 
 use crate::sdk::accounts::NTT;
 
@@ -14,6 +23,12 @@ pub struct Transfer {
     pub from_authority: Pubkey,
     pub peer: Pubkey,
     pub outbox_item: Pubkey,
+    /// Whether the sender draws from its exemption from the outbound rate
+    /// limit.
+    pub rate_limit_exempt: bool,
+    /// The token account that receives the protocol fee, if the transfer is
+    /// charged one.
+    pub fee_recipient: Option<Pubkey>,
 }
 
 pub fn transfer(ntt: &NTT, transfer: Transfer, args: TransferArgs, mode: Mode) -> Instruction {
@@ -89,6 +104,277 @@ pub fn transfer_lock_with_token_program_id(
     }
 }
 
+/// Builds a `transfer_*_direct` instruction, in which `transfer.from_authority`
+/// (the owner or delegate of `transfer.from`) signs directly.
+pub fn transfer_direct(
+    ntt: &NTT,
+    transfer: Transfer,
+    args: TransferArgs,
+    mode: Mode,
+) -> Instruction {
+    let chain_id = args.recipient_chain.id;
+    let common = common_with_token_program_id(ntt, &transfer, &Token::id());
+
+    let accounts = match mode {
+        Mode::Burning => example_native_token_transfers::accounts::TransferBurnDirect {
+            common,
+            inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+            peer: transfer.peer,
+            authority: transfer.from_authority,
+            token_authority: ntt.token_authority(),
+        }
+        .to_account_metas(None),
+        Mode::Locking => example_native_token_transfers::accounts::TransferLockDirect {
+            common,
+            inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+            peer: transfer.peer,
+            authority: transfer.from_authority,
+        }
+        .to_account_metas(None),
+    };
+    let data = match mode {
+        Mode::Burning => {
+            example_native_token_transfers::instruction::TransferBurnDirect { args }.data()
+        }
+        Mode::Locking => {
+            example_native_token_transfers::instruction::TransferLockDirect { args }.data()
+        }
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Builds a `transfer_*_sequenced` instruction. `transfer.from_authority`
+/// signs directly, and `transfer.outbox_item` is ignored in favour of the PDA
+/// derived from `sequence`, which must be the sender's next sequence number.
+pub fn transfer_sequenced(
+    ntt: &NTT,
+    transfer: Transfer,
+    sequence: u64,
+    args: TransferArgs,
+    mode: Mode,
+) -> Instruction {
+    let chain_id = args.recipient_chain.id;
+    let common = example_native_token_transfers::accounts::TransferSequenced {
+        payer: transfer.payer,
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        mint: transfer.mint,
+        from: transfer.from,
+        authority: transfer.from_authority,
+        token_program: Token::id(),
+        sender_sequence: ntt.sender_sequence(&transfer.from_authority),
+        outbox_item: ntt.sequenced_outbox_item(&transfer.from_authority, sequence),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        custody: ntt.custody(&transfer.mint),
+        system_program: System::id(),
+        outbound_queue: ntt.outbound_queue(),
+        rate_limit_exemption: transfer
+            .rate_limit_exempt
+            .then(|| ntt.rate_limit_exemption(&transfer.from_authority)),
+        fee_recipient: transfer.fee_recipient,
+    };
+
+    let accounts = match mode {
+        Mode::Burning => example_native_token_transfers::accounts::TransferBurnSequenced {
+            common,
+            inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+            peer: transfer.peer,
+            token_authority: ntt.token_authority(),
+        }
+        .to_account_metas(None),
+        Mode::Locking => example_native_token_transfers::accounts::TransferLockSequenced {
+            common,
+            inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+            peer: transfer.peer,
+        }
+        .to_account_metas(None),
+    };
+    let data = match mode {
+        Mode::Burning => {
+            example_native_token_transfers::instruction::TransferBurnSequenced { args }.data()
+        }
+        Mode::Locking => {
+            example_native_token_transfers::instruction::TransferLockSequenced { args }.data()
+        }
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Builds a `transfer_*_batch` instruction. `transfer.from_authority` signs
+/// directly, and `transfer.peer` and `transfer.outbox_item` are ignored in
+/// favour of the accounts of the legs. `sequence` must be the sender's next
+/// sequence number.
+pub fn transfer_batch(
+    ntt: &NTT,
+    transfer: Transfer,
+    sequence: u64,
+    args: TransferBatchArgs,
+    mode: Mode,
+) -> Instruction {
+    let common = example_native_token_transfers::accounts::TransferBatch {
+        payer: transfer.payer,
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        mint: transfer.mint,
+        from: transfer.from,
+        authority: transfer.from_authority,
+        token_program: Token::id(),
+        sender_sequence: ntt.sender_sequence(&transfer.from_authority),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        custody: ntt.custody(&transfer.mint),
+        system_program: System::id(),
+        outbound_queue: ntt.outbound_queue(),
+        rate_limit_exemption: transfer
+            .rate_limit_exempt
+            .then(|| ntt.rate_limit_exemption(&transfer.from_authority)),
+        fee_recipient: transfer.fee_recipient,
+    };
+
+    let mut accounts = match mode {
+        Mode::Burning => example_native_token_transfers::accounts::TransferBurnBatch {
+            common,
+            token_authority: ntt.token_authority(),
+        }
+        .to_account_metas(None),
+        Mode::Locking => example_native_token_transfers::accounts::TransferLockBatch { common }
+            .to_account_metas(None),
+    };
+
+    // the outbox items of the legs, then the peer and inbox rate limit of each
+    // distinct chain
+    let mut chains = vec![];
+    for (i, leg) in (0u64..).zip(&args.legs) {
+        accounts.push(AccountMeta::new(
+            ntt.sequenced_outbox_item(&transfer.from_authority, sequence + i),
+            false,
+        ));
+        if !chains.contains(&leg.recipient_chain.id) {
+            chains.push(leg.recipient_chain.id);
+        }
+    }
+    for chain_id in chains {
+        accounts.push(AccountMeta::new_readonly(ntt.peer(chain_id), false));
+        accounts.push(AccountMeta::new(ntt.inbox_rate_limit(chain_id), false));
+    }
+
+    let data = match mode {
+        Mode::Burning => {
+            example_native_token_transfers::instruction::TransferBurnBatch { args }.data()
+        }
+        Mode::Locking => {
+            example_native_token_transfers::instruction::TransferLockBatch { args }.data()
+        }
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Builds the ed25519 precompile instruction verifying `sender`'s signature
+/// over the intent, which has to immediately precede the
+/// `transfer_*_with_intent` instruction.
+pub fn sign_intent(sender: &Keypair, args: &TransferWithIntentArgs) -> Instruction {
+    let message = args.intent.message().unwrap();
+    let signature = sender.sign_message(&message);
+
+    // [num_signatures, padding, offsets (7 x u16), public key, signature, message]
+    let public_key_offset: u16 = 2 + 14;
+    let signature_offset = public_key_offset + 32;
+    let message_data_offset = signature_offset + 64;
+
+    let mut data = vec![1, 0];
+    for offset in [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_data_offset,
+        u16::try_from(message.len()).unwrap(),
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(sender.pubkey().as_ref());
+    data.extend_from_slice(signature.as_ref());
+    data.extend_from_slice(&message);
+
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Builds a `transfer_*_with_intent` instruction. `transfer.from_authority` is
+/// ignored, the tokens are spent by the intent authority of the intent's
+/// sender.
+pub fn transfer_with_intent(
+    ntt: &NTT,
+    transfer: Transfer,
+    relayer_fee_recipient: Pubkey,
+    args: TransferWithIntentArgs,
+    mode: Mode,
+) -> Instruction {
+    let chain_id = args.intent.recipient_chain.id;
+    let sender = args.intent.sender;
+    let common = common_with_token_program_id(ntt, &transfer, &Token::id());
+
+    let accounts = match mode {
+        Mode::Burning => example_native_token_transfers::accounts::TransferBurnWithIntent {
+            common,
+            inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+            peer: transfer.peer,
+            intent_nonce: ntt.intent_nonce(&sender),
+            intent_authority: ntt.intent_authority(&sender),
+            relayer_fee_recipient,
+            instructions: instructions_sysvar::ID,
+            system_program: System::id(),
+            token_authority: ntt.token_authority(),
+        }
+        .to_account_metas(None),
+        Mode::Locking => example_native_token_transfers::accounts::TransferLockWithIntent {
+            common,
+            inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+            peer: transfer.peer,
+            intent_nonce: ntt.intent_nonce(&sender),
+            intent_authority: ntt.intent_authority(&sender),
+            relayer_fee_recipient,
+            instructions: instructions_sysvar::ID,
+            system_program: System::id(),
+        }
+        .to_account_metas(None),
+    };
+    let data = match mode {
+        Mode::Burning => {
+            example_native_token_transfers::instruction::TransferBurnWithIntent { args }.data()
+        }
+        Mode::Locking => {
+            example_native_token_transfers::instruction::TransferLockWithIntent { args }.data()
+        }
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts,
+        data,
+    }
+}
+
 pub fn approve_token_authority(
     ntt: &NTT,
     user_token_account: &Pubkey,
@@ -133,5 +419,10 @@ fn common_with_token_program_id(
         outbox_rate_limit: ntt.outbox_rate_limit(),
         system_program: System::id(),
         custody: ntt.custody_with_token_program_id(&transfer.mint, token_program_id),
+        outbound_queue: ntt.outbound_queue(),
+        rate_limit_exemption: transfer
+            .rate_limit_exempt
+            .then(|| ntt.rate_limit_exemption(&transfer.from_authority)),
+        fee_recipient: transfer.fee_recipient,
     }
 }
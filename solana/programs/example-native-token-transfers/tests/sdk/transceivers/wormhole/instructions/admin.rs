@@ -22,6 +22,7 @@ pub fn set_transceiver_peer(
         owner: accounts.owner,
         payer: accounts.payer,
         peer: ntt.transceiver_peer(chain_id),
+        audit_log: ntt.audit_log(),
         system_program: System::id(),
     };
 
@@ -0,0 +1,57 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+use example_native_token_transfers::instructions::ReleaseInboundArgs;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+#[derive(Debug, Clone)]
+pub struct ReceiveAndRelease {
+    pub payer: Pubkey,
+    pub vaa: Pubkey,
+    pub chain_id: u16,
+    pub inbox_item: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub recipient_address: Pubkey,
+}
+
+pub fn receive_and_release_unlock(
+    ntt: &NTT,
+    accs: ReceiveAndRelease,
+    args: ReleaseInboundArgs,
+) -> Instruction {
+    let data =
+        example_native_token_transfers::instruction::ReceiveAndReleaseWormholeUnlock { args };
+
+    let accounts = example_native_token_transfers::accounts::ReceiveAndReleaseUnlock {
+        common: example_native_token_transfers::accounts::ReceiveAndRelease {
+            payer: accs.payer,
+            config: example_native_token_transfers::accounts::NotPausedConfig {
+                config: ntt.config(),
+            },
+            peer: ntt.peer(accs.chain_id),
+            transceiver_peer: ntt.transceiver_peer(accs.chain_id),
+            vaa: accs.vaa,
+            transceiver: ntt.registered_transceiver(&ntt.program()),
+            inbox_item: accs.inbox_item,
+            inbox_rate_limit: ntt.inbox_rate_limit(accs.chain_id),
+            outbox_rate_limit: ntt.outbox_rate_limit(),
+            recipient: accs.recipient,
+            recipient_address: accs.recipient_address,
+            token_authority: ntt.token_authority(),
+            mint: accs.mint,
+            token_program: Token::id(),
+            custody: ntt.custody(&accs.mint),
+            associated_token_program: AssociatedToken::id(),
+            system_program: System::id(),
+            inbound_queue: ntt.inbound_queue(accs.chain_id),
+        },
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -27,6 +27,7 @@ pub fn release_outbound(
         emitter: ntt.emitter(),
         transceiver: ntt.registered_transceiver(&ntt.program()),
         wormhole: wormhole_accounts(ntt),
+        outbound_queue: Some(ntt.outbound_queue()),
     };
     Instruction {
         program_id: example_native_token_transfers::ID,
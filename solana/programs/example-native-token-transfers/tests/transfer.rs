@@ -7,8 +7,23 @@ use common::setup::{TestData, OTHER_CHAIN};
 use example_native_token_transfers::{
     bitmap::Bitmap,
     error::NTTError,
-    instructions::{SetOutboundLimitArgs, TransferArgs},
-    queue::outbox::{OutboxItem, OutboxRateLimit},
+    events::OutboundTransfer,
+    instructions::{
+        OutboxItemStatus, RateLimitCapacity, SetAmountLimitsArgs, SetFeeArgs,
+        SetLargeTransferPolicyArgs, SetOutboundLimitArgs, SetPeerAddressFormatArgs, SetPeerFeeArgs,
+        SetRateLimitExemptionArgs, TransferArgs, TransferBatchArgs, TransferLeg,
+        TransferSimulation, TransferWithIntentArgs,
+    },
+    intent::TransferIntent,
+    peer::{AddressFormat, AmountLimits, LargeTransferPolicy},
+    queue::{
+        index::{QueueEntry, QueueIndex, QueueMembership},
+        outbox::{
+            OutboxItem, OutboxProvenance, OutboxProvenanceV1, OutboxRateLimit, RateLimitExemption,
+            SenderSequence,
+        },
+        rate_limit::{RateLimitSettings, RateLimitSettingsV1, RateLimitState},
+    },
     transceivers::wormhole::ReleaseOutboundArgs,
     transfer::Payload,
 };
@@ -20,15 +35,18 @@ use ntt_messages::{
 use sdk::accounts::NTT;
 use solana_program_test::*;
 use solana_sdk::{
-    instruction::InstructionError, signature::Keypair, signer::Signer,
-    transaction::TransactionError,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
 };
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 use wormhole_anchor_sdk::wormhole::PostedVaa;
 
 use crate::{
     common::{
         query::GetAccountDataAnchor,
-        setup::{ANOTHER_CHAIN, OUTBOUND_LIMIT, UNREGISTERED_CHAIN},
+        setup::{ANOTHER_CHAIN, INBOUND_LIMIT, OUTBOUND_LIMIT, UNREGISTERED_CHAIN},
     },
     sdk::{
         accounts::{good_ntt, NTTAccounts},
@@ -36,13 +54,29 @@ use crate::{
     },
 };
 use crate::{
-    common::{setup::OTHER_MANAGER, submit::Submittable},
+    common::{
+        setup::OTHER_MANAGER,
+        submit::{emitted_events, return_data, Submittable},
+    },
     sdk::{
         instructions::{
-            admin::{set_outbound_limit, set_paused, SetOutboundLimit, SetPaused},
+            admin::{
+                remove_rate_limit_exemption, set_amount_limits, set_fee, set_large_transfer_policy,
+                set_outbound_limit, set_outbound_strict_mode, set_paused, set_peer_address_format,
+                set_peer_fee, set_rate_limit_exemption, set_strict_dust_mode,
+                RemoveRateLimitExemption, SetAmountLimits, SetFee, SetLargeTransferPolicy,
+                SetOutboundLimit, SetPaused, SetPeerAddressFormat, SetPeerFee,
+                SetRateLimitExemption, SetStrictMode,
+            },
+            query::{
+                query_inbound_capacity, query_outbound_capacity, query_outbox_item_status,
+                simulate_transfer,
+            },
+            queue_index::dequeue_released_outbound,
             transfer::{
-                approve_token_authority, approve_token_authority_with_token_program_id, transfer,
-                transfer_with_token_program_id,
+                approve_token_authority, approve_token_authority_with_token_program_id,
+                sign_intent, transfer, transfer_batch, transfer_direct, transfer_sequenced,
+                transfer_with_intent, transfer_with_token_program_id,
             },
         },
         transceivers::wormhole::instructions::release_outbound::{
@@ -74,6 +108,8 @@ fn init_accs_args(
         from_authority: test_data.user.pubkey(),
         peer: ntt.peer(OTHER_CHAIN),
         outbox_item,
+        rate_limit_exempt: false,
+        fee_recipient: None,
     };
 
     let args = TransferArgs {
@@ -122,6 +158,359 @@ pub async fn test_transfer_burning_with_transfer_fee() {
     .await;
 }
 
+#[tokio::test]
+pub async fn test_transfer_direct_locking() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    test_transfer_direct(&mut ctx, &test_data, Mode::Locking).await;
+}
+
+#[tokio::test]
+pub async fn test_transfer_direct_burning() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+    test_transfer_direct(&mut ctx, &test_data, Mode::Burning).await;
+}
+
+/// Transfers signed directly by the token account owner, and by a delegate
+/// (standing in for a program PDA), without a session authority.
+async fn test_transfer_direct(ctx: &mut ProgramTestContext, test_data: &TestData, mode: Mode) {
+    // 1. the owner signs
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 154, false);
+
+    transfer_direct(&good_ntt, accs, args, mode)
+        .submit_with_signers(&[&test_data.user, &outbox_item], ctx)
+        .await
+        .unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account.sender, test_data.user.pubkey());
+    assert_eq!(
+        outbox_item_account.amount,
+        TrimmedAmount {
+            amount: 1,
+            decimals: 7
+        }
+    );
+
+    // 2. a delegate signs
+    let delegate = Keypair::new();
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 154, false);
+
+    spl_token_2022::instruction::approve(
+        &spl_token::ID,
+        &test_data.user_token_account,
+        &delegate.pubkey(),
+        &test_data.user.pubkey(),
+        &[],
+        args.amount,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+
+    transfer_direct(
+        &good_ntt,
+        Transfer {
+            from_authority: delegate.pubkey(),
+            ..accs
+        },
+        args,
+        mode,
+    )
+    .submit_with_signers(&[&delegate, &outbox_item], ctx)
+    .await
+    .unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account.sender, delegate.pubkey());
+}
+
+#[tokio::test]
+pub async fn test_transfer_invalid_recipient() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let expect_invalid_recipient = |err: BanksClientError| {
+        assert_eq!(
+            err.unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(NTTError::InvalidRecipientAddressFormat.into())
+            )
+        );
+    };
+
+    // the zero address is always rejected
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+    let args = TransferArgs {
+        recipient_address: [0u8; 32],
+        ..args
+    };
+    let err = transfer_direct(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&test_data.user, &outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    expect_invalid_recipient(err);
+
+    set_peer_address_format(
+        &good_ntt,
+        SetPeerAddressFormat {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerAddressFormatArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            address_format: AddressFormat::Evm,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // EVM addresses must be left-padded
+    let mut evm_address = [0u8; 32];
+    evm_address[..20].copy_from_slice(&[1u8; 20]);
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+    let args = TransferArgs {
+        recipient_address: evm_address,
+        ..args
+    };
+    let err = transfer_direct(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&test_data.user, &outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    expect_invalid_recipient(err);
+
+    let mut evm_address = [0u8; 32];
+    evm_address[12..].copy_from_slice(&[1u8; 20]);
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+    let args = TransferArgs {
+        recipient_address: evm_address,
+        ..args
+    };
+    transfer_direct(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&test_data.user, &outbox_item], &mut ctx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+pub async fn test_transfer_sequenced_locking() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    test_transfer_sequenced(&mut ctx, &test_data, Mode::Locking).await;
+}
+
+#[tokio::test]
+pub async fn test_transfer_sequenced_burning() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+    test_transfer_sequenced(&mut ctx, &test_data, Mode::Burning).await;
+}
+
+/// Transfers whose outbox items are PDAs derived from the sender's sequence
+/// number, so no outbox item keypair needs to sign.
+async fn test_transfer_sequenced(ctx: &mut ProgramTestContext, test_data: &TestData, mode: Mode) {
+    let sender = test_data.user.pubkey();
+
+    for sequence in 0..2 {
+        let outbox_item = good_ntt.sequenced_outbox_item(&sender, sequence);
+        let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item, 154, false);
+
+        transfer_sequenced(&good_ntt, accs, sequence, args, mode)
+            .submit_with_signers(&[&test_data.user], ctx)
+            .await
+            .unwrap();
+
+        let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+        assert_eq!(outbox_item_account.sender, sender);
+
+        let sender_sequence: SenderSequence = ctx
+            .get_account_data_anchor(good_ntt.sender_sequence(&sender))
+            .await;
+        assert_eq!(sender_sequence.next_sequence, sequence + 1);
+    }
+
+    // sequence numbers can't be skipped
+    let outbox_item = good_ntt.sequenced_outbox_item(&sender, 3);
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item, 154, false);
+
+    let err = transfer_sequenced(&good_ntt, accs, 3, args, mode)
+        .submit_with_signers(&[&test_data.user], ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintSeeds.into())
+        )
+    );
+}
+
+#[tokio::test]
+pub async fn test_transfer_with_intent_locking() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    test_transfer_with_intent(&mut ctx, &test_data, Mode::Locking).await;
+}
+
+#[tokio::test]
+pub async fn test_transfer_with_intent_burning() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+    test_transfer_with_intent(&mut ctx, &test_data, Mode::Burning).await;
+}
+
+/// A relayer (the payer of the transaction) submits a transfer intent signed by
+/// the user, and collects the relayer fee.
+async fn test_transfer_with_intent(ctx: &mut ProgramTestContext, test_data: &TestData, mode: Mode) {
+    let relayer = ctx.payer.pubkey();
+    let relayer_token_account =
+        get_associated_token_address_with_program_id(&relayer, &test_data.mint, &spl_token::ID);
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &relayer,
+        &test_data.mint,
+        &spl_token::ID,
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    // the user approves their intent authority once
+    spl_token_2022::instruction::approve(
+        &spl_token::ID,
+        &test_data.user_token_account,
+        &good_ntt.intent_authority(&test_data.user.pubkey()),
+        &test_data.user.pubkey(),
+        &[],
+        1000,
+    )
+    .unwrap()
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(&good_ntt, ctx, test_data, outbox_item.pubkey(), 154, false);
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let intent_args = TransferWithIntentArgs {
+        intent: TransferIntent {
+            ntt_manager: example_native_token_transfers::ID,
+            sender: test_data.user.pubkey(),
+            amount: args.amount,
+            recipient_chain: args.recipient_chain,
+            recipient_address: args.recipient_address,
+            should_queue: args.should_queue,
+            nonce: 0,
+            expiry: clock.unix_timestamp + 60,
+            max_relayer_fee: 10,
+        },
+        relayer_fee: 10,
+    };
+
+    // the fee can only be paid to the relayer
+    let err = Transaction::new_with_payer(
+        &[
+            sign_intent(&test_data.user, &intent_args),
+            transfer_with_intent(
+                &good_ntt,
+                accs.clone(),
+                test_data.user_token_account,
+                intent_args.clone(),
+                mode,
+            ),
+        ],
+        Some(&ctx.payer.pubkey()),
+    )
+    .submit_with_signers(&[&outbox_item], ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(NTTError::InvalidRelayerFeeRecipient.into())
+        )
+    );
+
+    Transaction::new_with_payer(
+        &[
+            sign_intent(&test_data.user, &intent_args),
+            transfer_with_intent(
+                &good_ntt,
+                accs.clone(),
+                relayer_token_account,
+                intent_args.clone(),
+                mode,
+            ),
+        ],
+        Some(&ctx.payer.pubkey()),
+    )
+    .submit_with_signers(&[&outbox_item], ctx)
+    .await
+    .unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account.sender, test_data.user.pubkey());
+
+    let relayer_token_account_data: TokenAccount =
+        ctx.get_account_data_anchor(relayer_token_account).await;
+    assert_eq!(relayer_token_account_data.amount, 10);
+
+    // the intent can't be replayed
+    let outbox_item = Keypair::new();
+    let err = Transaction::new_with_payer(
+        &[
+            sign_intent(&test_data.user, &intent_args),
+            transfer_with_intent(
+                &good_ntt,
+                Transfer {
+                    outbox_item: outbox_item.pubkey(),
+                    ..accs
+                },
+                relayer_token_account,
+                intent_args,
+                mode,
+            ),
+        ],
+        Some(&ctx.payer.pubkey()),
+    )
+    .submit_with_signers(&[&outbox_item], ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(NTTError::InvalidIntentNonce.into())
+        )
+    );
+}
+
 /// This tests the happy path of a transfer, with all the relevant account checks.
 /// Written as a helper function so both modes can be tested.
 async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode: Mode) {
@@ -160,6 +549,14 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
             recipient_address: [1u8; 32],
             release_timestamp: clock.unix_timestamp,
             released: Bitmap::new(),
+            queue: QueueMembership::new(),
+            provenance: OutboxProvenance::V1(OutboxProvenanceV1 {
+                requested_amount: 154,
+                created_at: clock.unix_timestamp,
+                payer: ctx.payer.pubkey(),
+                rate_limit_exempt: false,
+                fee: 0,
+            }),
         }
     );
 
@@ -1027,3 +1424,1018 @@ async fn test_cant_release_twice() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_queue_index() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+
+    let too_much = OUTBOUND_LIMIT + 1000;
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        too_much,
+        true,
+    );
+
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert!(outbox_item_account.queue.is_queued());
+
+    let outbound_queue: QueueIndex = ctx.get_account_data_anchor(good_ntt.outbound_queue()).await;
+    assert_eq!(outbound_queue.queued_amount, too_much);
+    assert_eq!(outbound_queue.queued_count, 1);
+    assert_eq!(
+        outbound_queue.entries,
+        vec![QueueEntry {
+            item: outbox_item.pubkey(),
+            amount: too_much,
+            release_timestamp: outbox_item_account.release_timestamp,
+        }]
+    );
+
+    // the transfer can't be removed from the index before it's released
+    let err = dequeue_released_outbound(&good_ntt, outbox_item.pubkey())
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferStillQueued.into())
+        )
+    );
+
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = outbox_item_account.release_timestamp;
+    ctx.set_sysvar(&clock);
+
+    release_outbound(
+        &good_ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert!(!outbox_item_account.queue.is_queued());
+
+    let outbound_queue: QueueIndex = ctx.get_account_data_anchor(good_ntt.outbound_queue()).await;
+    assert_eq!(outbound_queue.queued_amount, 0);
+    assert_eq!(outbound_queue.queued_count, 0);
+    assert!(outbound_queue.entries.is_empty());
+}
+
+#[tokio::test]
+#[allow(clippy::integer_division)]
+async fn test_simulate_transfer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbound: RateLimitCapacity =
+        return_data(query_outbound_capacity(&good_ntt), &mut ctx).await;
+    assert_eq!(
+        outbound,
+        RateLimitCapacity {
+            limit: OUTBOUND_LIMIT,
+            capacity: OUTBOUND_LIMIT,
+        }
+    );
+    let inbound: RateLimitCapacity = return_data(
+        query_inbound_capacity(&good_ntt, ChainId { id: OTHER_CHAIN }),
+        &mut ctx,
+    )
+    .await;
+    assert_eq!(inbound.capacity, INBOUND_LIMIT);
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        154,
+        false,
+    );
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let simulation: TransferSimulation = return_data(
+        simulate_transfer(&good_ntt, test_data.mint, args.clone()),
+        &mut ctx,
+    )
+    .await;
+    assert_eq!(
+        simulation,
+        TransferSimulation {
+            amount: 100,
+            fee: 0,
+            dust: 54,
+            trimmed_amount: TrimmedAmount {
+                amount: 1,
+                decimals: 7
+            },
+            queued: false,
+            release_timestamp: clock.unix_timestamp,
+        }
+    );
+
+    // the transfer does what the simulation predicted
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let status: OutboxItemStatus =
+        return_data(query_outbox_item_status(outbox_item.pubkey()), &mut ctx).await;
+    assert_eq!(
+        status,
+        OutboxItemStatus {
+            release_timestamp: simulation.release_timestamp,
+            releasable: true,
+            released: Bitmap::new(),
+        }
+    );
+
+    let outbound: RateLimitCapacity =
+        return_data(query_outbound_capacity(&good_ntt), &mut ctx).await;
+    assert_eq!(outbound.capacity, OUTBOUND_LIMIT - simulation.amount);
+
+    // transfers over the limit are queued, or rejected when they can't be
+    let too_much = OUTBOUND_LIMIT + 1000;
+    let (_, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        too_much,
+        true,
+    );
+    let simulation: TransferSimulation = return_data(
+        simulate_transfer(&good_ntt, test_data.mint, args.clone()),
+        &mut ctx,
+    )
+    .await;
+    assert!(simulation.queued);
+    // until the capacity has refilled by the missing 1100 (11/100th of the
+    // limit)
+    assert_eq!(
+        simulation.release_timestamp,
+        clock.unix_timestamp + RateLimitState::RATE_LIMIT_DURATION * 11 / 100
+    );
+
+    let err = simulate_transfer(
+        &good_ntt,
+        test_data.mint,
+        TransferArgs {
+            should_queue: false,
+            ..args
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferExceedsRateLimit.into())
+        )
+    );
+}
+
+#[tokio::test]
+#[allow(clippy::integer_division)]
+async fn test_strict_queueing() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_outbound_strict_mode(
+        &good_ntt,
+        SetStrictMode {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let rate_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(good_ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(
+        rate_limit.settings,
+        RateLimitSettings::V1(RateLimitSettingsV1 { strict: true })
+    );
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let day = RateLimitState::RATE_LIMIT_DURATION;
+
+    // 1000 over the limit: released once those have refilled, i.e. after a
+    // tenth of a day
+    let mut release_timestamps = vec![];
+    for amount in [OUTBOUND_LIMIT + 1000, 100] {
+        let outbox_item = Keypair::new();
+        let (accs, args) = init_accs_args(
+            &good_ntt,
+            &mut ctx,
+            &test_data,
+            outbox_item.pubkey(),
+            amount,
+            true,
+        );
+        approve_token_authority(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+        transfer(&good_ntt, accs, args, Mode::Locking)
+            .submit_with_signers(&[&outbox_item], &mut ctx)
+            .await
+            .unwrap();
+
+        assert_queued(&mut ctx, outbox_item.pubkey()).await;
+        let outbox_item_account: OutboxItem =
+            ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+        release_timestamps.push(outbox_item_account.release_timestamp);
+    }
+
+    // the first transfer reserved the whole capacity until its release, so
+    // the second one (which would otherwise fit right away) is queued behind
+    // it
+    assert_eq!(
+        release_timestamps,
+        vec![
+            clock.unix_timestamp + day / 10,
+            clock.unix_timestamp + day / 10 + day / 100
+        ]
+    );
+
+    let outbound: RateLimitCapacity =
+        return_data(query_outbound_capacity(&good_ntt), &mut ctx).await;
+    assert_eq!(outbound.capacity, 0);
+}
+
+#[tokio::test]
+async fn test_large_transfer_security_delay() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_large_transfer_policy(
+        &good_ntt,
+        SetLargeTransferPolicy {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetLargeTransferPolicyArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            policy: LargeTransferPolicy {
+                outbound_threshold: Some(1000),
+                outbound_delay: 2 * 60 * 60,
+                ..Default::default()
+            },
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the large transfer is only delayed if the sender allows it
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        1100,
+        false,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferRequiresSecurityDelay.into())
+        )
+    );
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+
+    // both fit in the rate limit, but only the small one is released right
+    // away
+    for (amount, release_timestamp) in [
+        (1100, clock.unix_timestamp + 2 * 60 * 60),
+        (1000, clock.unix_timestamp),
+    ] {
+        let outbox_item = Keypair::new();
+        let (accs, args) = init_accs_args(
+            &good_ntt,
+            &mut ctx,
+            &test_data,
+            outbox_item.pubkey(),
+            amount,
+            true,
+        );
+
+        let simulation: TransferSimulation = return_data(
+            simulate_transfer(&good_ntt, test_data.mint, args.clone()),
+            &mut ctx,
+        )
+        .await;
+        assert_eq!(simulation.release_timestamp, release_timestamp);
+
+        approve_token_authority(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+        transfer(&good_ntt, accs, args, Mode::Locking)
+            .submit_with_signers(&[&outbox_item], &mut ctx)
+            .await
+            .unwrap();
+
+        let outbox_item_account: OutboxItem =
+            ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+        assert_eq!(outbox_item_account.release_timestamp, release_timestamp);
+    }
+
+    // the delayed transfer still consumed the rate limit
+    let outbound: RateLimitCapacity =
+        return_data(query_outbound_capacity(&good_ntt), &mut ctx).await;
+    assert_eq!(outbound.capacity, OUTBOUND_LIMIT - 2100);
+}
+
+#[tokio::test]
+async fn test_rate_limit_exemption() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let amount = 2 * OUTBOUND_LIMIT;
+
+    set_rate_limit_exemption(
+        &good_ntt,
+        SetRateLimitExemption {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetRateLimitExemptionArgs {
+            sender: test_data.user.pubkey(),
+            limit: 3 * OUTBOUND_LIMIT,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        amount,
+        false,
+    );
+    let accs = Transfer {
+        rate_limit_exempt: true,
+        ..accs
+    };
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    // another sender's exemption can't be used
+    let other_sender = Keypair::new();
+    set_rate_limit_exemption(
+        &good_ntt,
+        SetRateLimitExemption {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetRateLimitExemptionArgs {
+            sender: other_sender.pubkey(),
+            limit: 3 * OUTBOUND_LIMIT,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+    let mut ix = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking);
+    for account in &mut ix.accounts {
+        if account.pubkey == good_ntt.rate_limit_exemption(&test_data.user.pubkey()) {
+            account.pubkey = good_ntt.rate_limit_exemption(&other_sender.pubkey());
+        }
+    }
+    let err = ix
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidRateLimitExemption.into())
+        )
+    );
+
+    // the transfer exceeds the outbound limit, but fits in the sender's own
+    let ix = transfer(&good_ntt, accs, args, Mode::Locking);
+    let events: Vec<OutboundTransfer> = emitted_events(ix.clone(), &[&outbox_item], &mut ctx).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].outbox_item, outbox_item.pubkey());
+    assert_eq!(events[0].amount, amount);
+    assert!(events[0].rate_limit_exempt);
+    ix.submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account.release_timestamp, clock.unix_timestamp);
+    assert_eq!(
+        outbox_item_account.provenance,
+        OutboxProvenance::V1(OutboxProvenanceV1 {
+            requested_amount: amount,
+            created_at: clock.unix_timestamp,
+            payer: ctx.payer.pubkey(),
+            rate_limit_exempt: true,
+            fee: 0,
+        })
+    );
+
+    let outbound: RateLimitCapacity =
+        return_data(query_outbound_capacity(&good_ntt), &mut ctx).await;
+    assert_eq!(outbound.capacity, OUTBOUND_LIMIT);
+    let exemption: RateLimitExemption = ctx
+        .get_account_data_anchor(good_ntt.rate_limit_exemption(&test_data.user.pubkey()))
+        .await;
+    assert_eq!(
+        exemption.rate_limit.capacity_at(clock.unix_timestamp),
+        OUTBOUND_LIMIT
+    );
+
+    // once the exemption is removed, the sender is rate limited again
+    remove_rate_limit_exemption(
+        &good_ntt,
+        RemoveRateLimitExemption {
+            owner: test_data.program_owner.pubkey(),
+        },
+        test_data.user.pubkey(),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        amount - 100,
+        false,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    let err = transfer(&good_ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferExceedsRateLimit.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_protocol_fee() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let fee_owner = Keypair::new();
+    let fee_recipient = get_associated_token_address_with_program_id(
+        &fee_owner.pubkey(),
+        &test_data.mint,
+        &spl_token::ID,
+    );
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &fee_owner.pubkey(),
+        &test_data.mint,
+        &spl_token::ID,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    // 1%
+    set_fee(
+        &good_ntt,
+        SetFee {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            fee_recipient,
+        },
+        SetFeeArgs { fee_bps: 100 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the fee is 100 (rounded down), and the dust of the rest (54) stays
+    // with the sender
+    let amount = 10_054;
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        outbox_item.pubkey(),
+        amount,
+        false,
+    );
+    approve_token_authority(
+        &good_ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let simulation: TransferSimulation = return_data(
+        simulate_transfer(&good_ntt, test_data.mint, args.clone()),
+        &mut ctx,
+    )
+    .await;
+    assert_eq!(simulation.amount, 9900);
+    assert_eq!(simulation.fee, 100);
+    assert_eq!(simulation.dust, 54);
+
+    let err = transfer(&good_ntt, accs.clone(), args.clone(), Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MissingFeeRecipient.into())
+        )
+    );
+
+    let err = transfer(
+        &good_ntt,
+        Transfer {
+            fee_recipient: Some(test_data.user_token_account),
+            ..accs.clone()
+        },
+        args.clone(),
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&outbox_item], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidFeeRecipient.into())
+        )
+    );
+
+    let user_before: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    let custody_before: TokenAccount = ctx
+        .get_account_data_anchor(good_ntt.custody(&test_data.mint))
+        .await;
+
+    let ix = transfer(
+        &good_ntt,
+        Transfer {
+            fee_recipient: Some(fee_recipient),
+            ..accs
+        },
+        args,
+        Mode::Locking,
+    );
+    let events: Vec<OutboundTransfer> = emitted_events(ix.clone(), &[&outbox_item], &mut ctx).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].amount, 9900);
+    assert_eq!(events[0].fee, 100);
+    ix.submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let user_after: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    let custody_after: TokenAccount = ctx
+        .get_account_data_anchor(good_ntt.custody(&test_data.mint))
+        .await;
+    let fee_recipient_account: TokenAccount = ctx.get_account_data_anchor(fee_recipient).await;
+    assert_eq!(user_before.amount - user_after.amount, 10_000);
+    assert_eq!(custody_after.amount - custody_before.amount, 9900);
+    assert_eq!(fee_recipient_account.amount, 100);
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(
+        outbox_item_account.amount,
+        TrimmedAmount {
+            amount: 99,
+            decimals: 7
+        }
+    );
+    assert_eq!(
+        outbox_item_account.provenance,
+        OutboxProvenance::V1(OutboxProvenanceV1 {
+            requested_amount: amount,
+            created_at: clock.unix_timestamp,
+            payer: ctx.payer.pubkey(),
+            rate_limit_exempt: false,
+            fee: 100,
+        })
+    );
+
+    // the peer's override takes precedence over the global fee
+    set_peer_fee(
+        &good_ntt,
+        SetPeerFee {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetPeerFeeArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            fee_bps: Some(0),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let (_, args) = init_accs_args(
+        &good_ntt,
+        &mut ctx,
+        &test_data,
+        Keypair::new().pubkey(),
+        amount,
+        false,
+    );
+    let simulation: TransferSimulation =
+        return_data(simulate_transfer(&good_ntt, test_data.mint, args), &mut ctx).await;
+    assert_eq!(simulation.amount, 10_000);
+    assert_eq!(simulation.fee, 0);
+    assert_eq!(simulation.dust, 54);
+
+    let err = set_fee(
+        &good_ntt,
+        SetFee {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            fee_recipient,
+        },
+        SetFeeArgs { fee_bps: 1_001 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidFee.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_amount_limits() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let payer = ctx.payer.pubkey();
+    let amount_limits = |min_amount, max_amount| {
+        set_amount_limits(
+            &good_ntt,
+            SetAmountLimits {
+                payer,
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetAmountLimitsArgs {
+                chain_id: ChainId { id: OTHER_CHAIN },
+                limits: AmountLimits {
+                    min_amount,
+                    max_amount,
+                    enforce_inbound: false,
+                },
+            },
+        )
+    };
+
+    let err = amount_limits(Some(1000), Some(999))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidAmountLimits.into())
+        )
+    );
+
+    amount_limits(Some(1000), Some(5000))
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    // the limits apply after dust is removed
+    for (amount, error) in [
+        (1054, None),
+        (954, Some(NTTError::TransferBelowMinimum)),
+        (5054, None),
+        (5154, Some(NTTError::TransferAboveMaximum)),
+    ] {
+        let outbox_item = Keypair::new();
+        let (accs, args) = init_accs_args(
+            &good_ntt,
+            &mut ctx,
+            &test_data,
+            outbox_item.pubkey(),
+            amount,
+            false,
+        );
+        approve_token_authority(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+
+        let result = transfer(&good_ntt, accs, args, Mode::Locking)
+            .submit_with_signers(&[&outbox_item], &mut ctx)
+            .await;
+        match error {
+            None => result.unwrap(),
+            Some(error) => assert_eq!(
+                result.unwrap_err().unwrap(),
+                TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+            ),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_strict_dust_mode() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_strict_dust_mode(
+        &good_ntt,
+        SetStrictMode {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // 54 can't be represented at the peer's decimals
+    for (amount, error) in [(154, Some(NTTError::TransferHasDust)), (100, None)] {
+        let outbox_item = Keypair::new();
+        let (accs, args) = init_accs_args(
+            &good_ntt,
+            &mut ctx,
+            &test_data,
+            outbox_item.pubkey(),
+            amount,
+            false,
+        );
+        approve_token_authority(
+            &good_ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+
+        let simulation = simulate_transfer(&good_ntt, test_data.mint, args.clone())
+            .submit(&mut ctx)
+            .await;
+        let result = transfer(&good_ntt, accs, args, Mode::Locking)
+            .submit_with_signers(&[&outbox_item], &mut ctx)
+            .await;
+        match error {
+            None => {
+                simulation.unwrap();
+                result.unwrap();
+            }
+            Some(error) => {
+                let error =
+                    TransactionError::InstructionError(0, InstructionError::Custom(error.into()));
+                assert_eq!(simulation.unwrap_err().unwrap(), error);
+                assert_eq!(result.unwrap_err().unwrap(), error);
+            }
+        }
+    }
+
+    let outbound: RateLimitCapacity =
+        return_data(query_outbound_capacity(&good_ntt), &mut ctx).await;
+    assert_eq!(outbound.capacity, OUTBOUND_LIMIT - 100);
+}
+
+#[tokio::test]
+async fn test_transfer_batch() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let sender = test_data.user.pubkey();
+    let (accs, _) = init_accs_args(&good_ntt, &mut ctx, &test_data, Pubkey::default(), 0, false);
+    let leg = |recipient_chain, recipient_address, amount| TransferLeg {
+        recipient_chain: ChainId {
+            id: recipient_chain,
+        },
+        recipient_address,
+        amount,
+    };
+
+    let err = transfer_batch(
+        &good_ntt,
+        accs.clone(),
+        0,
+        TransferBatchArgs {
+            legs: vec![],
+            should_queue: false,
+        },
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidBatchSize.into())
+        )
+    );
+
+    let user_before: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    let custody_before: TokenAccount = ctx
+        .get_account_data_anchor(good_ntt.custody(&test_data.mint))
+        .await;
+
+    // two legs to the same chain, and one to another
+    let legs = vec![
+        leg(OTHER_CHAIN, [1u8; 32], 154),
+        leg(ANOTHER_CHAIN, [2u8; 32], 1000),
+        leg(OTHER_CHAIN, [3u8; 32], 2000),
+    ];
+    transfer_batch(
+        &good_ntt,
+        accs.clone(),
+        0,
+        TransferBatchArgs {
+            legs: legs.clone(),
+            should_queue: false,
+        },
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    // the tokens of all legs (without dust) are moved at once
+    let user_after: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    let custody_after: TokenAccount = ctx
+        .get_account_data_anchor(good_ntt.custody(&test_data.mint))
+        .await;
+    assert_eq!(user_before.amount - user_after.amount, 3100);
+    assert_eq!(custody_after.amount - custody_before.amount, 3100);
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    for (sequence, (leg, amount)) in (0..).zip(legs.iter().zip([1, 100, 200])) {
+        let outbox_item: OutboxItem = ctx
+            .get_account_data_anchor(good_ntt.sequenced_outbox_item(&sender, sequence))
+            .await;
+        assert_eq!(outbox_item.sender, sender);
+        assert_eq!(outbox_item.recipient_chain, leg.recipient_chain);
+        assert_eq!(outbox_item.recipient_address, leg.recipient_address);
+        assert_eq!(
+            outbox_item.amount,
+            TrimmedAmount {
+                amount,
+                decimals: 7
+            }
+        );
+        assert_eq!(outbox_item.release_timestamp, clock.unix_timestamp);
+    }
+    let sender_sequence: SenderSequence = ctx
+        .get_account_data_anchor(good_ntt.sender_sequence(&sender))
+        .await;
+    assert_eq!(sender_sequence.next_sequence, 3);
+
+    // the rate limit is consumed for the aggregate
+    let outbound: RateLimitCapacity =
+        return_data(query_outbound_capacity(&good_ntt), &mut ctx).await;
+    assert_eq!(outbound.capacity, OUTBOUND_LIMIT - 3100);
+
+    // each leg fits in the remaining capacity, but not both
+    let err = transfer_batch(
+        &good_ntt,
+        accs,
+        3,
+        TransferBatchArgs {
+            legs: vec![
+                leg(OTHER_CHAIN, [1u8; 32], 4000),
+                leg(ANOTHER_CHAIN, [2u8; 32], 4000),
+            ],
+            should_queue: false,
+        },
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferExceedsRateLimit.into())
+        )
+    );
+}
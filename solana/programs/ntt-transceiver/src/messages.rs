@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use example_native_token_transfers::messages::RequestedGasDropoff;
 use ntt_messages::{chain_id::ChainId, transceiver::TransceiverMessageData};
 use std::{collections::HashMap, marker::PhantomData};
 
@@ -7,6 +8,7 @@ use std::{collections::HashMap, marker::PhantomData};
 pub struct ValidatedTransceiverMessage<A: AnchorDeserialize + AnchorSerialize + Space + Clone> {
     pub from_chain: ChainId,
     pub message: TransceiverMessageData<A>,
+    pub gas_dropoff: RequestedGasDropoff,
 }
 
 impl<A: AnchorDeserialize + AnchorSerialize + Space + Clone> ValidatedTransceiverMessage<A> {
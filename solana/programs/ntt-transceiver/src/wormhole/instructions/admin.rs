@@ -1,6 +1,12 @@
 use crate::peer::TransceiverPeer;
 use anchor_lang::prelude::*;
-use example_native_token_transfers::config::Config;
+use example_native_token_transfers::{
+    audit_log::{AuditAction, AuditLog},
+    config::Config,
+    instructions::AUDIT_LOG_SIGNER_SEED,
+    program::ExampleNativeTokenTransfers,
+    registered_transceiver::RegisteredTransceiver,
+};
 use ntt_messages::chain_id::ChainId;
 
 #[derive(Accounts)]
@@ -26,6 +32,33 @@ pub struct SetTransceiverPeer<'info> {
     pub peer: Account<'info, TransceiverPeer>,
 
     pub system_program: Program<'info, System>,
+
+    // NOTE: the accounts below are only needed to record the change in the
+    // manager's audit log
+    #[account(
+        constraint = transceiver.transceiver_address == crate::ID,
+    )]
+    pub transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+        seeds::program = manager.key(),
+    )]
+    /// CHECK: the seeds constraint ensures this is the manager's audit log.
+    /// It's required even before the log is created, so that it can't be
+    /// skipped once it exists.
+    pub audit_log: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [AUDIT_LOG_SIGNER_SEED],
+        bump
+    )]
+    /// CHECK: this PDA is used to sign the CPI into NTT manager program
+    pub audit_log_signer: UncheckedAccount<'info>,
+
+    pub manager: Program<'info, ExampleNativeTokenTransfers>,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -43,5 +76,32 @@ pub fn set_transceiver_peer(
         address: args.address,
     });
 
+    let accs = ctx.accounts;
+    if accs.audit_log.data_is_empty() {
+        return Ok(());
+    }
+
+    // NOTE: the peer account is created here (and can't be overwritten), so
+    // there is no previous state to record
+    example_native_token_transfers::cpi::record_transceiver_admin_action(
+        CpiContext::new_with_signer(
+            accs.manager.to_account_info(),
+            example_native_token_transfers::cpi::accounts::RecordTransceiverAdminAction {
+                signer: accs.audit_log_signer.to_account_info(),
+                config: accs.config.to_account_info(),
+                owner: accs.owner.to_account_info(),
+                transceiver: accs.transceiver.to_account_info(),
+                audit_log: accs.audit_log.to_account_info(),
+            },
+            &[&[AUDIT_LOG_SIGNER_SEED, &[ctx.bumps.audit_log_signer]]],
+        ),
+        example_native_token_transfers::instructions::RecordTransceiverAdminActionArgs {
+            action: AuditAction::SetWormholePeer,
+            subject: accs.peer.key(),
+            before: AuditLog::digest(&[0u8; 32])?,
+            after: AuditLog::digest(&args.address)?,
+        },
+    )?;
+
     Ok(())
 }
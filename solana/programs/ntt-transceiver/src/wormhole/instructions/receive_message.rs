@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use example_native_token_transfers::{
     config::{anchor_reexports::*, *},
     error::NTTError,
+    messages::RequestedGasDropoff,
     transfer::Payload,
 };
 use ntt_messages::{
@@ -64,12 +65,16 @@ pub struct ReceiveMessage<'info> {
 
 pub fn receive_message(ctx: Context<ReceiveMessage>) -> Result<()> {
     let message = ctx.accounts.vaa.message().message_data.clone();
+    let gas_dropoff = RequestedGasDropoff::from_transceiver_payload(
+        &ctx.accounts.vaa.message().transceiver_payload,
+    );
     let chain_id = ctx.accounts.vaa.emitter_chain();
     ctx.accounts
         .transceiver_message
         .set_inner(ValidatedTransceiverMessage {
             from_chain: ChainId { id: chain_id },
             message,
+            gas_dropoff,
         });
 
     Ok(())
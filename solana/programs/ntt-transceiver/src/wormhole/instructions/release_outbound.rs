@@ -61,6 +61,10 @@ pub struct ReleaseOutbound<'info> {
     )]
     /// CHECK: this PDA is used to sign the CPI into NTT manager program
     pub outbox_item_signer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: the manager's outbound queue index, checked by the manager
+    pub outbound_queue: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> ReleaseOutbound<'info> {
@@ -75,6 +79,10 @@ impl<'info> ReleaseOutbound<'info> {
                     },
                     outbox_item: self.outbox_item.to_account_info(),
                     transceiver: self.transceiver.to_account_info(),
+                    outbound_queue: self
+                        .outbound_queue
+                        .as_ref()
+                        .map(|outbound_queue| outbound_queue.to_account_info()),
                 },
                 // signer seeds
                 &[&[OUTBOX_ITEM_SIGNER_SEED, &[bump_seed]]],
@@ -36,7 +36,7 @@ import {
  * Test Config Constants
  */
 const SOLANA_ROOT_DIR = `${__dirname}/../`;
-const VERSION: IdlVersion = "3.0.0";
+const VERSION: IdlVersion = "4.0.0";
 const TOKEN_PROGRAM = spl.TOKEN_2022_PROGRAM_ID;
 const GUARDIAN_KEY =
   "cfb12303a19cde580bb4dd771639b0d26bc68353645571a8cff516ab2ee113a0";
@@ -563,7 +563,7 @@ describe("example-native-token-transfers", () => {
           { ntt: overrides["Solana"] },
           payerAddress
         );
-        expect(version).toBe("3.0.0");
+        expect(version).toBe("4.0.0");
       });
 
       test("It initializes using `emitterAccount` as transceiver address", async () => {
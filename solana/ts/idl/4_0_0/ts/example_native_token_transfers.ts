@@ -0,0 +1,16461 @@
+export type ExampleNativeTokenTransfers = {
+  "version": "4.0.0",
+  "name": "example_native_token_transfers",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "deployer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "In any case, this function is used to set the Config and initialize the program so we",
+            "assume the caller of this function will have total control over the program.",
+            "",
+            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
+            "Could refactor code to use `Box<_>` to reduce stack size."
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account that holds tokens in locking mode and temporarily",
+            "holds tokens in burning mode.",
+            "function if the token account has already been created."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "associated token account for the given mint."
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeLut",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entries",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormhole",
+              "accounts": [
+                {
+                  "name": "bridge",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "feeCollector",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "sequence",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "program",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "systemProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "clock",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "rent",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeAuditLog",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeDirectory",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncDirectoryPeer",
+      "accounts": [
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`chain_id`. It's deserialized in [`sync_directory_peer`] if it exists."
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ]
+    },
+    {
+      "name": "syncDirectoryTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeOutboundQueue",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeInboundQueue",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "inboundQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ]
+    },
+    {
+      "name": "dequeueReleasedOutbound",
+      "accounts": [
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "dequeueReleasedInbound",
+      "accounts": [
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: checked against the inbox item's queue membership in",
+            "[`QueueIndex::dequeue`]"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveLargeInbound",
+      "accounts": [
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveLargeInboundByOwner",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recordTransceiverAdminAction",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RecordTransceiverAdminActionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "version",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "queryOutboundCapacity",
+      "accounts": [
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "RateLimitCapacity"
+      }
+    },
+    {
+      "name": "queryInboundCapacity",
+      "accounts": [
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "RateLimitCapacity"
+      }
+    },
+    {
+      "name": "queryOutboxItemStatus",
+      "accounts": [
+        {
+          "name": "outboxItem",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "OutboxItemStatus"
+      }
+    },
+    {
+      "name": "queryInboxItemStatus",
+      "accounts": [
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "InboxItemStatus"
+      }
+    },
+    {
+      "name": "simulateTransfer",
+      "accounts": [
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimitExemption",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The sender's exemption from the outbound rate limit, to simulate a",
+            "transfer that draws from it instead."
+          ],
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "TransferSimulation"
+      }
+    },
+    {
+      "name": "transferBurn",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnDirect",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of `common.from`, or its delegate. The spl token program",
+            "checks that it can spend the tokens."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockDirect",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of `common.from`, or its delegate. The spl token program",
+            "checks that it can spend the tokens."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnSequenced",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "these tokens."
+              ]
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": true,
+              "docs": [
+                "The owner of `from`, or its delegate. The spl token program checks that",
+                "it can spend the tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "senderSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockSequenced",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "these tokens."
+              ]
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": true,
+              "docs": [
+                "The owner of `from`, or its delegate. The spl token program checks that",
+                "it can spend the tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "senderSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnBatch",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "these tokens."
+              ]
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": true,
+              "docs": [
+                "The owner of `from`, or its delegate. The spl token program checks that",
+                "it can spend the tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "senderSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded with [`QueueIndex::load`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the legs are inserted into the",
+                "outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when any",
+                "leg is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferBatchArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockBatch",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "these tokens."
+              ]
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": true,
+              "docs": [
+                "The owner of `from`, or its delegate. The spl token program checks that",
+                "it can spend the tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "senderSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded with [`QueueIndex::load`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the legs are inserted into the",
+                "outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when any",
+                "leg is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferBatchArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnWithIntent",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "intentNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "intentAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::intent::INTENT_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "relayerFeeRecipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The relayer fee goes to a token account owned by the relayer, i.e. the",
+            "payer of the transaction, so that the fee can't be redirected by",
+            "someone front-running the relayer with the same intent."
+          ]
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferWithIntentArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockWithIntent",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "intentNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "intentAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::intent::INTENT_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "relayerFeeRecipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The relayer fee goes to a token account owned by the relayer, i.e. the",
+            "payer of the transaction, so that the fee can't be redirected by",
+            "someone front-running the relayer with the same intent."
+          ]
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferWithIntentArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NOTE: checked against the message in [`Redeem::check_message`]"
+          ]
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`Account<T>` and `owner` constraints are mutually-exclusive. It's",
+            "deserialized (and its owner checked) exactly once, in [`redeem`], and",
+            "the other accounts are checked against it there rather than in",
+            "constraints, which would each parse it again.",
+            "NOTE: we don't replay protect VAAs. Instead, we replay protect",
+            "executing the messages themselves with the [`released`] flag."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
+            "This is because in a multi-transceiver configuration, the different",
+            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
+            "items content-addressed, we can ensure that disagreeing votes don't",
+            "interfere with each other.",
+            "[`Redeem::load_inbox_item`]. On the first call to [`redeem()`], the",
+            "[`InboxItem`] is allocated and initialized there; on subsequent calls,",
+            "it's loaded so that it can be \"voted\" on."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: checked against the message in [`Redeem::check_message`]"
+          ]
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "loaded with [`QueueIndex::load`] if it has been created"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipientAddress",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "the transfer"
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: checked against the inbox item's queue membership in",
+                "[`QueueIndex::dequeue`]. Required when the inbox item is queued in it."
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Required for transfers released in tranches, which consume the",
+                "inbound rate limit of their source chain as they're released."
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Required for transfers released in tranches, whose tranches flow back",
+                "into the outbound rate limit."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipientAddress",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "the transfer"
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: checked against the inbox item's queue membership in",
+                "[`QueueIndex::dequeue`]. Required when the inbox item is queued in it."
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Required for transfers released in tranches, which consume the",
+                "inbound rate limit of their source chain as they're released."
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Required for transfers released in tranches, whose tranches flow back",
+                "into the outbound rate limit."
+              ],
+              "isOptional": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deliverGasDropoff",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientAddress",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "the transfer"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptInboundToTokenAccount",
+      "accounts": [
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reportFailedInboundRelease",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientAddress",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "transfer is released to, which may not exist yet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestInboundEscrow",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redirectEscrowedInbound",
+      "accounts": [
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setEscrowRecovery",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseEscrowedInboundMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "escrow",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseEscrowedInboundUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "escrow",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnershipOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelOwnershipTransfer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTokenAuthorityFromMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthorityOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingTokenAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revertTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExpiredPendingTokenAuthority",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingTokenAuthority",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthorityToMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removePeer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "used here that wraps the Transceiver account type."
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deregisterTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setOutboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundStrictMode",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "strict",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setStrictDustMode",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "strict",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setInboundStrictMode",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundStrictModeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRateLimitExemption",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "exemption",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRateLimitExemptionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeRateLimitExemption",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "exemption",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "sender",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setBackflow",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetBackflowArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPeerAddressFormat",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerAddressFormatArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLargeTransferPolicy",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetLargeTransferPolicyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFee",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token account that receives the fees."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetFeeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPeerFee",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerFeeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAmountLimits",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetAmountLimitsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "markOutboxItemAsReleased",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [],
+      "returns": "bool"
+    },
+    {
+      "name": "setThreshold",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initializeCouncil",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "council",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetCouncilMembersArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCouncilMembers",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "council",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetCouncilMembersArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeCouncilInstruction",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The proposing member. Pays for the proposal account, and is refunded",
+            "when it's closed."
+          ]
+        },
+        {
+          "name": "council",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "councilInstruction",
+          "type": {
+            "defined": "CouncilInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveCouncilProposal",
+      "accounts": [
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "council",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeCouncilProposal",
+      "accounts": [
+        {
+          "name": "councilPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`COUNCIL_PAYER`]. Its lamports are only spent by approved proposals."
+          ]
+        },
+        {
+          "name": "council",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeCouncilProposal",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "council",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "receiveAndReleaseWormholeMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "peer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "transceiverPeer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "vaa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "transceiver",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: same address as in [`crate::instructions::Redeem`], so a transfer",
+                "can't be redeemed through both."
+              ]
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipientAddress",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "the transfer"
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "the source chain, which is loaded with [`QueueIndex::load`] if it has",
+                "been created"
+              ]
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveAndReleaseWormholeUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "peer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "transceiverPeer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "vaa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "transceiver",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: same address as in [`crate::instructions::Redeem`], so a transfer",
+                "can't be redeemed through both."
+              ]
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipientAddress",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "the transfer"
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "the source chain, which is loaded with [`QueueIndex::load`] if it has",
+                "been created"
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwnerExpiry",
+            "docs": [
+              "Timestamp after which [`Config::pending_owner`] can no longer claim",
+              "ownership. See [`crate::PENDING_AUTHORITY_VALIDITY`].",
+              "",
+              "NOTE: this field was appended after the initial release. Configs",
+              "created before then have enough slack at the end (as `pending_owner` is",
+              "always `None` when the program is upgradeable) to deserialize it, and",
+              "are grown when the next ownership transfer is initiated. The value is",
+              "only meaningful while `pending_owner` is set."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "NOTE: this field was appended after [`Config::pending_owner_expiry`].",
+              "Configs created before then are grown (see",
+              "[`Config::materialize_records`]) when the fee is first set or an",
+              "ownership transfer is initiated. Until then they may end in stale",
+              "bytes left by a previous `pending_owner`, which are read as",
+              "unavailable."
+            ],
+            "type": {
+              "defined": "FeeSettings"
+            }
+          },
+          {
+            "name": "dust",
+            "docs": [
+              "NOTE: this field was appended after [`Config::fees`], and must remain",
+              "the last one. Configs created before then are grown along with",
+              "[`Config::fees`]."
+            ],
+            "type": {
+              "defined": "DustSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LUT",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "settings",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that",
+              "peers created before then can still be read. Fields appended after it",
+              "must be trailing versioned records too."
+            ],
+            "type": {
+              "defined": "PeerSettings"
+            }
+          },
+          {
+            "name": "largeTransfers",
+            "docs": [
+              "NOTE: this field was appended after [`NttManagerPeer::settings`]."
+            ],
+            "type": {
+              "defined": "LargeTransferSettings"
+            }
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "NOTE: this field was appended after",
+              "[`NttManagerPeer::large_transfers`]."
+            ],
+            "type": {
+              "defined": "PeerFeeSettings"
+            }
+          },
+          {
+            "name": "amountLimits",
+            "docs": [
+              "NOTE: this field was appended after [`NttManagerPeer::fee`], and must",
+              "remain the last one."
+            ],
+            "type": {
+              "defined": "AmountLimitSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingTokenAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "See [`PendingTokenAuthorityExpiry`]."
+            ],
+            "type": {
+              "defined": "PendingTokenAuthorityExpiry"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "init",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "gasDropoff",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that inbox",
+              "items created before then can still be read. Fields appended after it",
+              "must be trailing versioned records too."
+            ],
+            "type": {
+              "defined": "InboxGasDropoff"
+            }
+          },
+          {
+            "name": "queue",
+            "docs": [
+              "NOTE: this field was appended after [`InboxItem::gas_dropoff`]. It's",
+              "only ever set on items that have the previous records."
+            ],
+            "type": {
+              "defined": "QueueMembership"
+            }
+          },
+          {
+            "name": "provenance",
+            "docs": [
+              "NOTE: this field was appended after [`InboxItem::queue`]. Like it, it's",
+              "only ever set on items that have the previous records."
+            ],
+            "type": {
+              "defined": "InboxProvenance"
+            }
+          },
+          {
+            "name": "tranches",
+            "docs": [
+              "NOTE: this field was appended after [`InboxItem::provenance`]. Like it,",
+              "it's only ever set on items that have the previous records."
+            ],
+            "type": {
+              "defined": "InboxTranches"
+            }
+          },
+          {
+            "name": "tokenAccountRecipient",
+            "docs": [
+              "NOTE: this field was appended after [`InboxItem::tranches`], and must",
+              "remain the last one. Like it, it's only ever set on items that have the",
+              "previous records."
+            ],
+            "type": {
+              "defined": "InboxTokenAccountRecipient"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          },
+          {
+            "name": "settings",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that rate",
+              "limits created before then can still be read."
+            ],
+            "type": {
+              "defined": "RateLimitSettings"
+            }
+          },
+          {
+            "name": "backflow",
+            "docs": [
+              "NOTE: this field was appended after `settings`, and must remain the",
+              "last one. It's only ever set on accounts that have the settings."
+            ],
+            "type": {
+              "defined": "BackflowSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The account that authorised the transfer. This is the owner of the",
+              "source token account for [`crate::instructions::transfer_burn`] and",
+              "[`crate::instructions::transfer_lock`], and the signing authority (which",
+              "may be a program PDA) for the `transfer_*_direct` and",
+              "`transfer_*_sequenced` variants."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "queue",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that",
+              "outbox items created before then can still be read. Fields appended",
+              "after it must be trailing versioned records too."
+            ],
+            "type": {
+              "defined": "QueueMembership"
+            }
+          },
+          {
+            "name": "provenance",
+            "docs": [
+              "NOTE: this field was appended after [`OutboxItem::queue`], and must",
+              "remain the last one."
+            ],
+            "type": {
+              "defined": "OutboxProvenance"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          },
+          {
+            "name": "settings",
+            "docs": [
+              "NOTE: this field was appended after the initial release, and must",
+              "remain the last one, so that rate limits created before then can still",
+              "be read."
+            ],
+            "type": {
+              "defined": "RateLimitSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auditLog",
+      "docs": [
+        "Ring buffer of the most recent administrative actions.",
+        "",
+        "Every instruction in [`crate::instructions::admin`] (and the transceiver",
+        "admin instructions) appends an entry here, so that monitoring can",
+        "reconstruct recent configuration changes from a single account fetch,",
+        "without access to historical transactions.",
+        "",
+        "The log is a required account of those instructions, even in deployments",
+        "that haven't created it yet, so that once it's created, no action can skip",
+        "it (see [`AuditLog::record_into`]).",
+        "",
+        "Entries are stored in insertion order until the buffer is full, after which",
+        "`head` points at the oldest entry (which is the next one to be overwritten)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "head",
+            "docs": [
+              "Index into `entries` at which the next entry will be written."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "totalEntries",
+            "docs": [
+              "Total number of entries ever recorded (including overwritten ones)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "AuditLogEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "directory",
+      "docs": [
+        "Lists the peers ([`crate::peer::NttManagerPeer`]) and registered",
+        "transceivers ([`crate::registered_transceiver::RegisteredTransceiver`]) of",
+        "the deployment, so that clients can discover them with a single account",
+        "fetch instead of `getProgramAccounts`.",
+        "",
+        "The directory is passed to the admin instructions that modify peers and",
+        "transceivers as a trailing optional account. Unlike the",
+        "[`crate::audit_log::AuditLog`], skipping it loses nothing: entries that",
+        "are missing or stale (e.g. for deployments that predate the directory) can",
+        "be fixed permissionlessly with",
+        "[`crate::instructions::sync_directory_peer`] and",
+        "[`crate::instructions::sync_directory_transceiver`].",
+        "",
+        "Peers are sorted by chain id, and transceivers by id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "peers",
+            "type": {
+              "vec": {
+                "defined": "DirectoryPeer"
+              }
+            }
+          },
+          {
+            "name": "transceivers",
+            "type": {
+              "vec": {
+                "defined": "DirectoryTransceiver"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "queueIndex",
+      "docs": [
+        "Transfers delayed by a rate limit, and not released yet.",
+        "",
+        "There is one index for outbound transfers (seeded by",
+        "[`QueueIndex::OUTBOUND_SEED_PREFIX`]), and one per chain for inbound",
+        "transfers (seeded by [`QueueIndex::INBOUND_SEED_PREFIX`] and the chain id).",
+        "Transfers are added when the rate limit delays them, and removed when",
+        "they're released.",
+        "",
+        "The instructions that queue transfers (transfer, redeem and",
+        "receive-and-release) take the index as a required account, even before",
+        "it's created (see [`QueueIndex::load`]). Once it is, every",
+        "transfer they queue is tracked. Inbound releases must pass the index the",
+        "inbox item is queued in. Outbound releases (which transceivers perform)",
+        "take it as an optional trailing account: transfers released without it",
+        "stay in the index until they're removed with",
+        "[`crate::instructions::dequeue_released_outbound`]. Transfers released",
+        "before the inbound index was required are removed with",
+        "[`crate::instructions::dequeue_released_inbound`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "queuedAmount",
+            "docs": [
+              "Total amount of the queued transfers."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "queuedCount",
+            "docs": [
+              "Number of queued transfers."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "entries",
+            "docs": [
+              "The queued transfers, as long as there is room for them (see",
+              "[`QUEUE_INDEX_CAPACITY`])."
+            ],
+            "type": {
+              "vec": {
+                "defined": "QueueEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rateLimitExemption",
+      "docs": [
+        "An owner-managed exemption of a sender from the outbound rate limit.",
+        "Transfers by the sender that pass this account draw from its dedicated",
+        "rate limit instead, so that large institutional transfers don't queue up",
+        "behind (or crowd out) everyone else's.",
+        "SECURITY: must check the sender (since there are multiple PDAs, namely one",
+        "for each sender.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "senderSequence",
+      "docs": [
+        "The next sequence number of a sender, used to derive the address of its next",
+        "PDA outbox item. Since the message id is the outbox item's address, this",
+        "makes message ids predictable, and lets clients enumerate a sender's",
+        "transfers."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nextSequence",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "intentNonce",
+      "docs": [
+        "Tracks the next valid intent nonce of a sender. Intents have to be executed",
+        "in nonce order, and each nonce can only be used once."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nextNonce",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxEscrow",
+      "docs": [
+        "Tracks an inbound transfer whose recipient can't receive the tokens (e.g.",
+        "because their token account is frozen). Seeded by the",
+        "[`super::inbox::InboxItem`] it belongs to.",
+        "",
+        "Once the inbox item is [`super::inbox::ReleaseStatus::Escrowed`] (after",
+        "[`InboxEscrow::MAX_FAILED_ATTEMPTS`] failed attempts, or at the recipient's",
+        "request), it can no longer be released to the recipient's associated token",
+        "account. Instead, the tokens are released to `destination`, which is either",
+        "chosen by the recipient (effective immediately), or by the owner among the",
+        "recipient's token accounts (effective after",
+        "[`InboxEscrow::RECOVERY_TIMELOCK`]). The recipient can override the owner's",
+        "choice until then."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "inboxItem",
+            "type": "publicKey"
+          },
+          {
+            "name": "failedAttempts",
+            "type": "u8"
+          },
+          {
+            "name": "lastFailedAttempt",
+            "type": "i64"
+          },
+          {
+            "name": "destination",
+            "docs": [
+              "The token account the escrowed tokens are released to."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "destinationEffectiveAt",
+            "docs": [
+              "Unix timestamp from which the tokens can be released to `destination`."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "council",
+      "docs": [
+        "An M-of-N council that can act as the owner of the program.",
+        "",
+        "The council account is a PDA, so it can be set as [`crate::config::Config::owner`]",
+        "(through the regular [`crate::instructions::transfer_ownership`] and",
+        "[`crate::instructions::claim_ownership`] flow). From then on, admin",
+        "instructions are executed by the program signing for the council PDA once",
+        "`threshold` of its `members` have approved a [`CouncilProposal`].",
+        "",
+        "Since the council PDA claims ownership as a signer, the upgrade authority of",
+        "the program is transferred to it too, and upgrades are executed the same",
+        "way as any other proposal."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "members",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Number of member approvals required to execute a proposal."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "Incremented every time the members or the threshold change. Proposals",
+              "created under a previous generation can no longer be approved or",
+              "executed, as their approvals refer to member indices of the old set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "nextProposalId",
+            "docs": [
+              "The id of the next [`CouncilProposal`]."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "councilProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "The [`Council::generation`] the proposal was created under."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "proposer",
+            "docs": [
+              "The member that created the proposal. It also paid the rent, which is",
+              "refunded when the proposal is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "approvals",
+            "docs": [
+              "Bitmap of [`Council::members`] indices that approved the proposal."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          },
+          {
+            "name": "instruction",
+            "type": {
+              "defined": "CouncilInstruction"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "tokenDecimals",
+            "docs": [
+              "The token decimals on the peer chain."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "Mode"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "ReleaseInboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertWhenNotReady",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseStatus",
+      "docs": [
+        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
+        "such, this must be used as a state machine that moves forward in a linear manner. A state",
+        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
+        "`ReleaseAfter`).",
+        "",
+        "`Escrowed` is an alternative path from `ReleaseAfter` to `Released`, for",
+        "transfers that can't be delivered to the recipient. See",
+        "[`super::escrow::InboxEscrow`].",
+        "",
+        "`AwaitingGuardian` comes before `ReleaseAfter` for large transfers that need",
+        "the approval of the peer's guardian (see",
+        "[`crate::peer::LargeTransferPolicy`]). It holds the release timestamp set",
+        "by the rate limit.",
+        "",
+        "NOTE: new variants must only ever be appended, as the discriminant is",
+        "stored on-chain."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotApproved"
+          },
+          {
+            "name": "ReleaseAfter",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "Released"
+          },
+          {
+            "name": "Escrowed"
+          },
+          {
+            "name": "AwaitingGuardian",
+            "fields": [
+              "i64"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "The maximum capacity of the rate limiter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed (unless the rate limit is strict, see",
+              "[`RateLimitSettingsV1::strict`]).",
+              "",
+              "In strict mode, this can be in the future: the capacity up to then is",
+              "reserved by queued transfers."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RecordTransceiverAdminActionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "action",
+            "type": {
+              "defined": "AuditAction"
+            }
+          },
+          {
+            "name": "subject",
+            "type": "publicKey"
+          },
+          {
+            "name": "before",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "after",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditAction",
+      "docs": [
+        "The administrative action recorded by an [`AuditLogEntry`].",
+        "",
+        "NOTE: new variants must only ever be appended, as the discriminant is",
+        "stored on-chain."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SetPeer"
+          },
+          {
+            "name": "RegisterTransceiver"
+          },
+          {
+            "name": "DeregisterTransceiver"
+          },
+          {
+            "name": "SetOutboundLimit"
+          },
+          {
+            "name": "SetInboundLimit"
+          },
+          {
+            "name": "SetPaused"
+          },
+          {
+            "name": "SetThreshold"
+          },
+          {
+            "name": "TransferOwnership"
+          },
+          {
+            "name": "TransferOwnershipOneStepUnchecked"
+          },
+          {
+            "name": "ClaimOwnership"
+          },
+          {
+            "name": "AcceptTokenAuthority"
+          },
+          {
+            "name": "AcceptTokenAuthorityFromMultisig"
+          },
+          {
+            "name": "SetTokenAuthority"
+          },
+          {
+            "name": "SetTokenAuthorityOneStepUnchecked"
+          },
+          {
+            "name": "RevertTokenAuthority"
+          },
+          {
+            "name": "ClaimTokenAuthority"
+          },
+          {
+            "name": "ClaimTokenAuthorityToMultisig"
+          },
+          {
+            "name": "SetWormholePeer"
+          },
+          {
+            "name": "InitializeCouncil"
+          },
+          {
+            "name": "SetCouncilMembers"
+          },
+          {
+            "name": "CancelOwnershipTransfer"
+          },
+          {
+            "name": "SetEscrowRecovery"
+          },
+          {
+            "name": "RemovePeer"
+          },
+          {
+            "name": "SetOutboundStrictMode"
+          },
+          {
+            "name": "SetInboundStrictMode"
+          },
+          {
+            "name": "SetLargeTransferPolicy"
+          },
+          {
+            "name": "ApproveLargeInbound"
+          },
+          {
+            "name": "SetBackflow"
+          },
+          {
+            "name": "SetRateLimitExemption"
+          },
+          {
+            "name": "RemoveRateLimitExemption"
+          },
+          {
+            "name": "SetFee"
+          },
+          {
+            "name": "SetPeerFee"
+          },
+          {
+            "name": "SetAmountLimits"
+          },
+          {
+            "name": "SetStrictDustMode"
+          },
+          {
+            "name": "SetPeerAddressFormat"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitCapacity",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "capacity",
+            "docs": [
+              "The amount that can be transferred right now without being queued."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "releasable",
+            "docs": [
+              "Whether the release timestamp has passed."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "released",
+            "docs": [
+              "The transceivers that have released the transfer."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "votes",
+            "docs": [
+              "The transceivers that have voted for the transfer."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releasable",
+            "docs": [
+              "Whether the transfer can be released right now (see",
+              "[`InboxItem::check_releasable`])."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferSimulation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "The amount that would be taken from the sender, after deducting the",
+              "protocol fee and removing dust."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "The protocol fee that would be paid to the fee recipient."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "dust",
+            "docs": [
+              "The dust that would be left with the sender."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "trimmedAmount",
+            "docs": [
+              "The amount in the message."
+            ],
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "queued",
+            "docs": [
+              "Whether the transfer would be queued, by the outbound rate limit or the",
+              "peer's large transfer delay."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "releaseTimestamp",
+            "docs": [
+              "When the transfer could be released: now, unless it's queued."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferBatchArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "legs",
+            "type": {
+              "vec": {
+                "defined": "TransferLeg"
+              }
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferLeg",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferWithIntentArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "intent",
+            "type": {
+              "defined": "TransferIntent"
+            }
+          },
+          {
+            "name": "relayerFee",
+            "docs": [
+              "The fee paid to the relayer, at most `intent.max_relayer_fee`."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferIntent",
+      "docs": [
+        "A transfer intent signed by `sender`. The signed message is the borsh",
+        "serialization of this struct (see [`TransferIntent::message`])."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nttManager",
+            "docs": [
+              "The NTT manager program the intent is for. Prevents the same intent from",
+              "being replayed against other deployments."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The owner of the token account the tokens are transferred from."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount to transfer (excluding the relayer fee)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Must match the sender's [`IntentNonce::next_nonce`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the intent can no longer be executed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxRelayerFee",
+            "docs": [
+              "The maximum fee (in the bridged token) the relayer may charge, on top of",
+              "`amount`."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundStrictModeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "strict",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRateLimitExemptionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "limit",
+            "docs": [
+              "The limit of the sender's dedicated rate limit."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetBackflowArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "outbound",
+            "docs": [
+              "Backflow of transfers sent to the chain, into its inbound rate limit."
+            ],
+            "type": {
+              "defined": "Backflow"
+            }
+          },
+          {
+            "name": "inbound",
+            "docs": [
+              "Backflow of transfers received from the chain, into the outbound rate",
+              "limit."
+            ],
+            "type": {
+              "defined": "Backflow"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Backflow",
+      "docs": [
+        "How much of a transfer flows back into the rate limit of the opposite",
+        "direction (\"backflow\"): sending a transfer to a chain refills that chain's",
+        "inbound rate limit, and receiving one refills the outbound rate limit."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Disabled"
+          },
+          {
+            "name": "Full"
+          },
+          {
+            "name": "Percentage",
+            "fields": [
+              "u8"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerAddressFormatArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "addressFormat",
+            "docs": [
+              "The format of recipient addresses on the peer chain."
+            ],
+            "type": {
+              "defined": "AddressFormat"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddressFormat",
+      "docs": [
+        "How addresses of a chain are encoded in the 32-byte universal address",
+        "format.",
+        "",
+        "Peers configured before the format was added read as",
+        "[`AddressFormat::Bytes32`], which accepts any non-zero address."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Bytes32"
+          },
+          {
+            "name": "Evm"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetLargeTransferPolicyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "policy",
+            "type": {
+              "defined": "LargeTransferPolicy"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LargeTransferPolicy",
+      "docs": [
+        "Extra checks on large transfers to and from a peer, on top of the rate",
+        "limits. Both are disabled by default."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outboundThreshold",
+            "docs": [
+              "Outbound transfers of more than this amount (after removing dust) can",
+              "only be released [`LargeTransferPolicy::outbound_delay`] seconds after",
+              "they're sent, even if the rate limit has capacity for them."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "outboundDelay",
+            "type": "i64"
+          },
+          {
+            "name": "inboundThreshold",
+            "docs": [
+              "Inbound transfers of more than this amount can only be released once",
+              "[`LargeTransferPolicy::guardian`] has approved them (see",
+              "[`crate::instructions::approve_large_inbound`])."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "guardian",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetFeeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerFeeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "feeBps",
+            "docs": [
+              "The fee on transfers to the peer, in basis points, or `None` to charge",
+              "the global fee."
+            ],
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetAmountLimitsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "limits",
+            "type": {
+              "defined": "AmountLimits"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AmountLimits",
+      "docs": [
+        "Bounds on the amount of transfers to and from a peer, after the protocol",
+        "fee is deducted and dust is removed (in the mint's decimals). Transfers",
+        "outside of them are rejected, instead of being queued. Both are disabled",
+        "by default."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "enforceInbound",
+            "docs": [
+              "Whether inbound transfers are checked too. Since the tokens have",
+              "already left the peer chain by then, rejected inbound transfers can't",
+              "be redeemed until the limits are relaxed."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetCouncilMembersArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CouncilInstruction",
+      "docs": [
+        "The instruction to be executed by a [`CouncilProposal`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": "CouncilAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CouncilAccountMeta",
+      "docs": [
+        "A copy of [`solana_program::instruction::AccountMeta`] with",
+        "`AnchorSerialize`/`AnchorDeserialize` impls."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettings",
+      "docs": [
+        "The protocol fee charged on outbound transfers, set by",
+        "[`crate::instructions::set_fee`]. Peers can override the rate (see",
+        "[`crate::peer::PeerFeeSettingsV1::fee_bps`]).",
+        "[`FeeSettings::Unavailable`] means no fee."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "FeeSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "docs": [
+              "The fee, in basis points of the transferred amount."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "The token account (of the managed mint) that receives the fees."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DustSettings",
+      "docs": [
+        "How outbound transfers handle dust (the part of the amount that can't be",
+        "represented at the trimmed decimals), set by",
+        "[`crate::instructions::set_strict_dust_mode`].",
+        "[`DustSettings::Unavailable`] means the defaults."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "DustSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "DustSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "strict",
+            "docs": [
+              "Whether outbound transfers with dust are rejected. Otherwise (the",
+              "default) the dust is removed from the amount, and stays with the",
+              "sender."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitSettings",
+      "docs": [
+        "Optional settings of a rate limit account, set by",
+        "[`crate::instructions::set_outbound_strict_mode`] and",
+        "[`crate::instructions::set_inbound_strict_mode`].",
+        "[`RateLimitSettings::Unavailable`] (for accounts that haven't been",
+        "configured since the settings were added) means the defaults."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "RateLimitSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "strict",
+            "docs": [
+              "Whether queued transfers reserve capacity. By default, a transfer that",
+              "exceeds the capacity is queued without consuming any, so a backlog of",
+              "queued transfers can be released all at once, on top of the transfers",
+              "that keep coming in. In strict mode, a queued transfer drains the",
+              "capacity it needs as of its release timestamp, and later transfers",
+              "queue up behind it."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditLogEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "action",
+            "type": {
+              "defined": "AuditAction"
+            }
+          },
+          {
+            "name": "signer",
+            "docs": [
+              "The account that authorised the action (the owner, or the claimant in",
+              "the case of the two-step claim instructions)."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "The account affected by the action (e.g. the peer PDA for",
+              "[`AuditAction::SetPeer`], or the new owner for",
+              "[`AuditAction::TransferOwnership`])."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "before",
+            "docs": [
+              "Digest of the relevant state before the action."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "after",
+            "docs": [
+              "Digest of the relevant state after the action."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DirectoryPeer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DirectoryTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "Deregistered transceivers keep their id, so they stay listed, but",
+              "disabled."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "docs": [
+              "The [`super::outbox::OutboxItem`] or [`super::inbox::InboxItem`]."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of the transfer (in the mint's decimals)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueMembership",
+      "docs": [
+        "Whether a transfer is counted in its [`QueueIndex`].",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). Items created before",
+        "then are never queued in an index."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "QueueMembershipV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueMembershipV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "queued",
+            "type": "bool"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "The [`QueueIndex`] the transfer is (or was) queued in."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount counted in the index's totals."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxProvenance",
+      "docs": [
+        "Details of an outbound transfer that aren't part of the message, so that",
+        "it can be traced and reconciled.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). Outbox items are written",
+        "once, in full, so new details don't need a new version of this record:",
+        "they go in their own trailing record after it instead."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "OutboxProvenanceV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxProvenanceV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requestedAmount",
+            "docs": [
+              "The amount requested by the sender, before the protocol fee was",
+              "deducted and dust was removed (in the mint's decimals)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The account that paid for the outbox item."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rateLimitExempt",
+            "docs": [
+              "Whether the transfer drew from the sender's [`RateLimitExemption`]",
+              "instead of the outbound rate limit."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "The protocol fee paid to the fee recipient (in the mint's decimals)."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxGasDropoff",
+      "docs": [
+        "The native gas dropoff requested by the sender of an inbound transfer, and",
+        "how much of it was delivered to the recipient on release (for",
+        "reconciliation with the relayer's compensation on the source chain).",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "InboxGasDropoffV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxGasDropoffV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requested",
+            "type": "u64"
+          },
+          {
+            "name": "delivered",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxProvenance",
+      "docs": [
+        "Where an inbound transfer came from, and when it was received and",
+        "approved, so that it can be traced without the original message.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "InboxProvenanceV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxProvenanceV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "sourceNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The sender on the source chain."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "messageId",
+            "docs": [
+              "The id of the NTT manager message (for transfers from Solana, the",
+              "address of the outbox item)."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "When the first transceiver voted for the transfer."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "approvedAt",
+            "docs": [
+              "When the transfer reached the threshold."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxTranches",
+      "docs": [
+        "Progress of a transfer larger than the inbound rate limit, which is",
+        "released in tranches as the rate limit allows (see",
+        "[`InboxItem::try_release_tranche`]), rather than all at once.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). It's set on all new",
+        "inbox items, so that their accounts have room for it, and only",
+        "[`InboxTranches::enable`]d when the transfer is approved."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "InboxTranchesV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxTranchesV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether the transfer is released in tranches."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "releasedAmount",
+            "docs": [
+              "The amount released so far."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxTokenAccountRecipient",
+      "docs": [
+        "Whether the recipient address of an inbound transfer, which is then a",
+        "token account of the mint rather than a wallet, accepted to receive the",
+        "transfer itself (see",
+        "[`crate::instructions::accept_inbound_to_token_account`]), and who owned",
+        "it at the time.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). It's set on all new",
+        "inbox items, so that their accounts have room for it."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "InboxTokenAccountRecipientV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxTokenAccountRecipientV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "The owner of the token account, if it accepted the transfer."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerSettings",
+      "docs": [
+        "The address format of a peer, set by [`crate::instructions::set_peer`].",
+        "[`PeerSettings::Unavailable`] (for peers that haven't been configured since",
+        "the settings were added) means the defaults."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "PeerSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "addressFormat",
+            "docs": [
+              "The format of recipient addresses on the peer chain. Transfers to",
+              "recipients that don't match it are rejected."
+            ],
+            "type": {
+              "defined": "AddressFormat"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LargeTransferSettings",
+      "docs": [
+        "The large transfer policy of a peer, set by",
+        "[`crate::instructions::set_large_transfer_policy`].",
+        "[`LargeTransferSettings::Unavailable`] means no policy."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "LargeTransferPolicy"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerFeeSettings",
+      "docs": [
+        "The protocol fee override of a peer, set by",
+        "[`crate::instructions::set_peer_fee`].",
+        "[`PeerFeeSettings::Unavailable`] means the global fee applies."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "PeerFeeSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerFeeSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "docs": [
+              "The protocol fee on transfers to the peer, in basis points, if it",
+              "overrides the global one (see [`crate::config::FeeSettingsV1`])."
+            ],
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AmountLimitSettings",
+      "docs": [
+        "The amount limits of a peer, set by",
+        "[`crate::instructions::set_amount_limits`].",
+        "[`AmountLimitSettings::Unavailable`] means no limits."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "AmountLimits"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "BackflowSettings",
+      "docs": [
+        "The backflow settings of a peer, stored on its inbound rate limit account,",
+        "and set by [`crate::instructions::set_backflow`].",
+        "[`BackflowSettings::Unavailable`] means full backflow in both directions."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "BackflowSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "BackflowSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outbound",
+            "docs": [
+              "Backflow of transfers sent to the peer, into its inbound rate limit."
+            ],
+            "type": {
+              "defined": "Backflow"
+            }
+          },
+          {
+            "name": "inbound",
+            "docs": [
+              "Backflow of transfers received from the peer, into the outbound rate",
+              "limit."
+            ],
+            "type": {
+              "defined": "Backflow"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingTokenAuthorityExpiry",
+      "docs": [
+        "Timestamp after which [`PendingTokenAuthority::pending_authority`] can no",
+        "longer claim the token authority. See [`crate::PENDING_AUTHORITY_VALIDITY`].",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "PendingTokenAuthorityExpiryV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingTokenAuthorityExpiryV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "OutboundTransfer",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipientChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "recipientAddress",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "rateLimitExempt",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "CantReleaseYet",
+      "msg": "CantReleaseYet"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidPendingOwner",
+      "msg": "InvalidPendingOwner"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidChainId",
+      "msg": "InvalidChainId"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidRecipientAddress",
+      "msg": "InvalidRecipientAddress"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTransceiverPeer",
+      "msg": "InvalidTransceiverPeer"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidNttManagerPeer",
+      "msg": "InvalidNttManagerPeer"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidRecipientNttManager",
+      "msg": "InvalidRecipientNttManager"
+    },
+    {
+      "code": 6007,
+      "name": "TransferAlreadyRedeemed",
+      "msg": "TransferAlreadyRedeemed"
+    },
+    {
+      "code": 6008,
+      "name": "TransferCannotBeRedeemed",
+      "msg": "TransferCannotBeRedeemed"
+    },
+    {
+      "code": 6009,
+      "name": "TransferNotApproved",
+      "msg": "TransferNotApproved"
+    },
+    {
+      "code": 6010,
+      "name": "MessageAlreadySent",
+      "msg": "MessageAlreadySent"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidMode",
+      "msg": "InvalidMode"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6013,
+      "name": "TransferExceedsRateLimit",
+      "msg": "TransferExceedsRateLimit"
+    },
+    {
+      "code": 6014,
+      "name": "Paused",
+      "msg": "Paused"
+    },
+    {
+      "code": 6015,
+      "name": "DisabledTransceiver",
+      "msg": "DisabledTransceiver"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidDeployer",
+      "msg": "InvalidDeployer"
+    },
+    {
+      "code": 6017,
+      "name": "BadAmountAfterTransfer",
+      "msg": "BadAmountAfterTransfer"
+    },
+    {
+      "code": 6018,
+      "name": "BadAmountAfterBurn",
+      "msg": "BadAmountAfterBurn"
+    },
+    {
+      "code": 6019,
+      "name": "ZeroThreshold",
+      "msg": "ZeroThreshold"
+    },
+    {
+      "code": 6020,
+      "name": "OverflowExponent",
+      "msg": "OverflowExponent"
+    },
+    {
+      "code": 6021,
+      "name": "OverflowScaledAmount",
+      "msg": "OverflowScaledAmount"
+    },
+    {
+      "code": 6022,
+      "name": "BitmapIndexOutOfBounds",
+      "msg": "BitmapIndexOutOfBounds"
+    },
+    {
+      "code": 6023,
+      "name": "NoRegisteredTransceivers",
+      "msg": "NoRegisteredTransceivers"
+    },
+    {
+      "code": 6024,
+      "name": "NotPaused",
+      "msg": "NotPaused"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidPendingTokenAuthority",
+      "msg": "InvalidPendingTokenAuthority"
+    },
+    {
+      "code": 6026,
+      "name": "IncorrectRentPayer",
+      "msg": "IncorrectRentPayer"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidMultisig",
+      "msg": "InvalidMultisig"
+    },
+    {
+      "code": 6028,
+      "name": "ThresholdTooHigh",
+      "msg": "ThresholdTooHigh"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidTransceiverProgram",
+      "msg": "InvalidTransceiverProgram"
+    },
+    {
+      "code": 6030,
+      "name": "NotCouncilMember",
+      "msg": "NotCouncilMember"
+    },
+    {
+      "code": 6031,
+      "name": "TooManyCouncilMembers",
+      "msg": "TooManyCouncilMembers"
+    },
+    {
+      "code": 6032,
+      "name": "DuplicateCouncilMember",
+      "msg": "DuplicateCouncilMember"
+    },
+    {
+      "code": 6033,
+      "name": "CouncilProposalAlreadyApproved",
+      "msg": "CouncilProposalAlreadyApproved"
+    },
+    {
+      "code": 6034,
+      "name": "CouncilProposalAlreadyExecuted",
+      "msg": "CouncilProposalAlreadyExecuted"
+    },
+    {
+      "code": 6035,
+      "name": "CouncilProposalStillActive",
+      "msg": "CouncilProposalStillActive"
+    },
+    {
+      "code": 6036,
+      "name": "StaleCouncilProposal",
+      "msg": "StaleCouncilProposal"
+    },
+    {
+      "code": 6037,
+      "name": "CouncilThresholdNotMet",
+      "msg": "CouncilThresholdNotMet"
+    },
+    {
+      "code": 6038,
+      "name": "CouncilProposalSelfReference",
+      "msg": "CouncilProposalSelfReference"
+    },
+    {
+      "code": 6039,
+      "name": "PendingOwnerExpired",
+      "msg": "PendingOwnerExpired"
+    },
+    {
+      "code": 6040,
+      "name": "NoPendingOwner",
+      "msg": "NoPendingOwner"
+    },
+    {
+      "code": 6041,
+      "name": "PendingTokenAuthorityExpired",
+      "msg": "PendingTokenAuthorityExpired"
+    },
+    {
+      "code": 6042,
+      "name": "PendingTokenAuthorityNotExpired",
+      "msg": "PendingTokenAuthorityNotExpired"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidIntentSignature",
+      "msg": "InvalidIntentSignature"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidIntentNttManager",
+      "msg": "InvalidIntentNttManager"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidIntentSender",
+      "msg": "InvalidIntentSender"
+    },
+    {
+      "code": 6046,
+      "name": "IntentExpired",
+      "msg": "IntentExpired"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidIntentNonce",
+      "msg": "InvalidIntentNonce"
+    },
+    {
+      "code": 6048,
+      "name": "RelayerFeeTooHigh",
+      "msg": "RelayerFeeTooHigh"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidRelayerFeeRecipient",
+      "msg": "InvalidRelayerFeeRecipient"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidRecipientAddressFormat",
+      "msg": "InvalidRecipientAddressFormat"
+    },
+    {
+      "code": 6051,
+      "name": "TransferEscrowed",
+      "msg": "TransferEscrowed"
+    },
+    {
+      "code": 6052,
+      "name": "TransferNotEscrowed",
+      "msg": "TransferNotEscrowed"
+    },
+    {
+      "code": 6053,
+      "name": "InboxItemDeliverable",
+      "msg": "InboxItemDeliverable"
+    },
+    {
+      "code": 6054,
+      "name": "FailedAttemptTooSoon",
+      "msg": "FailedAttemptTooSoon"
+    },
+    {
+      "code": 6055,
+      "name": "NotInboxItemRecipient",
+      "msg": "NotInboxItemRecipient"
+    },
+    {
+      "code": 6056,
+      "name": "NoEscrowDestination",
+      "msg": "NoEscrowDestination"
+    },
+    {
+      "code": 6057,
+      "name": "EscrowRecoveryTimelocked",
+      "msg": "EscrowRecoveryTimelocked"
+    },
+    {
+      "code": 6058,
+      "name": "InvalidEscrowRecoveryDestination",
+      "msg": "InvalidEscrowRecoveryDestination"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidRecipientTokenAccount",
+      "msg": "InvalidRecipientTokenAccount"
+    },
+    {
+      "code": 6060,
+      "name": "DirectoryFull",
+      "msg": "DirectoryFull"
+    },
+    {
+      "code": 6061,
+      "name": "InvalidQueueIndex",
+      "msg": "InvalidQueueIndex"
+    },
+    {
+      "code": 6062,
+      "name": "TransferStillQueued",
+      "msg": "TransferStillQueued"
+    },
+    {
+      "code": 6063,
+      "name": "GuardianApprovalRequired",
+      "msg": "GuardianApprovalRequired"
+    },
+    {
+      "code": 6064,
+      "name": "NotAwaitingGuardian",
+      "msg": "NotAwaitingGuardian"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidGuardian",
+      "msg": "InvalidGuardian"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidLargeTransferPolicy",
+      "msg": "InvalidLargeTransferPolicy"
+    },
+    {
+      "code": 6067,
+      "name": "TransferReleasedInTranches",
+      "msg": "TransferReleasedInTranches"
+    },
+    {
+      "code": 6068,
+      "name": "TransferNotReleasedInTranches",
+      "msg": "TransferNotReleasedInTranches"
+    },
+    {
+      "code": 6069,
+      "name": "MissingRateLimit",
+      "msg": "MissingRateLimit"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidBackflow",
+      "msg": "InvalidBackflow"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidRateLimitExemption",
+      "msg": "InvalidRateLimitExemption"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidFee",
+      "msg": "InvalidFee"
+    },
+    {
+      "code": 6073,
+      "name": "MissingFeeRecipient",
+      "msg": "MissingFeeRecipient"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidFeeRecipient",
+      "msg": "InvalidFeeRecipient"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidAmountLimits",
+      "msg": "InvalidAmountLimits"
+    },
+    {
+      "code": 6076,
+      "name": "TransferBelowMinimum",
+      "msg": "TransferBelowMinimum"
+    },
+    {
+      "code": 6077,
+      "name": "TransferAboveMaximum",
+      "msg": "TransferAboveMaximum"
+    },
+    {
+      "code": 6078,
+      "name": "TransferHasDust",
+      "msg": "TransferHasDust"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidBatchSize",
+      "msg": "InvalidBatchSize"
+    },
+    {
+      "code": 6080,
+      "name": "BatchAmountOverflow",
+      "msg": "BatchAmountOverflow"
+    },
+    {
+      "code": 6081,
+      "name": "TransferRequiresSecurityDelay",
+      "msg": "TransferRequiresSecurityDelay"
+    },
+    {
+      "code": 6082,
+      "name": "TokenAccountRecipientUnsupported",
+      "msg": "TokenAccountRecipientUnsupported"
+    }
+  ]
+}
+export const IDL: ExampleNativeTokenTransfers = {
+  "version": "4.0.0",
+  "name": "example_native_token_transfers",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "deployer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "In any case, this function is used to set the Config and initialize the program so we",
+            "assume the caller of this function will have total control over the program.",
+            "",
+            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
+            "Could refactor code to use `Box<_>` to reduce stack size."
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account that holds tokens in locking mode and temporarily",
+            "holds tokens in burning mode.",
+            "function if the token account has already been created."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "associated token account for the given mint."
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeLut",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entries",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormhole",
+              "accounts": [
+                {
+                  "name": "bridge",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "feeCollector",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "sequence",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "program",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "systemProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "clock",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "rent",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeAuditLog",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeDirectory",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncDirectoryPeer",
+      "accounts": [
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`chain_id`. It's deserialized in [`sync_directory_peer`] if it exists."
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ]
+    },
+    {
+      "name": "syncDirectoryTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeOutboundQueue",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeInboundQueue",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "inboundQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ]
+    },
+    {
+      "name": "dequeueReleasedOutbound",
+      "accounts": [
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "dequeueReleasedInbound",
+      "accounts": [
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: checked against the inbox item's queue membership in",
+            "[`QueueIndex::dequeue`]"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveLargeInbound",
+      "accounts": [
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveLargeInboundByOwner",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recordTransceiverAdminAction",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RecordTransceiverAdminActionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "version",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "queryOutboundCapacity",
+      "accounts": [
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "RateLimitCapacity"
+      }
+    },
+    {
+      "name": "queryInboundCapacity",
+      "accounts": [
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "RateLimitCapacity"
+      }
+    },
+    {
+      "name": "queryOutboxItemStatus",
+      "accounts": [
+        {
+          "name": "outboxItem",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "OutboxItemStatus"
+      }
+    },
+    {
+      "name": "queryInboxItemStatus",
+      "accounts": [
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "InboxItemStatus"
+      }
+    },
+    {
+      "name": "simulateTransfer",
+      "accounts": [
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimitExemption",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The sender's exemption from the outbound rate limit, to simulate a",
+            "transfer that draws from it instead."
+          ],
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "TransferSimulation"
+      }
+    },
+    {
+      "name": "transferBurn",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnDirect",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of `common.from`, or its delegate. The spl token program",
+            "checks that it can spend the tokens."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockDirect",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of `common.from`, or its delegate. The spl token program",
+            "checks that it can spend the tokens."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnSequenced",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "these tokens."
+              ]
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": true,
+              "docs": [
+                "The owner of `from`, or its delegate. The spl token program checks that",
+                "it can spend the tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "senderSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockSequenced",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "these tokens."
+              ]
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": true,
+              "docs": [
+                "The owner of `from`, or its delegate. The spl token program checks that",
+                "it can spend the tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "senderSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnBatch",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "these tokens."
+              ]
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": true,
+              "docs": [
+                "The owner of `from`, or its delegate. The spl token program checks that",
+                "it can spend the tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "senderSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded with [`QueueIndex::load`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the legs are inserted into the",
+                "outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when any",
+                "leg is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferBatchArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockBatch",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "these tokens."
+              ]
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": true,
+              "docs": [
+                "The owner of `from`, or its delegate. The spl token program checks that",
+                "it can spend the tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "senderSequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded with [`QueueIndex::load`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the legs are inserted into the",
+                "outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when any",
+                "leg is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferBatchArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnWithIntent",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "intentNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "intentAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::intent::INTENT_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "relayerFeeRecipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The relayer fee goes to a token account owned by the relayer, i.e. the",
+            "payer of the transaction, so that the fee can't be redirected by",
+            "someone front-running the relayer with the same intent."
+          ]
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferWithIntentArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockWithIntent",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "which is loaded in [`QueueIndex::enqueue_into`] if it has been created"
+              ]
+            },
+            {
+              "name": "rateLimitExemption",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The sender's exemption from the outbound rate limit, if it has one.",
+                "NOTE: checked against the sender when the transfer is inserted into",
+                "the outbox"
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The token account that receives the protocol fee. Required when the",
+                "transfer is charged a fee (see [`FeeSettingsV1`])."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "intentNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "intentAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::intent::INTENT_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "relayerFeeRecipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The relayer fee goes to a token account owned by the relayer, i.e. the",
+            "payer of the transaction, so that the fee can't be redirected by",
+            "someone front-running the relayer with the same intent."
+          ]
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferWithIntentArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NOTE: checked against the message in [`Redeem::check_message`]"
+          ]
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`Account<T>` and `owner` constraints are mutually-exclusive. It's",
+            "deserialized (and its owner checked) exactly once, in [`redeem`], and",
+            "the other accounts are checked against it there rather than in",
+            "constraints, which would each parse it again.",
+            "NOTE: we don't replay protect VAAs. Instead, we replay protect",
+            "executing the messages themselves with the [`released`] flag."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
+            "This is because in a multi-transceiver configuration, the different",
+            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
+            "items content-addressed, we can ensure that disagreeing votes don't",
+            "interfere with each other.",
+            "[`Redeem::load_inbox_item`]. On the first call to [`redeem()`], the",
+            "[`InboxItem`] is allocated and initialized there; on subsequent calls,",
+            "it's loaded so that it can be \"voted\" on."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: checked against the message in [`Redeem::check_message`]"
+          ]
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "loaded with [`QueueIndex::load`] if it has been created"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipientAddress",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "the transfer"
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: checked against the inbox item's queue membership in",
+                "[`QueueIndex::dequeue`]. Required when the inbox item is queued in it."
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Required for transfers released in tranches, which consume the",
+                "inbound rate limit of their source chain as they're released."
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Required for transfers released in tranches, whose tranches flow back",
+                "into the outbound rate limit."
+              ],
+              "isOptional": true
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipientAddress",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "the transfer"
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: checked against the inbox item's queue membership in",
+                "[`QueueIndex::dequeue`]. Required when the inbox item is queued in it."
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Required for transfers released in tranches, which consume the",
+                "inbound rate limit of their source chain as they're released."
+              ],
+              "isOptional": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Required for transfers released in tranches, whose tranches flow back",
+                "into the outbound rate limit."
+              ],
+              "isOptional": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deliverGasDropoff",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientAddress",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "the transfer"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptInboundToTokenAccount",
+      "accounts": [
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reportFailedInboundRelease",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientAddress",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "transfer is released to, which may not exist yet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestInboundEscrow",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redirectEscrowedInbound",
+      "accounts": [
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setEscrowRecovery",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseEscrowedInboundMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "escrow",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseEscrowedInboundUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "escrow",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnershipOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelOwnershipTransfer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTokenAuthorityFromMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "currentMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthorityOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "newAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingTokenAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revertTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExpiredPendingTokenAuthority",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingTokenAuthority",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthority",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenAuthorityToMultisig",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "multisigTokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true
+            },
+            {
+              "name": "rentPayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "pendingTokenAuthority",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "newMultisigAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removePeer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": {
+            "defined": "ChainId"
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "used here that wraps the Transceiver account type."
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deregisterTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        },
+        {
+          "name": "directory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setOutboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundStrictMode",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "strict",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setStrictDustMode",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "strict",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setInboundStrictMode",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundStrictModeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRateLimitExemption",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "exemption",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRateLimitExemptionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeRateLimitExemption",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "exemption",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "sender",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setBackflow",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetBackflowArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPeerAddressFormat",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerAddressFormatArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLargeTransferPolicy",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetLargeTransferPolicyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFee",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token account that receives the fees."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetFeeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPeerFee",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerFeeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAmountLimits",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetAmountLimitsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "markOutboxItemAsReleased",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [],
+      "returns": "bool"
+    },
+    {
+      "name": "setThreshold",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initializeCouncil",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "council",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetCouncilMembersArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCouncilMembers",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "council",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetCouncilMembersArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeCouncilInstruction",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The proposing member. Pays for the proposal account, and is refunded",
+            "when it's closed."
+          ]
+        },
+        {
+          "name": "council",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "councilInstruction",
+          "type": {
+            "defined": "CouncilInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveCouncilProposal",
+      "accounts": [
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "council",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeCouncilProposal",
+      "accounts": [
+        {
+          "name": "councilPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`COUNCIL_PAYER`]. Its lamports are only spent by approved proposals."
+          ]
+        },
+        {
+          "name": "council",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeCouncilProposal",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "council",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`AuditLog::record_into`])"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "receiveAndReleaseWormholeMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "peer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "transceiverPeer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "vaa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "transceiver",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: same address as in [`crate::instructions::Redeem`], so a transfer",
+                "can't be redeemed through both."
+              ]
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipientAddress",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "the transfer"
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "the source chain, which is loaded with [`QueueIndex::load`] if it has",
+                "been created"
+              ]
+            }
+          ]
+        },
+        {
+          "name": "multisigTokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveAndReleaseWormholeUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "peer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "transceiverPeer",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "vaa",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "transceiver",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "NOTE: same address as in [`crate::instructions::Redeem`], so a transfer",
+                "can't be redeemed through both."
+              ]
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "recipientAddress",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "the transfer"
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "inboundQueue",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "the source chain, which is loaded with [`QueueIndex::load`] if it has",
+                "been created"
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwnerExpiry",
+            "docs": [
+              "Timestamp after which [`Config::pending_owner`] can no longer claim",
+              "ownership. See [`crate::PENDING_AUTHORITY_VALIDITY`].",
+              "",
+              "NOTE: this field was appended after the initial release. Configs",
+              "created before then have enough slack at the end (as `pending_owner` is",
+              "always `None` when the program is upgradeable) to deserialize it, and",
+              "are grown when the next ownership transfer is initiated. The value is",
+              "only meaningful while `pending_owner` is set."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "NOTE: this field was appended after [`Config::pending_owner_expiry`].",
+              "Configs created before then are grown (see",
+              "[`Config::materialize_records`]) when the fee is first set or an",
+              "ownership transfer is initiated. Until then they may end in stale",
+              "bytes left by a previous `pending_owner`, which are read as",
+              "unavailable."
+            ],
+            "type": {
+              "defined": "FeeSettings"
+            }
+          },
+          {
+            "name": "dust",
+            "docs": [
+              "NOTE: this field was appended after [`Config::fees`], and must remain",
+              "the last one. Configs created before then are grown along with",
+              "[`Config::fees`]."
+            ],
+            "type": {
+              "defined": "DustSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LUT",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "settings",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that",
+              "peers created before then can still be read. Fields appended after it",
+              "must be trailing versioned records too."
+            ],
+            "type": {
+              "defined": "PeerSettings"
+            }
+          },
+          {
+            "name": "largeTransfers",
+            "docs": [
+              "NOTE: this field was appended after [`NttManagerPeer::settings`]."
+            ],
+            "type": {
+              "defined": "LargeTransferSettings"
+            }
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "NOTE: this field was appended after",
+              "[`NttManagerPeer::large_transfers`]."
+            ],
+            "type": {
+              "defined": "PeerFeeSettings"
+            }
+          },
+          {
+            "name": "amountLimits",
+            "docs": [
+              "NOTE: this field was appended after [`NttManagerPeer::fee`], and must",
+              "remain the last one."
+            ],
+            "type": {
+              "defined": "AmountLimitSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingTokenAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "See [`PendingTokenAuthorityExpiry`]."
+            ],
+            "type": {
+              "defined": "PendingTokenAuthorityExpiry"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "init",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "gasDropoff",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that inbox",
+              "items created before then can still be read. Fields appended after it",
+              "must be trailing versioned records too."
+            ],
+            "type": {
+              "defined": "InboxGasDropoff"
+            }
+          },
+          {
+            "name": "queue",
+            "docs": [
+              "NOTE: this field was appended after [`InboxItem::gas_dropoff`]. It's",
+              "only ever set on items that have the previous records."
+            ],
+            "type": {
+              "defined": "QueueMembership"
+            }
+          },
+          {
+            "name": "provenance",
+            "docs": [
+              "NOTE: this field was appended after [`InboxItem::queue`]. Like it, it's",
+              "only ever set on items that have the previous records."
+            ],
+            "type": {
+              "defined": "InboxProvenance"
+            }
+          },
+          {
+            "name": "tranches",
+            "docs": [
+              "NOTE: this field was appended after [`InboxItem::provenance`]. Like it,",
+              "it's only ever set on items that have the previous records."
+            ],
+            "type": {
+              "defined": "InboxTranches"
+            }
+          },
+          {
+            "name": "tokenAccountRecipient",
+            "docs": [
+              "NOTE: this field was appended after [`InboxItem::tranches`], and must",
+              "remain the last one. Like it, it's only ever set on items that have the",
+              "previous records."
+            ],
+            "type": {
+              "defined": "InboxTokenAccountRecipient"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          },
+          {
+            "name": "settings",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that rate",
+              "limits created before then can still be read."
+            ],
+            "type": {
+              "defined": "RateLimitSettings"
+            }
+          },
+          {
+            "name": "backflow",
+            "docs": [
+              "NOTE: this field was appended after `settings`, and must remain the",
+              "last one. It's only ever set on accounts that have the settings."
+            ],
+            "type": {
+              "defined": "BackflowSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The account that authorised the transfer. This is the owner of the",
+              "source token account for [`crate::instructions::transfer_burn`] and",
+              "[`crate::instructions::transfer_lock`], and the signing authority (which",
+              "may be a program PDA) for the `transfer_*_direct` and",
+              "`transfer_*_sequenced` variants."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "queue",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that",
+              "outbox items created before then can still be read. Fields appended",
+              "after it must be trailing versioned records too."
+            ],
+            "type": {
+              "defined": "QueueMembership"
+            }
+          },
+          {
+            "name": "provenance",
+            "docs": [
+              "NOTE: this field was appended after [`OutboxItem::queue`], and must",
+              "remain the last one."
+            ],
+            "type": {
+              "defined": "OutboxProvenance"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          },
+          {
+            "name": "settings",
+            "docs": [
+              "NOTE: this field was appended after the initial release, and must",
+              "remain the last one, so that rate limits created before then can still",
+              "be read."
+            ],
+            "type": {
+              "defined": "RateLimitSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auditLog",
+      "docs": [
+        "Ring buffer of the most recent administrative actions.",
+        "",
+        "Every instruction in [`crate::instructions::admin`] (and the transceiver",
+        "admin instructions) appends an entry here, so that monitoring can",
+        "reconstruct recent configuration changes from a single account fetch,",
+        "without access to historical transactions.",
+        "",
+        "The log is a required account of those instructions, even in deployments",
+        "that haven't created it yet, so that once it's created, no action can skip",
+        "it (see [`AuditLog::record_into`]).",
+        "",
+        "Entries are stored in insertion order until the buffer is full, after which",
+        "`head` points at the oldest entry (which is the next one to be overwritten)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "head",
+            "docs": [
+              "Index into `entries` at which the next entry will be written."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "totalEntries",
+            "docs": [
+              "Total number of entries ever recorded (including overwritten ones)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "AuditLogEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "directory",
+      "docs": [
+        "Lists the peers ([`crate::peer::NttManagerPeer`]) and registered",
+        "transceivers ([`crate::registered_transceiver::RegisteredTransceiver`]) of",
+        "the deployment, so that clients can discover them with a single account",
+        "fetch instead of `getProgramAccounts`.",
+        "",
+        "The directory is passed to the admin instructions that modify peers and",
+        "transceivers as a trailing optional account. Unlike the",
+        "[`crate::audit_log::AuditLog`], skipping it loses nothing: entries that",
+        "are missing or stale (e.g. for deployments that predate the directory) can",
+        "be fixed permissionlessly with",
+        "[`crate::instructions::sync_directory_peer`] and",
+        "[`crate::instructions::sync_directory_transceiver`].",
+        "",
+        "Peers are sorted by chain id, and transceivers by id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "peers",
+            "type": {
+              "vec": {
+                "defined": "DirectoryPeer"
+              }
+            }
+          },
+          {
+            "name": "transceivers",
+            "type": {
+              "vec": {
+                "defined": "DirectoryTransceiver"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "queueIndex",
+      "docs": [
+        "Transfers delayed by a rate limit, and not released yet.",
+        "",
+        "There is one index for outbound transfers (seeded by",
+        "[`QueueIndex::OUTBOUND_SEED_PREFIX`]), and one per chain for inbound",
+        "transfers (seeded by [`QueueIndex::INBOUND_SEED_PREFIX`] and the chain id).",
+        "Transfers are added when the rate limit delays them, and removed when",
+        "they're released.",
+        "",
+        "The instructions that queue transfers (transfer, redeem and",
+        "receive-and-release) take the index as a required account, even before",
+        "it's created (see [`QueueIndex::load`]). Once it is, every",
+        "transfer they queue is tracked. Inbound releases must pass the index the",
+        "inbox item is queued in. Outbound releases (which transceivers perform)",
+        "take it as an optional trailing account: transfers released without it",
+        "stay in the index until they're removed with",
+        "[`crate::instructions::dequeue_released_outbound`]. Transfers released",
+        "before the inbound index was required are removed with",
+        "[`crate::instructions::dequeue_released_inbound`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "queuedAmount",
+            "docs": [
+              "Total amount of the queued transfers."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "queuedCount",
+            "docs": [
+              "Number of queued transfers."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "entries",
+            "docs": [
+              "The queued transfers, as long as there is room for them (see",
+              "[`QUEUE_INDEX_CAPACITY`])."
+            ],
+            "type": {
+              "vec": {
+                "defined": "QueueEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rateLimitExemption",
+      "docs": [
+        "An owner-managed exemption of a sender from the outbound rate limit.",
+        "Transfers by the sender that pass this account draw from its dedicated",
+        "rate limit instead, so that large institutional transfers don't queue up",
+        "behind (or crowd out) everyone else's.",
+        "SECURITY: must check the sender (since there are multiple PDAs, namely one",
+        "for each sender.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "senderSequence",
+      "docs": [
+        "The next sequence number of a sender, used to derive the address of its next",
+        "PDA outbox item. Since the message id is the outbox item's address, this",
+        "makes message ids predictable, and lets clients enumerate a sender's",
+        "transfers."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nextSequence",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "intentNonce",
+      "docs": [
+        "Tracks the next valid intent nonce of a sender. Intents have to be executed",
+        "in nonce order, and each nonce can only be used once."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nextNonce",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxEscrow",
+      "docs": [
+        "Tracks an inbound transfer whose recipient can't receive the tokens (e.g.",
+        "because their token account is frozen). Seeded by the",
+        "[`super::inbox::InboxItem`] it belongs to.",
+        "",
+        "Once the inbox item is [`super::inbox::ReleaseStatus::Escrowed`] (after",
+        "[`InboxEscrow::MAX_FAILED_ATTEMPTS`] failed attempts, or at the recipient's",
+        "request), it can no longer be released to the recipient's associated token",
+        "account. Instead, the tokens are released to `destination`, which is either",
+        "chosen by the recipient (effective immediately), or by the owner among the",
+        "recipient's token accounts (effective after",
+        "[`InboxEscrow::RECOVERY_TIMELOCK`]). The recipient can override the owner's",
+        "choice until then."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "inboxItem",
+            "type": "publicKey"
+          },
+          {
+            "name": "failedAttempts",
+            "type": "u8"
+          },
+          {
+            "name": "lastFailedAttempt",
+            "type": "i64"
+          },
+          {
+            "name": "destination",
+            "docs": [
+              "The token account the escrowed tokens are released to."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "destinationEffectiveAt",
+            "docs": [
+              "Unix timestamp from which the tokens can be released to `destination`."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "council",
+      "docs": [
+        "An M-of-N council that can act as the owner of the program.",
+        "",
+        "The council account is a PDA, so it can be set as [`crate::config::Config::owner`]",
+        "(through the regular [`crate::instructions::transfer_ownership`] and",
+        "[`crate::instructions::claim_ownership`] flow). From then on, admin",
+        "instructions are executed by the program signing for the council PDA once",
+        "`threshold` of its `members` have approved a [`CouncilProposal`].",
+        "",
+        "Since the council PDA claims ownership as a signer, the upgrade authority of",
+        "the program is transferred to it too, and upgrades are executed the same",
+        "way as any other proposal."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "members",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Number of member approvals required to execute a proposal."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "Incremented every time the members or the threshold change. Proposals",
+              "created under a previous generation can no longer be approved or",
+              "executed, as their approvals refer to member indices of the old set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "nextProposalId",
+            "docs": [
+              "The id of the next [`CouncilProposal`]."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "councilProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "The [`Council::generation`] the proposal was created under."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "proposer",
+            "docs": [
+              "The member that created the proposal. It also paid the rent, which is",
+              "refunded when the proposal is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "approvals",
+            "docs": [
+              "Bitmap of [`Council::members`] indices that approved the proposal."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          },
+          {
+            "name": "instruction",
+            "type": {
+              "defined": "CouncilInstruction"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "tokenDecimals",
+            "docs": [
+              "The token decimals on the peer chain."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "Mode"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "ReleaseInboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertWhenNotReady",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseStatus",
+      "docs": [
+        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
+        "such, this must be used as a state machine that moves forward in a linear manner. A state",
+        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
+        "`ReleaseAfter`).",
+        "",
+        "`Escrowed` is an alternative path from `ReleaseAfter` to `Released`, for",
+        "transfers that can't be delivered to the recipient. See",
+        "[`super::escrow::InboxEscrow`].",
+        "",
+        "`AwaitingGuardian` comes before `ReleaseAfter` for large transfers that need",
+        "the approval of the peer's guardian (see",
+        "[`crate::peer::LargeTransferPolicy`]). It holds the release timestamp set",
+        "by the rate limit.",
+        "",
+        "NOTE: new variants must only ever be appended, as the discriminant is",
+        "stored on-chain."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotApproved"
+          },
+          {
+            "name": "ReleaseAfter",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "Released"
+          },
+          {
+            "name": "Escrowed"
+          },
+          {
+            "name": "AwaitingGuardian",
+            "fields": [
+              "i64"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "The maximum capacity of the rate limiter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed (unless the rate limit is strict, see",
+              "[`RateLimitSettingsV1::strict`]).",
+              "",
+              "In strict mode, this can be in the future: the capacity up to then is",
+              "reserved by queued transfers."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RecordTransceiverAdminActionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "action",
+            "type": {
+              "defined": "AuditAction"
+            }
+          },
+          {
+            "name": "subject",
+            "type": "publicKey"
+          },
+          {
+            "name": "before",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "after",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditAction",
+      "docs": [
+        "The administrative action recorded by an [`AuditLogEntry`].",
+        "",
+        "NOTE: new variants must only ever be appended, as the discriminant is",
+        "stored on-chain."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SetPeer"
+          },
+          {
+            "name": "RegisterTransceiver"
+          },
+          {
+            "name": "DeregisterTransceiver"
+          },
+          {
+            "name": "SetOutboundLimit"
+          },
+          {
+            "name": "SetInboundLimit"
+          },
+          {
+            "name": "SetPaused"
+          },
+          {
+            "name": "SetThreshold"
+          },
+          {
+            "name": "TransferOwnership"
+          },
+          {
+            "name": "TransferOwnershipOneStepUnchecked"
+          },
+          {
+            "name": "ClaimOwnership"
+          },
+          {
+            "name": "AcceptTokenAuthority"
+          },
+          {
+            "name": "AcceptTokenAuthorityFromMultisig"
+          },
+          {
+            "name": "SetTokenAuthority"
+          },
+          {
+            "name": "SetTokenAuthorityOneStepUnchecked"
+          },
+          {
+            "name": "RevertTokenAuthority"
+          },
+          {
+            "name": "ClaimTokenAuthority"
+          },
+          {
+            "name": "ClaimTokenAuthorityToMultisig"
+          },
+          {
+            "name": "SetWormholePeer"
+          },
+          {
+            "name": "InitializeCouncil"
+          },
+          {
+            "name": "SetCouncilMembers"
+          },
+          {
+            "name": "CancelOwnershipTransfer"
+          },
+          {
+            "name": "SetEscrowRecovery"
+          },
+          {
+            "name": "RemovePeer"
+          },
+          {
+            "name": "SetOutboundStrictMode"
+          },
+          {
+            "name": "SetInboundStrictMode"
+          },
+          {
+            "name": "SetLargeTransferPolicy"
+          },
+          {
+            "name": "ApproveLargeInbound"
+          },
+          {
+            "name": "SetBackflow"
+          },
+          {
+            "name": "SetRateLimitExemption"
+          },
+          {
+            "name": "RemoveRateLimitExemption"
+          },
+          {
+            "name": "SetFee"
+          },
+          {
+            "name": "SetPeerFee"
+          },
+          {
+            "name": "SetAmountLimits"
+          },
+          {
+            "name": "SetStrictDustMode"
+          },
+          {
+            "name": "SetPeerAddressFormat"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitCapacity",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "capacity",
+            "docs": [
+              "The amount that can be transferred right now without being queued."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "releasable",
+            "docs": [
+              "Whether the release timestamp has passed."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "released",
+            "docs": [
+              "The transceivers that have released the transfer."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "votes",
+            "docs": [
+              "The transceivers that have voted for the transfer."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releasable",
+            "docs": [
+              "Whether the transfer can be released right now (see",
+              "[`InboxItem::check_releasable`])."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferSimulation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "The amount that would be taken from the sender, after deducting the",
+              "protocol fee and removing dust."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "The protocol fee that would be paid to the fee recipient."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "dust",
+            "docs": [
+              "The dust that would be left with the sender."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "trimmedAmount",
+            "docs": [
+              "The amount in the message."
+            ],
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "queued",
+            "docs": [
+              "Whether the transfer would be queued, by the outbound rate limit or the",
+              "peer's large transfer delay."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "releaseTimestamp",
+            "docs": [
+              "When the transfer could be released: now, unless it's queued."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferBatchArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "legs",
+            "type": {
+              "vec": {
+                "defined": "TransferLeg"
+              }
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferLeg",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferWithIntentArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "intent",
+            "type": {
+              "defined": "TransferIntent"
+            }
+          },
+          {
+            "name": "relayerFee",
+            "docs": [
+              "The fee paid to the relayer, at most `intent.max_relayer_fee`."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferIntent",
+      "docs": [
+        "A transfer intent signed by `sender`. The signed message is the borsh",
+        "serialization of this struct (see [`TransferIntent::message`])."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nttManager",
+            "docs": [
+              "The NTT manager program the intent is for. Prevents the same intent from",
+              "being replayed against other deployments."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The owner of the token account the tokens are transferred from."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount to transfer (excluding the relayer fee)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Must match the sender's [`IntentNonce::next_nonce`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the intent can no longer be executed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxRelayerFee",
+            "docs": [
+              "The maximum fee (in the bridged token) the relayer may charge, on top of",
+              "`amount`."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundStrictModeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "strict",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRateLimitExemptionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "limit",
+            "docs": [
+              "The limit of the sender's dedicated rate limit."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetBackflowArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "outbound",
+            "docs": [
+              "Backflow of transfers sent to the chain, into its inbound rate limit."
+            ],
+            "type": {
+              "defined": "Backflow"
+            }
+          },
+          {
+            "name": "inbound",
+            "docs": [
+              "Backflow of transfers received from the chain, into the outbound rate",
+              "limit."
+            ],
+            "type": {
+              "defined": "Backflow"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Backflow",
+      "docs": [
+        "How much of a transfer flows back into the rate limit of the opposite",
+        "direction (\"backflow\"): sending a transfer to a chain refills that chain's",
+        "inbound rate limit, and receiving one refills the outbound rate limit."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Disabled"
+          },
+          {
+            "name": "Full"
+          },
+          {
+            "name": "Percentage",
+            "fields": [
+              "u8"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerAddressFormatArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "addressFormat",
+            "docs": [
+              "The format of recipient addresses on the peer chain."
+            ],
+            "type": {
+              "defined": "AddressFormat"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddressFormat",
+      "docs": [
+        "How addresses of a chain are encoded in the 32-byte universal address",
+        "format.",
+        "",
+        "Peers configured before the format was added read as",
+        "[`AddressFormat::Bytes32`], which accepts any non-zero address."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Bytes32"
+          },
+          {
+            "name": "Evm"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetLargeTransferPolicyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "policy",
+            "type": {
+              "defined": "LargeTransferPolicy"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LargeTransferPolicy",
+      "docs": [
+        "Extra checks on large transfers to and from a peer, on top of the rate",
+        "limits. Both are disabled by default."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outboundThreshold",
+            "docs": [
+              "Outbound transfers of more than this amount (after removing dust) can",
+              "only be released [`LargeTransferPolicy::outbound_delay`] seconds after",
+              "they're sent, even if the rate limit has capacity for them."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "outboundDelay",
+            "type": "i64"
+          },
+          {
+            "name": "inboundThreshold",
+            "docs": [
+              "Inbound transfers of more than this amount can only be released once",
+              "[`LargeTransferPolicy::guardian`] has approved them (see",
+              "[`crate::instructions::approve_large_inbound`])."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "guardian",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetFeeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerFeeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "feeBps",
+            "docs": [
+              "The fee on transfers to the peer, in basis points, or `None` to charge",
+              "the global fee."
+            ],
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetAmountLimitsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "limits",
+            "type": {
+              "defined": "AmountLimits"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AmountLimits",
+      "docs": [
+        "Bounds on the amount of transfers to and from a peer, after the protocol",
+        "fee is deducted and dust is removed (in the mint's decimals). Transfers",
+        "outside of them are rejected, instead of being queued. Both are disabled",
+        "by default."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "enforceInbound",
+            "docs": [
+              "Whether inbound transfers are checked too. Since the tokens have",
+              "already left the peer chain by then, rejected inbound transfers can't",
+              "be redeemed until the limits are relaxed."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetCouncilMembersArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CouncilInstruction",
+      "docs": [
+        "The instruction to be executed by a [`CouncilProposal`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": "CouncilAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CouncilAccountMeta",
+      "docs": [
+        "A copy of [`solana_program::instruction::AccountMeta`] with",
+        "`AnchorSerialize`/`AnchorDeserialize` impls."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettings",
+      "docs": [
+        "The protocol fee charged on outbound transfers, set by",
+        "[`crate::instructions::set_fee`]. Peers can override the rate (see",
+        "[`crate::peer::PeerFeeSettingsV1::fee_bps`]).",
+        "[`FeeSettings::Unavailable`] means no fee."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "FeeSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "docs": [
+              "The fee, in basis points of the transferred amount."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "The token account (of the managed mint) that receives the fees."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DustSettings",
+      "docs": [
+        "How outbound transfers handle dust (the part of the amount that can't be",
+        "represented at the trimmed decimals), set by",
+        "[`crate::instructions::set_strict_dust_mode`].",
+        "[`DustSettings::Unavailable`] means the defaults."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "DustSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "DustSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "strict",
+            "docs": [
+              "Whether outbound transfers with dust are rejected. Otherwise (the",
+              "default) the dust is removed from the amount, and stays with the",
+              "sender."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitSettings",
+      "docs": [
+        "Optional settings of a rate limit account, set by",
+        "[`crate::instructions::set_outbound_strict_mode`] and",
+        "[`crate::instructions::set_inbound_strict_mode`].",
+        "[`RateLimitSettings::Unavailable`] (for accounts that haven't been",
+        "configured since the settings were added) means the defaults."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "RateLimitSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "strict",
+            "docs": [
+              "Whether queued transfers reserve capacity. By default, a transfer that",
+              "exceeds the capacity is queued without consuming any, so a backlog of",
+              "queued transfers can be released all at once, on top of the transfers",
+              "that keep coming in. In strict mode, a queued transfer drains the",
+              "capacity it needs as of its release timestamp, and later transfers",
+              "queue up behind it."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditLogEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "action",
+            "type": {
+              "defined": "AuditAction"
+            }
+          },
+          {
+            "name": "signer",
+            "docs": [
+              "The account that authorised the action (the owner, or the claimant in",
+              "the case of the two-step claim instructions)."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "The account affected by the action (e.g. the peer PDA for",
+              "[`AuditAction::SetPeer`], or the new owner for",
+              "[`AuditAction::TransferOwnership`])."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "before",
+            "docs": [
+              "Digest of the relevant state before the action."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "after",
+            "docs": [
+              "Digest of the relevant state after the action."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DirectoryPeer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DirectoryTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "Deregistered transceivers keep their id, so they stay listed, but",
+              "disabled."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "docs": [
+              "The [`super::outbox::OutboxItem`] or [`super::inbox::InboxItem`]."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of the transfer (in the mint's decimals)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueMembership",
+      "docs": [
+        "Whether a transfer is counted in its [`QueueIndex`].",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). Items created before",
+        "then are never queued in an index."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "QueueMembershipV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueMembershipV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "queued",
+            "type": "bool"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "The [`QueueIndex`] the transfer is (or was) queued in."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount counted in the index's totals."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxProvenance",
+      "docs": [
+        "Details of an outbound transfer that aren't part of the message, so that",
+        "it can be traced and reconciled.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). Outbox items are written",
+        "once, in full, so new details don't need a new version of this record:",
+        "they go in their own trailing record after it instead."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "OutboxProvenanceV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxProvenanceV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requestedAmount",
+            "docs": [
+              "The amount requested by the sender, before the protocol fee was",
+              "deducted and dust was removed (in the mint's decimals)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The account that paid for the outbox item."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rateLimitExempt",
+            "docs": [
+              "Whether the transfer drew from the sender's [`RateLimitExemption`]",
+              "instead of the outbound rate limit."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "The protocol fee paid to the fee recipient (in the mint's decimals)."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxGasDropoff",
+      "docs": [
+        "The native gas dropoff requested by the sender of an inbound transfer, and",
+        "how much of it was delivered to the recipient on release (for",
+        "reconciliation with the relayer's compensation on the source chain).",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "InboxGasDropoffV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxGasDropoffV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requested",
+            "type": "u64"
+          },
+          {
+            "name": "delivered",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxProvenance",
+      "docs": [
+        "Where an inbound transfer came from, and when it was received and",
+        "approved, so that it can be traced without the original message.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "InboxProvenanceV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxProvenanceV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "sourceNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The sender on the source chain."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "messageId",
+            "docs": [
+              "The id of the NTT manager message (for transfers from Solana, the",
+              "address of the outbox item)."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "When the first transceiver voted for the transfer."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "approvedAt",
+            "docs": [
+              "When the transfer reached the threshold."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxTranches",
+      "docs": [
+        "Progress of a transfer larger than the inbound rate limit, which is",
+        "released in tranches as the rate limit allows (see",
+        "[`InboxItem::try_release_tranche`]), rather than all at once.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). It's set on all new",
+        "inbox items, so that their accounts have room for it, and only",
+        "[`InboxTranches::enable`]d when the transfer is approved."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "InboxTranchesV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxTranchesV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether the transfer is released in tranches."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "releasedAmount",
+            "docs": [
+              "The amount released so far."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxTokenAccountRecipient",
+      "docs": [
+        "Whether the recipient address of an inbound transfer, which is then a",
+        "token account of the mint rather than a wallet, accepted to receive the",
+        "transfer itself (see",
+        "[`crate::instructions::accept_inbound_to_token_account`]), and who owned",
+        "it at the time.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). It's set on all new",
+        "inbox items, so that their accounts have room for it."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "InboxTokenAccountRecipientV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxTokenAccountRecipientV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "The owner of the token account, if it accepted the transfer."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerSettings",
+      "docs": [
+        "The address format of a peer, set by [`crate::instructions::set_peer`].",
+        "[`PeerSettings::Unavailable`] (for peers that haven't been configured since",
+        "the settings were added) means the defaults."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "PeerSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "addressFormat",
+            "docs": [
+              "The format of recipient addresses on the peer chain. Transfers to",
+              "recipients that don't match it are rejected."
+            ],
+            "type": {
+              "defined": "AddressFormat"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LargeTransferSettings",
+      "docs": [
+        "The large transfer policy of a peer, set by",
+        "[`crate::instructions::set_large_transfer_policy`].",
+        "[`LargeTransferSettings::Unavailable`] means no policy."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "LargeTransferPolicy"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerFeeSettings",
+      "docs": [
+        "The protocol fee override of a peer, set by",
+        "[`crate::instructions::set_peer_fee`].",
+        "[`PeerFeeSettings::Unavailable`] means the global fee applies."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "PeerFeeSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "PeerFeeSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "docs": [
+              "The protocol fee on transfers to the peer, in basis points, if it",
+              "overrides the global one (see [`crate::config::FeeSettingsV1`])."
+            ],
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AmountLimitSettings",
+      "docs": [
+        "The amount limits of a peer, set by",
+        "[`crate::instructions::set_amount_limits`].",
+        "[`AmountLimitSettings::Unavailable`] means no limits."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "AmountLimits"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "BackflowSettings",
+      "docs": [
+        "The backflow settings of a peer, stored on its inbound rate limit account,",
+        "and set by [`crate::instructions::set_backflow`].",
+        "[`BackflowSettings::Unavailable`] means full backflow in both directions."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "BackflowSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "BackflowSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outbound",
+            "docs": [
+              "Backflow of transfers sent to the peer, into its inbound rate limit."
+            ],
+            "type": {
+              "defined": "Backflow"
+            }
+          },
+          {
+            "name": "inbound",
+            "docs": [
+              "Backflow of transfers received from the peer, into the outbound rate",
+              "limit."
+            ],
+            "type": {
+              "defined": "Backflow"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingTokenAuthorityExpiry",
+      "docs": [
+        "Timestamp after which [`PendingTokenAuthority::pending_authority`] can no",
+        "longer claim the token authority. See [`crate::PENDING_AUTHORITY_VALIDITY`].",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`])."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "PendingTokenAuthorityExpiryV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingTokenAuthorityExpiryV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "OutboundTransfer",
+      "fields": [
+        {
+          "name": "outboxItem",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipientChain",
+          "type": {
+            "defined": "ChainId"
+          },
+          "index": false
+        },
+        {
+          "name": "recipientAddress",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "releaseTimestamp",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "rateLimitExempt",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "CantReleaseYet",
+      "msg": "CantReleaseYet"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidPendingOwner",
+      "msg": "InvalidPendingOwner"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidChainId",
+      "msg": "InvalidChainId"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidRecipientAddress",
+      "msg": "InvalidRecipientAddress"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTransceiverPeer",
+      "msg": "InvalidTransceiverPeer"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidNttManagerPeer",
+      "msg": "InvalidNttManagerPeer"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidRecipientNttManager",
+      "msg": "InvalidRecipientNttManager"
+    },
+    {
+      "code": 6007,
+      "name": "TransferAlreadyRedeemed",
+      "msg": "TransferAlreadyRedeemed"
+    },
+    {
+      "code": 6008,
+      "name": "TransferCannotBeRedeemed",
+      "msg": "TransferCannotBeRedeemed"
+    },
+    {
+      "code": 6009,
+      "name": "TransferNotApproved",
+      "msg": "TransferNotApproved"
+    },
+    {
+      "code": 6010,
+      "name": "MessageAlreadySent",
+      "msg": "MessageAlreadySent"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidMode",
+      "msg": "InvalidMode"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6013,
+      "name": "TransferExceedsRateLimit",
+      "msg": "TransferExceedsRateLimit"
+    },
+    {
+      "code": 6014,
+      "name": "Paused",
+      "msg": "Paused"
+    },
+    {
+      "code": 6015,
+      "name": "DisabledTransceiver",
+      "msg": "DisabledTransceiver"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidDeployer",
+      "msg": "InvalidDeployer"
+    },
+    {
+      "code": 6017,
+      "name": "BadAmountAfterTransfer",
+      "msg": "BadAmountAfterTransfer"
+    },
+    {
+      "code": 6018,
+      "name": "BadAmountAfterBurn",
+      "msg": "BadAmountAfterBurn"
+    },
+    {
+      "code": 6019,
+      "name": "ZeroThreshold",
+      "msg": "ZeroThreshold"
+    },
+    {
+      "code": 6020,
+      "name": "OverflowExponent",
+      "msg": "OverflowExponent"
+    },
+    {
+      "code": 6021,
+      "name": "OverflowScaledAmount",
+      "msg": "OverflowScaledAmount"
+    },
+    {
+      "code": 6022,
+      "name": "BitmapIndexOutOfBounds",
+      "msg": "BitmapIndexOutOfBounds"
+    },
+    {
+      "code": 6023,
+      "name": "NoRegisteredTransceivers",
+      "msg": "NoRegisteredTransceivers"
+    },
+    {
+      "code": 6024,
+      "name": "NotPaused",
+      "msg": "NotPaused"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidPendingTokenAuthority",
+      "msg": "InvalidPendingTokenAuthority"
+    },
+    {
+      "code": 6026,
+      "name": "IncorrectRentPayer",
+      "msg": "IncorrectRentPayer"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidMultisig",
+      "msg": "InvalidMultisig"
+    },
+    {
+      "code": 6028,
+      "name": "ThresholdTooHigh",
+      "msg": "ThresholdTooHigh"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidTransceiverProgram",
+      "msg": "InvalidTransceiverProgram"
+    },
+    {
+      "code": 6030,
+      "name": "NotCouncilMember",
+      "msg": "NotCouncilMember"
+    },
+    {
+      "code": 6031,
+      "name": "TooManyCouncilMembers",
+      "msg": "TooManyCouncilMembers"
+    },
+    {
+      "code": 6032,
+      "name": "DuplicateCouncilMember",
+      "msg": "DuplicateCouncilMember"
+    },
+    {
+      "code": 6033,
+      "name": "CouncilProposalAlreadyApproved",
+      "msg": "CouncilProposalAlreadyApproved"
+    },
+    {
+      "code": 6034,
+      "name": "CouncilProposalAlreadyExecuted",
+      "msg": "CouncilProposalAlreadyExecuted"
+    },
+    {
+      "code": 6035,
+      "name": "CouncilProposalStillActive",
+      "msg": "CouncilProposalStillActive"
+    },
+    {
+      "code": 6036,
+      "name": "StaleCouncilProposal",
+      "msg": "StaleCouncilProposal"
+    },
+    {
+      "code": 6037,
+      "name": "CouncilThresholdNotMet",
+      "msg": "CouncilThresholdNotMet"
+    },
+    {
+      "code": 6038,
+      "name": "CouncilProposalSelfReference",
+      "msg": "CouncilProposalSelfReference"
+    },
+    {
+      "code": 6039,
+      "name": "PendingOwnerExpired",
+      "msg": "PendingOwnerExpired"
+    },
+    {
+      "code": 6040,
+      "name": "NoPendingOwner",
+      "msg": "NoPendingOwner"
+    },
+    {
+      "code": 6041,
+      "name": "PendingTokenAuthorityExpired",
+      "msg": "PendingTokenAuthorityExpired"
+    },
+    {
+      "code": 6042,
+      "name": "PendingTokenAuthorityNotExpired",
+      "msg": "PendingTokenAuthorityNotExpired"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidIntentSignature",
+      "msg": "InvalidIntentSignature"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidIntentNttManager",
+      "msg": "InvalidIntentNttManager"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidIntentSender",
+      "msg": "InvalidIntentSender"
+    },
+    {
+      "code": 6046,
+      "name": "IntentExpired",
+      "msg": "IntentExpired"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidIntentNonce",
+      "msg": "InvalidIntentNonce"
+    },
+    {
+      "code": 6048,
+      "name": "RelayerFeeTooHigh",
+      "msg": "RelayerFeeTooHigh"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidRelayerFeeRecipient",
+      "msg": "InvalidRelayerFeeRecipient"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidRecipientAddressFormat",
+      "msg": "InvalidRecipientAddressFormat"
+    },
+    {
+      "code": 6051,
+      "name": "TransferEscrowed",
+      "msg": "TransferEscrowed"
+    },
+    {
+      "code": 6052,
+      "name": "TransferNotEscrowed",
+      "msg": "TransferNotEscrowed"
+    },
+    {
+      "code": 6053,
+      "name": "InboxItemDeliverable",
+      "msg": "InboxItemDeliverable"
+    },
+    {
+      "code": 6054,
+      "name": "FailedAttemptTooSoon",
+      "msg": "FailedAttemptTooSoon"
+    },
+    {
+      "code": 6055,
+      "name": "NotInboxItemRecipient",
+      "msg": "NotInboxItemRecipient"
+    },
+    {
+      "code": 6056,
+      "name": "NoEscrowDestination",
+      "msg": "NoEscrowDestination"
+    },
+    {
+      "code": 6057,
+      "name": "EscrowRecoveryTimelocked",
+      "msg": "EscrowRecoveryTimelocked"
+    },
+    {
+      "code": 6058,
+      "name": "InvalidEscrowRecoveryDestination",
+      "msg": "InvalidEscrowRecoveryDestination"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidRecipientTokenAccount",
+      "msg": "InvalidRecipientTokenAccount"
+    },
+    {
+      "code": 6060,
+      "name": "DirectoryFull",
+      "msg": "DirectoryFull"
+    },
+    {
+      "code": 6061,
+      "name": "InvalidQueueIndex",
+      "msg": "InvalidQueueIndex"
+    },
+    {
+      "code": 6062,
+      "name": "TransferStillQueued",
+      "msg": "TransferStillQueued"
+    },
+    {
+      "code": 6063,
+      "name": "GuardianApprovalRequired",
+      "msg": "GuardianApprovalRequired"
+    },
+    {
+      "code": 6064,
+      "name": "NotAwaitingGuardian",
+      "msg": "NotAwaitingGuardian"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidGuardian",
+      "msg": "InvalidGuardian"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidLargeTransferPolicy",
+      "msg": "InvalidLargeTransferPolicy"
+    },
+    {
+      "code": 6067,
+      "name": "TransferReleasedInTranches",
+      "msg": "TransferReleasedInTranches"
+    },
+    {
+      "code": 6068,
+      "name": "TransferNotReleasedInTranches",
+      "msg": "TransferNotReleasedInTranches"
+    },
+    {
+      "code": 6069,
+      "name": "MissingRateLimit",
+      "msg": "MissingRateLimit"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidBackflow",
+      "msg": "InvalidBackflow"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidRateLimitExemption",
+      "msg": "InvalidRateLimitExemption"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidFee",
+      "msg": "InvalidFee"
+    },
+    {
+      "code": 6073,
+      "name": "MissingFeeRecipient",
+      "msg": "MissingFeeRecipient"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidFeeRecipient",
+      "msg": "InvalidFeeRecipient"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidAmountLimits",
+      "msg": "InvalidAmountLimits"
+    },
+    {
+      "code": 6076,
+      "name": "TransferBelowMinimum",
+      "msg": "TransferBelowMinimum"
+    },
+    {
+      "code": 6077,
+      "name": "TransferAboveMaximum",
+      "msg": "TransferAboveMaximum"
+    },
+    {
+      "code": 6078,
+      "name": "TransferHasDust",
+      "msg": "TransferHasDust"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidBatchSize",
+      "msg": "InvalidBatchSize"
+    },
+    {
+      "code": 6080,
+      "name": "BatchAmountOverflow",
+      "msg": "BatchAmountOverflow"
+    },
+    {
+      "code": 6081,
+      "name": "TransferRequiresSecurityDelay",
+      "msg": "TransferRequiresSecurityDelay"
+    },
+    {
+      "code": 6082,
+      "name": "TokenAccountRecipientUnsupported",
+      "msg": "TokenAccountRecipientUnsupported"
+    }
+  ]
+}
+
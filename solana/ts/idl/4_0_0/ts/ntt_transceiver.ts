@@ -0,0 +1,2021 @@
+export type NttTransceiver = {
+  "version": "4.0.0",
+  "name": "ntt_transceiver",
+  "instructions": [
+    {
+      "name": "transceiverType",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "It's required even before the log is created, so that it can't be",
+            "skipped once it exists."
+          ]
+        },
+        {
+          "name": "auditLogSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwnerExpiry",
+            "docs": [
+              "Timestamp after which [`Config::pending_owner`] can no longer claim",
+              "ownership. See [`crate::PENDING_AUTHORITY_VALIDITY`].",
+              "",
+              "NOTE: this field was appended after the initial release. Configs",
+              "created before then have enough slack at the end (as `pending_owner` is",
+              "always `None` when the program is upgradeable) to deserialize it, and",
+              "are grown when the next ownership transfer is initiated. The value is",
+              "only meaningful while `pending_owner` is set."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "NOTE: this field was appended after [`Config::pending_owner_expiry`].",
+              "Configs created before then are grown (see",
+              "[`Config::materialize_records`]) when the fee is first set or an",
+              "ownership transfer is initiated. Until then they may end in stale",
+              "bytes left by a previous `pending_owner`, which are read as",
+              "unavailable."
+            ],
+            "type": {
+              "defined": "FeeSettings"
+            }
+          },
+          {
+            "name": "dust",
+            "docs": [
+              "NOTE: this field was appended after [`Config::fees`], and must remain",
+              "the last one. Configs created before then are grown along with",
+              "[`Config::fees`]."
+            ],
+            "type": {
+              "defined": "DustSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The account that authorised the transfer. This is the owner of the",
+              "source token account for [`crate::instructions::transfer_burn`] and",
+              "[`crate::instructions::transfer_lock`], and the signing authority (which",
+              "may be a program PDA) for the `transfer_*_direct` and",
+              "`transfer_*_sequenced` variants."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "queue",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that",
+              "outbox items created before then can still be read. Fields appended",
+              "after it must be trailing versioned records too."
+            ],
+            "type": {
+              "defined": "QueueMembership"
+            }
+          },
+          {
+            "name": "provenance",
+            "docs": [
+              "NOTE: this field was appended after [`OutboxItem::queue`], and must",
+              "remain the last one."
+            ],
+            "type": {
+              "defined": "OutboxProvenance"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettings",
+      "docs": [
+        "The protocol fee charged on outbound transfers, set by",
+        "[`crate::instructions::set_fee`]. Peers can override the rate (see",
+        "[`crate::peer::PeerFeeSettingsV1::fee_bps`]).",
+        "[`FeeSettings::Unavailable`] means no fee."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "FeeSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "docs": [
+              "The fee, in basis points of the transferred amount."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "The token account (of the managed mint) that receives the fees."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DustSettings",
+      "docs": [
+        "How outbound transfers handle dust (the part of the amount that can't be",
+        "represented at the trimmed decimals), set by",
+        "[`crate::instructions::set_strict_dust_mode`].",
+        "[`DustSettings::Unavailable`] means the defaults."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "DustSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "DustSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "strict",
+            "docs": [
+              "Whether outbound transfers with dust are rejected. Otherwise (the",
+              "default) the dust is removed from the amount, and stays with the",
+              "sender."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueMembership",
+      "docs": [
+        "Whether a transfer is counted in its [`QueueIndex`].",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). Items created before",
+        "then are never queued in an index."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "QueueMembershipV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueMembershipV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "queued",
+            "type": "bool"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "The [`QueueIndex`] the transfer is (or was) queued in."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount counted in the index's totals."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxProvenance",
+      "docs": [
+        "Details of an outbound transfer that aren't part of the message, so that",
+        "it can be traced and reconciled.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). Outbox items are written",
+        "once, in full, so new details don't need a new version of this record:",
+        "they go in their own trailing record after it instead."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "OutboxProvenanceV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxProvenanceV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requestedAmount",
+            "docs": [
+              "The amount requested by the sender, before the protocol fee was",
+              "deducted and dust was removed (in the mint's decimals)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The account that paid for the outbox item."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rateLimitExempt",
+            "docs": [
+              "Whether the transfer drew from the sender's [`RateLimitExemption`]",
+              "instead of the outbound rate limit."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "The protocol fee paid to the fee recipient (in the mint's decimals)."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ]
+}
+export const IDL: NttTransceiver = {
+  "version": "4.0.0",
+  "name": "ntt_transceiver",
+  "instructions": [
+    {
+      "name": "transceiverType",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "It's required even before the log is created, so that it can't be",
+            "skipped once it exists."
+          ]
+        },
+        {
+          "name": "auditLogSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboundQueue",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "enforced by the [`CpiContext`] call in [`post_message`].",
+            "The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwnerExpiry",
+            "docs": [
+              "Timestamp after which [`Config::pending_owner`] can no longer claim",
+              "ownership. See [`crate::PENDING_AUTHORITY_VALIDITY`].",
+              "",
+              "NOTE: this field was appended after the initial release. Configs",
+              "created before then have enough slack at the end (as `pending_owner` is",
+              "always `None` when the program is upgradeable) to deserialize it, and",
+              "are grown when the next ownership transfer is initiated. The value is",
+              "only meaningful while `pending_owner` is set."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "NOTE: this field was appended after [`Config::pending_owner_expiry`].",
+              "Configs created before then are grown (see",
+              "[`Config::materialize_records`]) when the fee is first set or an",
+              "ownership transfer is initiated. Until then they may end in stale",
+              "bytes left by a previous `pending_owner`, which are read as",
+              "unavailable."
+            ],
+            "type": {
+              "defined": "FeeSettings"
+            }
+          },
+          {
+            "name": "dust",
+            "docs": [
+              "NOTE: this field was appended after [`Config::fees`], and must remain",
+              "the last one. Configs created before then are grown along with",
+              "[`Config::fees`]."
+            ],
+            "type": {
+              "defined": "DustSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The account that authorised the transfer. This is the owner of the",
+              "source token account for [`crate::instructions::transfer_burn`] and",
+              "[`crate::instructions::transfer_lock`], and the signing authority (which",
+              "may be a program PDA) for the `transfer_*_direct` and",
+              "`transfer_*_sequenced` variants."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "queue",
+            "docs": [
+              "NOTE: this field was appended after the initial release, so that",
+              "outbox items created before then can still be read. Fields appended",
+              "after it must be trailing versioned records too."
+            ],
+            "type": {
+              "defined": "QueueMembership"
+            }
+          },
+          {
+            "name": "provenance",
+            "docs": [
+              "NOTE: this field was appended after [`OutboxItem::queue`], and must",
+              "remain the last one."
+            ],
+            "type": {
+              "defined": "OutboxProvenance"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettings",
+      "docs": [
+        "The protocol fee charged on outbound transfers, set by",
+        "[`crate::instructions::set_fee`]. Peers can override the rate (see",
+        "[`crate::peer::PeerFeeSettingsV1::fee_bps`]).",
+        "[`FeeSettings::Unavailable`] means no fee."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "FeeSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "docs": [
+              "The fee, in basis points of the transferred amount."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "The token account (of the managed mint) that receives the fees."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DustSettings",
+      "docs": [
+        "How outbound transfers handle dust (the part of the amount that can't be",
+        "represented at the trimmed decimals), set by",
+        "[`crate::instructions::set_strict_dust_mode`].",
+        "[`DustSettings::Unavailable`] means the defaults."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "DustSettingsV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "DustSettingsV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "strict",
+            "docs": [
+              "Whether outbound transfers with dust are rejected. Otherwise (the",
+              "default) the dust is removed from the amount, and stays with the",
+              "sender."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueMembership",
+      "docs": [
+        "Whether a transfer is counted in its [`QueueIndex`].",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). Items created before",
+        "then are never queued in an index."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "QueueMembershipV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueMembershipV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "queued",
+            "type": "bool"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "The [`QueueIndex`] the transfer is (or was) queued in."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount counted in the index's totals."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxProvenance",
+      "docs": [
+        "Details of an outbound transfer that aren't part of the message, so that",
+        "it can be traced and reconciled.",
+        "",
+        "NOTE: this was added after the initial release, so it's stored as a",
+        "trailing versioned record (see [`crate::layout`]). Outbox items are written",
+        "once, in full, so new details don't need a new version of this record:",
+        "they go in their own trailing record after it instead."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unavailable"
+          },
+          {
+            "name": "V1",
+            "fields": [
+              {
+                "defined": "OutboxProvenanceV1"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxProvenanceV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requestedAmount",
+            "docs": [
+              "The amount requested by the sender, before the protocol fee was",
+              "deducted and dust was removed (in the mint's decimals)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The account that paid for the outbox item."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rateLimitExempt",
+            "docs": [
+              "Whether the transfer drew from the sender's [`RateLimitExemption`]",
+              "instead of the outbound rate limit."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "The protocol fee paid to the fee recipient (in the mint's decimals)."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ]
+}
+
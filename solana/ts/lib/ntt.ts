@@ -1,4 +1,5 @@
 import {
+  IdlAccounts,
   Program,
   parseIdlErrors,
   translateError,
@@ -106,6 +107,14 @@ export namespace NTT {
       derivePda(["registered_transceiver", transceiver.toBytes()], programId);
     const lutAccount = (): PublicKey => derivePda("lut", programId);
     const lutAuthority = (): PublicKey => derivePda("lut_authority", programId);
+    const auditLog = (): PublicKey => derivePda("audit_log", programId);
+    const directory = (): PublicKey => derivePda("directory", programId);
+    const rateLimitExemption = (sender: PublicKey): PublicKey =>
+      derivePda(["rate_limit_exemption", sender.toBytes()], programId);
+    const outboundQueue = (): PublicKey =>
+      derivePda("outbound_queue", programId);
+    const inboundQueue = (chain: Chain): PublicKey =>
+      derivePda(["inbound_queue", chainToBytes(chain)], programId);
     const sessionAuthority = (
       sender: PublicKey,
       args: TransferArgs
@@ -140,6 +149,11 @@ export namespace NTT {
       registeredTransceiver,
       lutAccount,
       lutAuthority,
+      auditLog,
+      directory,
+      rateLimitExemption,
+      outboundQueue,
+      inboundQueue,
     };
   };
 
@@ -158,6 +172,8 @@ export namespace NTT {
       derivePda(["transceiver_message", chainToBytes(chain), id], programId);
     const wormholeMessageAccount = (outboxItem: PublicKey): PublicKey =>
       derivePda(["message", outboxItem.toBytes()], programId);
+    const auditLogSigner = (): PublicKey =>
+      derivePda("audit_log_signer", programId);
 
     // TODO: memoize?
     return {
@@ -166,6 +182,7 @@ export namespace NTT {
       transceiverPeerAccount,
       transceiverMessageAccount,
       wormholeMessageAccount,
+      auditLogSigner,
     };
   };
 
@@ -397,13 +414,15 @@ export namespace NTT {
     },
     pdas?: Pdas
   ): Promise<TransactionInstruction> {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
 
     const custody = await custodyAccountAddress(pdas, config);
     const recipientChain = toChain(args.transferArgs.recipientChain.id);
     const transferIx = await program.methods
       .transferBurn(args.transferArgs)
-      .accountsStrict({
+      .accounts({
         common: {
           payer: args.payer,
           config: { config: pdas.configAccount() },
@@ -414,6 +433,16 @@ export namespace NTT {
           outboxRateLimit: pdas.outboxRateLimitAccount(),
           systemProgram: SystemProgram.programId,
           custody,
+          // NOTE: rate limit exemptions, protocol fees and queue indices are
+          // only supported for versions >= 4.x.x
+          ...(major >= 4 && {
+            rateLimitExemption: await initializedOrNull(
+              program,
+              pdas.rateLimitExemption(args.fromAuthority)
+            ),
+            feeRecipient: feeRecipient(config),
+            outboundQueue: pdas.outboundQueue(),
+          }),
         },
         peer: pdas.peerAccount(recipientChain),
         inboxRateLimit: pdas.inboxRateLimitAccount(recipientChain),
@@ -479,13 +508,15 @@ export namespace NTT {
   ): Promise<TransactionInstruction> {
     if (config.paused) throw new Error("Contract is paused");
 
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
 
     const chain = toChain(args.transferArgs.recipientChain.id);
     const custody = await custodyAccountAddress(pdas, config);
     const transferIx = await program.methods
       .transferLock(args.transferArgs)
-      .accountsStrict({
+      .accounts({
         common: {
           payer: args.payer,
           config: { config: pdas.configAccount() },
@@ -496,6 +527,16 @@ export namespace NTT {
           outboxRateLimit: pdas.outboxRateLimitAccount(),
           custody,
           systemProgram: SystemProgram.programId,
+          // NOTE: rate limit exemptions, protocol fees and queue indices are
+          // only supported for versions >= 4.x.x
+          ...(major >= 4 && {
+            rateLimitExemption: await initializedOrNull(
+              program,
+              pdas.rateLimitExemption(args.fromAuthority)
+            ),
+            feeRecipient: feeRecipient(config),
+            outboundQueue: pdas.outboundQueue(),
+          }),
         },
         peer: pdas.peerAccount(chain),
         inboxRateLimit: pdas.inboxRateLimitAccount(chain),
@@ -598,6 +639,19 @@ export namespace NTT {
           tokenAuthority: pdas.tokenAuthority(),
           tokenProgram: config.tokenProgram,
           custody: await custodyAccountAddress(pdas, config),
+          // NOTE: creating the recipient token account, tranche releases and
+          // queue indices are only supported for versions >= 4.x.x
+          ...(major >= 4 && {
+            recipientAddress,
+            associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            inboxRateLimit: pdas.inboxRateLimitAccount(args.chain),
+            outboxRateLimit: pdas.outboxRateLimitAccount(),
+            inboundQueue: await inboxItemQueue(
+              program,
+              pdas.inboxItemAccount(args.chain, args.nttMessage)
+            ),
+          }),
         },
         // NOTE: SPL Multisig token authority is only supported for versions >= 3.x.x
         ...(major >= 3 && {
@@ -649,6 +703,8 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     const recipientAddress =
       args.recipient ??
       (await NTT.getInboxItem(program, args.chain, args.nttMessage))
@@ -664,7 +720,7 @@ export namespace NTT {
         revertOnDelay: args.revertWhenNotReady,
         revertWhenNotReady: args.revertWhenNotReady,
       })
-      .accountsStrict({
+      .accounts({
         common: {
           payer: args.payer,
           config: { config: pdas.configAccount() },
@@ -679,6 +735,19 @@ export namespace NTT {
           tokenAuthority: pdas.tokenAuthority(),
           tokenProgram: config.tokenProgram,
           custody,
+          // NOTE: creating the recipient token account, tranche releases and
+          // queue indices are only supported for versions >= 4.x.x
+          ...(major >= 4 && {
+            recipientAddress,
+            associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            inboxRateLimit: pdas.inboxRateLimitAccount(args.chain),
+            outboxRateLimit: pdas.outboxRateLimitAccount(),
+            inboundQueue: await inboxItemQueue(
+              program,
+              pdas.inboxItemAccount(args.chain, args.nttMessage)
+            ),
+          }),
         },
         custody,
       })
@@ -727,19 +796,30 @@ export namespace NTT {
     args: {
       owner: PublicKey;
       newOwner: PublicKey;
+      // pays for growing configs created before 4.x.x (defaults to the owner)
+      payer?: PublicKey;
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .transferOwnershipOneStepUnchecked()
-      .accountsStrict({
+      .accounts({
         config: pdas.configAccount(),
         owner: args.owner,
         newOwner: args.newOwner,
         upgradeLock: pdas.upgradeLock(),
         programData: programDataAddress(program.programId),
         bpfLoaderUpgradeableProgram: BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+        // NOTE: the audit log (and growing the config) is only supported for
+        // versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+          payer: args.payer ?? args.owner,
+          systemProgram: SystemProgram.programId,
+        }),
       })
       .instruction();
   }
@@ -749,19 +829,30 @@ export namespace NTT {
     args: {
       owner: PublicKey;
       newOwner: PublicKey;
+      // pays for growing configs created before 4.x.x (defaults to the owner)
+      payer?: PublicKey;
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .transferOwnership()
-      .accountsStrict({
+      .accounts({
         config: pdas.configAccount(),
         owner: args.owner,
         newOwner: args.newOwner,
         upgradeLock: pdas.upgradeLock(),
         programData: programDataAddress(program.programId),
         bpfLoaderUpgradeableProgram: BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+        // NOTE: the audit log (and growing the config) is only supported for
+        // versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+          payer: args.payer ?? args.owner,
+          systemProgram: SystemProgram.programId,
+        }),
       })
       .instruction();
   }
@@ -773,15 +864,21 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .claimOwnership()
-      .accountsStrict({
+      .accounts({
         config: pdas.configAccount(),
         newOwner: args.newOwner,
         upgradeLock: pdas.upgradeLock(),
         programData: programDataAddress(program.programId),
         bpfLoaderUpgradeableProgram: BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -795,10 +892,12 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .acceptTokenAuthority()
-      .accountsStrict({
+      .accounts({
         common: {
           config: pdas.configAccount(),
           mint: config.mint,
@@ -807,6 +906,10 @@ export namespace NTT {
           multisigTokenAuthority: args.multisigTokenAuthority ?? null,
         },
         currentAuthority: args.currentAuthority,
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -821,10 +924,12 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .acceptTokenAuthorityFromMultisig()
-      .accountsStrict({
+      .accounts({
         common: {
           config: pdas.configAccount(),
           mint: config.mint,
@@ -833,6 +938,10 @@ export namespace NTT {
           multisigTokenAuthority: args.multisigTokenAuthority ?? null,
         },
         currentMultisigAuthority: args.currentMultisigAuthority,
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .remainingAccounts(
         args.additionalSigners.map((pubkey) => ({
@@ -854,10 +963,12 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .setTokenAuthorityOneStepUnchecked()
-      .accountsStrict({
+      .accounts({
         common: {
           config: pdas.configAccount(),
           tokenAuthority: pdas.tokenAuthority(),
@@ -867,6 +978,10 @@ export namespace NTT {
           multisigTokenAuthority: args.multisigTokenAuthority ?? null,
         },
         tokenProgram: config.tokenProgram,
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -882,10 +997,12 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .setTokenAuthority()
-      .accountsStrict({
+      .accounts({
         common: {
           config: pdas.configAccount(),
           tokenAuthority: pdas.tokenAuthority(),
@@ -897,6 +1014,10 @@ export namespace NTT {
         rentPayer: args.rentPayer,
         pendingTokenAuthority: pdas.pendingTokenAuthority(),
         systemProgram: SystemProgram.programId,
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -911,10 +1032,12 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .revertTokenAuthority()
-      .accountsStrict({
+      .accounts({
         common: {
           config: pdas.configAccount(),
           mint: config.mint,
@@ -926,6 +1049,10 @@ export namespace NTT {
           multisigTokenAuthority: args.multisigTokenAuthority ?? null,
         },
         owner: args.owner,
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -940,10 +1067,12 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .claimTokenAuthority()
-      .accountsStrict({
+      .accounts({
         common: {
           config: pdas.configAccount(),
           mint: config.mint,
@@ -955,6 +1084,10 @@ export namespace NTT {
           multisigTokenAuthority: args.multisigTokenAuthority ?? null,
         },
         newAuthority: args.newAuthority,
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -970,10 +1103,12 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .claimTokenAuthorityToMultisig()
-      .accountsStrict({
+      .accounts({
         common: {
           config: pdas.configAccount(),
           mint: config.mint,
@@ -985,6 +1120,10 @@ export namespace NTT {
           multisigTokenAuthority: args.multisigTokenAuthority ?? null,
         },
         newMultisigAuthority: args.newMultisigAuthority,
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .remainingAccounts(
         args.additionalSigners.map((pubkey) => ({
@@ -1008,6 +1147,8 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .setPeer({
@@ -1022,6 +1163,12 @@ export namespace NTT {
         config: pdas.configAccount(),
         peer: pdas.peerAccount(args.chain),
         inboxRateLimit: pdas.inboxRateLimitAccount(args.chain),
+        // NOTE: the audit log and the directory are only supported for
+        // versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+          directory: await initializedOrNull(program, pdas.directory()),
+        }),
       })
       .instruction();
   }
@@ -1035,12 +1182,18 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .setPaused(args.paused)
-      .accountsStrict({
+      .accounts({
         owner: args.owner,
         config: pdas.configAccount(),
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -1053,12 +1206,18 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .setThreshold(args.threshold)
-      .accountsStrict({
+      .accounts({
         owner: args.owner,
         config: pdas.configAccount(),
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -1071,6 +1230,8 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .setOutboundLimit({
@@ -1080,6 +1241,10 @@ export namespace NTT {
         owner: args.owner,
         config: pdas.configAccount(),
         rateLimit: pdas.outboxRateLimitAccount(),
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -1093,6 +1258,8 @@ export namespace NTT {
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return program.methods
       .setInboundLimit({
@@ -1103,6 +1270,10 @@ export namespace NTT {
         owner: args.owner,
         config: pdas.configAccount(),
         rateLimit: pdas.inboxRateLimitAccount(args.chain),
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: pdas.auditLog(),
+        }),
       })
       .instruction();
   }
@@ -1118,6 +1289,8 @@ export namespace NTT {
     pdas?: Pdas,
     transceiverPdas?: TransceiverPdas
   ): Promise<TransactionInstruction> {
+    const [major, , ,] = parseVersion(program.idl.version);
+
     pdas = pdas ?? NTT.pdas(program.programId);
     transceiverPdas =
       transceiverPdas ?? NTT.transceiverPdas(transceiverProgramId);
@@ -1141,12 +1314,66 @@ export namespace NTT {
         inboxItem: pdas.inboxItemAccount(chain, nttMessage),
         inboxRateLimit: pdas.inboxRateLimitAccount(chain),
         outboxRateLimit: pdas.outboxRateLimitAccount(),
+        // NOTE: queue indices are only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          inboundQueue: pdas.inboundQueue(chain),
+        }),
       })
       .instruction();
   }
 
   // Account access
 
+  type ProgramAccounts = IdlAccounts<
+    NttBindings.NativeTokenTransfer<IdlVersion>
+  >;
+
+  /**
+   * Fetches an account of the program, or returns null if it doesn't exist.
+   *
+   * Version 4.x.x appended versioned records to the layout of existing
+   * accounts. Accounts created before then end before them (or in stale
+   * bytes), and the program reads them as unavailable. Such accounts are
+   * decoded with the 3.x.x layout, and zero-filled up to the current one,
+   * which decodes the records as unavailable too.
+   */
+  export async function fetchAccountNullable<A extends keyof ProgramAccounts>(
+    program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
+    account: A,
+    address: PublicKey
+  ): Promise<ProgramAccounts[A] | null> {
+    const info = await program.provider.connection.getAccountInfo(address);
+    if (info === null) return null;
+
+    const [major, , ,] = parseVersion(program.idl.version);
+    const size = program.account[account].size;
+    let data = info.data;
+    if (major >= 4 && data.length < size) {
+      const legacy = getNttProgram(
+        program.provider.connection,
+        program.programId.toBase58(),
+        "3.0.0"
+      );
+      const prefix = await legacy.coder.accounts.encode(
+        account,
+        legacy.coder.accounts.decode(account, data)
+      );
+      data = Buffer.concat([prefix, Buffer.alloc(size - prefix.length)]);
+    }
+    return program.coder.accounts.decode(account, data);
+  }
+
+  export async function fetchAccount<A extends keyof ProgramAccounts>(
+    program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
+    account: A,
+    address: PublicKey
+  ): Promise<ProgramAccounts[A]> {
+    const decoded = await fetchAccountNullable(program, account, address);
+    if (decoded === null)
+      throw new Error(`Account does not exist or has no data ${address}`);
+    return decoded;
+  }
+
   /**
    * Fetches the Config account from the contract.
    *
@@ -1159,7 +1386,7 @@ export namespace NTT {
     program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
     pdas: Pdas
   ): Promise<NttBindings.Config<IdlVersion>> {
-    return program.account.config.fetch(pdas.configAccount());
+    return fetchAccount(program, "config", pdas.configAccount());
   }
 
   export async function getInboxItem(
@@ -1167,11 +1394,59 @@ export namespace NTT {
     fromChain: Chain,
     nttMessage: Ntt.Message
   ): Promise<NttBindings.InboxItem<IdlVersion>> {
-    return program.account.inboxItem.fetch(
+    return fetchAccount(
+      program,
+      "inboxItem",
       NTT.pdas(program.programId).inboxItemAccount(fromChain, nttMessage)
     );
   }
 
+  /**
+   * Returns the token account that receives the protocol fee, which transfers
+   * must pass once the fee is set (versions >= 4.x.x). Peer fee overrides
+   * only change the rate.
+   */
+  export function feeRecipient(
+    config: NttBindings.Config<IdlVersion>
+  ): PublicKey | null {
+    // NOTE: anchor decodes tuple variants into objects keyed by field index
+    const fees: { v1?: { 0: { feeRecipient: PublicKey } } } | undefined = (
+      config as any
+    ).fees;
+    return fees?.v1?.[0].feeRecipient ?? null;
+  }
+
+  /**
+   * Returns `address` if the account exists, or null otherwise. Optional
+   * accounts that hold program state (e.g. the directory) must be initialized
+   * when they're passed, so they're only passed once they are.
+   */
+  export async function initializedOrNull(
+    program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
+    address: PublicKey
+  ): Promise<PublicKey | null> {
+    const info = await program.provider.connection.getAccountInfo(address);
+    return info === null ? null : address;
+  }
+
+  /**
+   * Returns the queue index the inbox item is listed in, which releasing it
+   * must pass (versions >= 4.x.x), or null if it isn't queued in one (e.g.
+   * when it's about to be created in the same transaction).
+   */
+  async function inboxItemQueue(
+    program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
+    inboxItem: PublicKey
+  ): Promise<PublicKey | null> {
+    const item = await fetchAccountNullable(program, "inboxItem", inboxItem);
+    // NOTE: anchor decodes tuple variants into objects keyed by field index
+    const queue:
+      | { v1?: { 0: { queued: boolean; index: PublicKey } } }
+      | undefined = (item as any)?.queue;
+    const membership = queue?.v1?.[0];
+    return membership?.queued ? membership.index : null;
+  }
+
   export async function getAddressLookupTable(
     program: Program<NttBindings.NativeTokenTransfer<IdlVersion>>,
     pdas?: Pdas
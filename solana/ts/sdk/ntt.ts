@@ -64,7 +64,7 @@ export class SolanaNttWormholeTransceiver<
   constructor(
     readonly manager: SolanaNtt<N, C>,
     readonly program: Program<NttBindings.Transceiver<IdlVersion>>,
-    readonly version: string = "3.0.0"
+    readonly version: string = "4.0.0"
   ) {
     this.programId = program.programId;
     this.pdas = NTT.transceiverPdas(program.programId);
@@ -184,6 +184,7 @@ export class SolanaNttWormholeTransceiver<
 
   async *setPeer(peer: ChainAddress<C>, payer: AccountAddress<C>) {
     const sender = new SolanaAddress(payer).unwrap();
+    const [major, , ,] = parseVersion(this.version);
     const ix = await this.program.methods
       .setWormholePeer({
         chainId: { id: toChainId(peer.chain) },
@@ -194,6 +195,15 @@ export class SolanaNttWormholeTransceiver<
         owner: sender,
         config: this.manager.pdas.configAccount(),
         peer: this.pdas.transceiverPeerAccount(peer.chain),
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          transceiver: this.manager.pdas.registeredTransceiver(
+            this.program.programId
+          ),
+          auditLog: this.manager.pdas.auditLog(),
+          auditLogSigner: this.pdas.auditLogSigner(),
+          manager: this.manager.program.programId,
+        }),
       })
       .instruction();
 
@@ -322,6 +332,13 @@ export class SolanaNttWormholeTransceiver<
           manager: this.manager.program.programId,
           outboxItemSigner: this.pdas.outboxItemSigner(),
         }),
+        // NOTE: queue indices are only supported for versions >= 4.x.x
+        ...(major >= 4 && {
+          outboundQueue: await NTT.initializedOrNull(
+            this.manager.program,
+            this.manager.pdas.outboundQueue()
+          ),
+        }),
       })
       .instruction();
   }
@@ -352,7 +369,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     readonly chain: C,
     readonly connection: Connection,
     readonly contracts: Contracts & { ntt?: Ntt.Contracts },
-    readonly version: string = "3.0.0"
+    readonly version: string = "4.0.0"
   ) {
     if (!contracts.ntt) throw new Error("Ntt contracts not found");
 
@@ -509,11 +526,14 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
   }
 
   private async createSetThresholdInstruction(owner: PublicKey, threshold: number) {
+    const [major, , ,] = parseVersion(this.version);
     return await this.program.methods
       .setThreshold(threshold)
-      .accountsStrict({
+      .accounts({
         owner: owner,
         config: this.pdas.configAccount(),
+        // NOTE: the audit log is only supported for versions >= 4.x.x
+        ...(major >= 4 && { auditLog: this.pdas.auditLog() }),
       })
       .instruction();
   }
@@ -532,6 +552,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     const ix = await NTT.createTransferOwnershipInstruction(this.program, {
       owner: new SolanaAddress(await this.getOwner()).unwrap(),
       newOwner: new SolanaAddress(newOwner).unwrap(),
+      payer: sender,
     });
 
     const tx = new Transaction();
@@ -604,7 +625,9 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
   }
 
   async getPeer<C extends Chain>(chain: C): Promise<Ntt.Peer<C> | null> {
-    const peer = await this.program.account.nttManagerPeer.fetchNullable(
+    const peer = await NTT.fetchAccountNullable(
+      this.program,
+      "nttManagerPeer",
       this.pdas.peerAccount(chain)
     );
 
@@ -637,7 +660,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
       );
     } catch (e) {
       // This might happen if e.g. the program is not deployed yet.
-      const version = "3.0.0";
+      const version = "4.0.0";
       return version;
     }
   }
@@ -751,10 +774,11 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
       throw new Error(`Transceiver not found`);
     }
     const transceiverProgramId = transceiver.programId;
+    const [major, , ,] = parseVersion(this.version);
 
     return this.program.methods
       .registerTransceiver()
-      .accountsStrict({
+      .accounts({
         payer,
         owner,
         config: this.pdas.configAccount(),
@@ -762,6 +786,15 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
         registeredTransceiver:
           this.pdas.registeredTransceiver(transceiverProgramId),
         systemProgram: SystemProgram.programId,
+        // NOTE: the audit log and the directory are only supported for
+        // versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: this.pdas.auditLog(),
+          directory: await NTT.initializedOrNull(
+            this.program,
+            this.pdas.directory()
+          ),
+        }),
       })
       .instruction();
   }
@@ -775,14 +808,24 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
       throw new Error(`Transceiver not found`);
     }
     const transceiverProgramId = transceiver.programId;
+    const [major, , ,] = parseVersion(this.version);
 
     return this.program.methods
       .deregisterTransceiver()
-      .accountsStrict({
+      .accounts({
         owner,
         config: this.pdas.configAccount(),
         registeredTransceiver:
           this.pdas.registeredTransceiver(transceiverProgramId),
+        // NOTE: the audit log and the directory are only supported for
+        // versions >= 4.x.x
+        ...(major >= 4 && {
+          auditLog: this.pdas.auditLog(),
+          directory: await NTT.initializedOrNull(
+            this.program,
+            this.pdas.directory()
+          ),
+        }),
       })
       .instruction();
   }
@@ -1113,14 +1156,18 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
   }
 
   async getCurrentOutboundCapacity(): Promise<bigint> {
-    const rl = await this.program.account.outboxRateLimit.fetch(
+    const rl = await NTT.fetchAccount(
+      this.program,
+      "outboxRateLimit",
       this.pdas.outboxRateLimitAccount()
     );
     return BigInt(rl.rateLimit.capacityAtLastTx.toString());
   }
 
   async getOutboundLimit(): Promise<bigint> {
-    const rl = await this.program.account.outboxRateLimit.fetch(
+    const rl = await NTT.fetchAccount(
+      this.program,
+      "outboxRateLimit",
       this.pdas.outboxRateLimitAccount()
     );
     return BigInt(rl.rateLimit.limit.toString());
@@ -1140,7 +1187,9 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
   }
 
   async getCurrentInboundCapacity(fromChain: Chain): Promise<bigint> {
-    const rl = await this.program.account.inboxRateLimit.fetch(
+    const rl = await NTT.fetchAccount(
+      this.program,
+      "inboxRateLimit",
       this.pdas.inboxRateLimitAccount(fromChain)
     );
     return BigInt(rl.rateLimit.capacityAtLastTx.toString());
@@ -1152,7 +1201,9 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
   }
 
   async getInboundLimit(fromChain: Chain): Promise<bigint> {
-    const rl = await this.program.account.inboxRateLimit.fetch(
+    const rl = await NTT.fetchAccount(
+      this.program,
+      "inboxRateLimit",
       this.pdas.inboxRateLimitAccount(fromChain)
     );
     return BigInt(rl.rateLimit.limit.toString());
@@ -1181,7 +1232,9 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     const payload = attestation.payload["nttManagerPayload"];
     let inboxItem;
     try {
-      inboxItem = await this.program.account.inboxItem.fetch(
+      inboxItem = await NTT.fetchAccount(
+        this.program,
+        "inboxItem",
         this.pdas.inboxItemAccount(attestation.emitterChain, payload)
       );
     } catch (e: any) {
@@ -1200,7 +1253,9 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     const payload = attestation.payload["nttManagerPayload"];
     let inboxItem;
     try {
-      inboxItem = await this.program.account.inboxItem.fetch(
+      inboxItem = await NTT.fetchAccount(
+        this.program,
+        "inboxItem",
         this.pdas.inboxItemAccount(attestation.emitterChain, payload)
       );
     } catch (e: any) {
@@ -1219,7 +1274,9 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     const payload = attestation.payload["nttManagerPayload"];
     try {
       // check that the inbox item was initialized
-      const inboxItem = await this.program.account.inboxItem.fetch(
+      const inboxItem = await NTT.fetchAccount(
+        this.program,
+        "inboxItem",
         this.pdas.inboxItemAccount(attestation.emitterChain, payload)
       );
       return inboxItem.init;
@@ -1280,7 +1337,9 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
   ): Promise<Ntt.InboundQueuedTransfer<C> | null> {
     let inboxItem;
     try {
-      inboxItem = await this.program.account.inboxItem.fetch(
+      inboxItem = await NTT.fetchAccount(
+        this.program,
+        "inboxItem",
         this.pdas.inboxItemAccount(chain, nttMessage)
       );
     } catch (e: any) {